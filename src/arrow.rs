@@ -0,0 +1,175 @@
+//! Conversions from and to Arrow's `TimestampMillisecondArray`.
+//!
+//! Arrow stores millisecond timestamps as a buffer of `i64`, which is the same
+//! layout as a slice of [`UtcTimeStamp`]. Conversions in both directions are
+//! therefore zero-copy where ownership allows it. Arrays created here carry
+//! the `UTC` time zone.
+//!
+//! Examples:
+//!
+//! ```
+//! use utctimestamp::{arrow, UtcTimeStamp};
+//!
+//! let ts = vec![UtcTimeStamp::from_seconds(1), UtcTimeStamp::from_seconds(2)];
+//! let array = arrow::to_array(ts.clone());
+//! assert_eq!(arrow::as_slice(&array), &ts[..]);
+//! ```
+
+use crate::{as_millis_slice, from_millis_slice, into_millis_vec, UtcTimeStamp};
+use arrow_array::{
+    builder::{ArrayBuilder, TimestampMillisecondBuilder},
+    TimestampMillisecondArray,
+};
+use arrow_buffer::ScalarBuffer;
+
+/// Time zone attached to all arrays created by this module.
+pub const TIME_ZONE: &str = "UTC";
+
+/// Convert a vector into an array without nulls, reusing the allocation.
+pub fn to_array(timestamps: Vec<UtcTimeStamp>) -> TimestampMillisecondArray {
+    let values = ScalarBuffer::from(into_millis_vec(timestamps));
+    TimestampMillisecondArray::new(values, None).with_timezone(TIME_ZONE)
+}
+
+/// View the values of an array as timestamps, without copying.
+///
+/// The values in null slots are unspecified, so check
+/// `Array::is_null` for arrays that may contain nulls.
+pub fn as_slice(array: &TimestampMillisecondArray) -> &[UtcTimeStamp] {
+    from_millis_slice(array.values())
+}
+
+/// Iterate over the timestamps of an array, yielding `None` for nulls.
+pub fn iter(array: &TimestampMillisecondArray) -> impl Iterator<Item = Option<UtcTimeStamp>> + '_ {
+    array.iter().map(|x| x.map(UtcTimeStamp::from_milliseconds))
+}
+
+/// Incrementally builds a nullable timestamp array.
+///
+/// Examples:
+///
+/// ```
+/// use utctimestamp::{arrow::TimestampArrayBuilder, UtcTimeStamp};
+///
+/// let mut builder = TimestampArrayBuilder::with_capacity(2);
+/// builder.append(UtcTimeStamp::zero());
+/// builder.append_null();
+///
+/// let array = builder.finish();
+/// assert_eq!(utctimestamp::arrow::iter(&array).collect::<Vec<_>>(), [Some(UtcTimeStamp::zero()), None]);
+/// ```
+#[derive(Debug)]
+pub struct TimestampArrayBuilder {
+    inner: TimestampMillisecondBuilder,
+}
+
+impl TimestampArrayBuilder {
+    /// Create a builder with space for `capacity` timestamps.
+    pub fn with_capacity(capacity: usize) -> Self {
+        TimestampArrayBuilder {
+            inner: TimestampMillisecondBuilder::with_capacity(capacity).with_timezone(TIME_ZONE),
+        }
+    }
+
+    /// Append a timestamp.
+    #[inline]
+    pub fn append(&mut self, ts: UtcTimeStamp) {
+        self.inner.append_value(ts.as_milliseconds());
+    }
+
+    /// Append a null.
+    #[inline]
+    pub fn append_null(&mut self) {
+        self.inner.append_null();
+    }
+
+    /// Append a timestamp or a null.
+    #[inline]
+    pub fn append_option(&mut self, ts: Option<UtcTimeStamp>) {
+        self.inner
+            .append_option(ts.map(UtcTimeStamp::as_milliseconds));
+    }
+
+    /// Append all timestamps of a slice, without nulls.
+    pub fn append_slice(&mut self, timestamps: &[UtcTimeStamp]) {
+        self.inner.append_slice(as_millis_slice(timestamps));
+    }
+
+    /// Number of slots appended so far.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Whether nothing was appended yet.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Build the array and reset the builder.
+    pub fn finish(&mut self) -> TimestampMillisecondArray {
+        self.inner.finish()
+    }
+}
+
+impl Default for TimestampArrayBuilder {
+    fn default() -> Self {
+        TimestampArrayBuilder::with_capacity(0)
+    }
+}
+
+impl Extend<Option<UtcTimeStamp>> for TimestampArrayBuilder {
+    fn extend<I: IntoIterator<Item = Option<UtcTimeStamp>>>(&mut self, iter: I) {
+        for ts in iter {
+            self.append_option(ts);
+        }
+    }
+}
+
+impl core::iter::FromIterator<Option<UtcTimeStamp>> for TimestampArrayBuilder {
+    fn from_iter<I: IntoIterator<Item = Option<UtcTimeStamp>>>(iter: I) -> Self {
+        let mut builder = TimestampArrayBuilder::default();
+        builder.extend(iter);
+        builder
+    }
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::Array;
+
+    #[test]
+    fn arrays() {
+        let ts: Vec<_> = (-2..3).map(UtcTimeStamp::from_milliseconds).collect();
+        let ptr = ts.as_ptr();
+
+        let array = to_array(ts.clone());
+        assert_eq!(array.timezone(), Some(TIME_ZONE));
+        assert_eq!(array.null_count(), 0);
+        assert_eq!(as_slice(&array), &ts[..]);
+        assert_eq!(as_slice(&to_array(ts)).as_ptr(), ptr);
+
+        let mut builder: TimestampArrayBuilder =
+            vec![Some(UtcTimeStamp::zero()), None].into_iter().collect();
+        builder.append_slice(&[UtcTimeStamp::from_milliseconds(i64::MIN)]);
+        assert_eq!(builder.len(), 3);
+
+        let array = builder.finish();
+        assert!(builder.is_empty());
+        assert_eq!(array.timezone(), Some(TIME_ZONE));
+        assert_eq!(iter(&array).collect::<Vec<_>>(), [
+            Some(UtcTimeStamp::zero()),
+            None,
+            Some(UtcTimeStamp::from_milliseconds(i64::MIN)),
+        ]);
+        assert_eq!(as_slice(&array.slice(2, 1)), &[
+            UtcTimeStamp::from_milliseconds(i64::MIN)
+        ]);
+    }
+}
@@ -0,0 +1,80 @@
+//! Helpers for Avro's `timestamp-millis` and `timestamp-micros` logical types.
+//!
+//! Both logical types annotate a plain `long`, so they map directly onto the
+//! `TimestampMillis(i64)` and `TimestampMicros(i64)` values of Avro libraries
+//! such as `apache-avro`, without depending on any of them.
+//!
+//! Examples:
+//!
+//! ```
+//! use utctimestamp::{avro, UtcTimeStamp};
+//!
+//! let ts = UtcTimeStamp::from_milliseconds(1_600_000_000_250);
+//! assert_eq!(avro::to_timestamp_micros(ts), Ok(1_600_000_000_250_000));
+//! assert_eq!(avro::from_timestamp_micros(1_600_000_000_250_999), ts);
+//! ```
+
+use crate::{OutOfRangeError, Rounding, UtcTimeStamp};
+
+/// Schema of a `long` annotated with the `timestamp-millis` logical type.
+pub const TIMESTAMP_MILLIS_SCHEMA: &str = r#"{"type":"long","logicalType":"timestamp-millis"}"#;
+
+/// Schema of a `long` annotated with the `timestamp-micros` logical type.
+pub const TIMESTAMP_MICROS_SCHEMA: &str = r#"{"type":"long","logicalType":"timestamp-micros"}"#;
+
+/// Convert to a `timestamp-millis` value.
+#[inline]
+pub fn to_timestamp_millis(ts: UtcTimeStamp) -> i64 {
+    ts.as_milliseconds()
+}
+
+/// Convert a `timestamp-millis` value.
+#[inline]
+pub fn from_timestamp_millis(millis: i64) -> UtcTimeStamp {
+    UtcTimeStamp::from_milliseconds(millis)
+}
+
+/// Convert to a `timestamp-micros` value.
+///
+/// Fails for timestamps beyond about ±292,000 years.
+pub fn to_timestamp_micros(ts: UtcTimeStamp) -> Result<i64, OutOfRangeError> {
+    ts.as_milliseconds()
+        .checked_mul(1000)
+        .ok_or(OutOfRangeError)
+}
+
+/// Convert a `timestamp-micros` value, rounding towards the past.
+///
+/// See [`UtcTimeStamp::from_microseconds`] for other rounding modes.
+#[inline]
+pub fn from_timestamp_micros(micros: i64) -> UtcTimeStamp {
+    UtcTimeStamp::from_microseconds(micros, Rounding::Floor)
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn logical_types() {
+        let ts = UtcTimeStamp::from_milliseconds(-1);
+        assert_eq!(to_timestamp_millis(ts), -1);
+        assert_eq!(from_timestamp_millis(-1), ts);
+        assert_eq!(to_timestamp_micros(ts), Ok(-1000));
+        assert_eq!(from_timestamp_micros(-1), ts);
+        assert_eq!(from_timestamp_micros(-1000), ts);
+        assert_eq!(
+            to_timestamp_micros(UtcTimeStamp::from_milliseconds(i64::MAX)),
+            Err(OutOfRangeError)
+        );
+
+        for schema in &[TIMESTAMP_MILLIS_SCHEMA, TIMESTAMP_MICROS_SCHEMA] {
+            let schema: serde_json::Value = serde_json::from_str(schema).unwrap();
+            assert_eq!(schema["type"], "long");
+        }
+    }
+}
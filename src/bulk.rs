@@ -0,0 +1,243 @@
+//! Bulk operations on timestamp slices.
+
+use crate::{TimeDelta, UtcTimeStamp};
+use core::iter;
+
+// ============================================================================================== //
+// [Reinterpretation]                                                                             //
+// ============================================================================================== //
+
+// `UtcTimeStamp` is `repr(transparent)` over `i64`, so slices and vectors of
+// both types share the same layout and the casts below are sound.
+
+/// View a timestamp slice as raw milliseconds since the epoch, without copying.
+#[inline]
+pub fn as_millis_slice(timestamps: &[UtcTimeStamp]) -> &[i64] {
+    unsafe { core::slice::from_raw_parts(timestamps.as_ptr() as *const i64, timestamps.len()) }
+}
+
+/// Mutable variant of [`as_millis_slice`].
+#[inline]
+pub fn as_millis_slice_mut(timestamps: &mut [UtcTimeStamp]) -> &mut [i64] {
+    unsafe {
+        core::slice::from_raw_parts_mut(timestamps.as_mut_ptr() as *mut i64, timestamps.len())
+    }
+}
+
+/// View raw milliseconds since the epoch as timestamps, without copying.
+#[inline]
+pub fn from_millis_slice(millis: &[i64]) -> &[UtcTimeStamp] {
+    unsafe { core::slice::from_raw_parts(millis.as_ptr() as *const UtcTimeStamp, millis.len()) }
+}
+
+/// Mutable variant of [`from_millis_slice`].
+#[inline]
+pub fn from_millis_slice_mut(millis: &mut [i64]) -> &mut [UtcTimeStamp] {
+    unsafe {
+        core::slice::from_raw_parts_mut(millis.as_mut_ptr() as *mut UtcTimeStamp, millis.len())
+    }
+}
+
+/// Convert a timestamp vector into raw milliseconds, reusing the allocation.
+pub fn into_millis_vec(timestamps: Vec<UtcTimeStamp>) -> Vec<i64> {
+    let mut timestamps = core::mem::ManuallyDrop::new(timestamps);
+    let (ptr, len, cap) = (
+        timestamps.as_mut_ptr(),
+        timestamps.len(),
+        timestamps.capacity(),
+    );
+    unsafe { Vec::from_raw_parts(ptr as *mut i64, len, cap) }
+}
+
+/// Convert raw milliseconds into a timestamp vector, reusing the allocation.
+pub fn from_millis_vec(millis: Vec<i64>) -> Vec<UtcTimeStamp> {
+    let mut millis = core::mem::ManuallyDrop::new(millis);
+    let (ptr, len, cap) = (millis.as_mut_ptr(), millis.len(), millis.capacity());
+    unsafe { Vec::from_raw_parts(ptr as *mut UtcTimeStamp, len, cap) }
+}
+
+// ============================================================================================== //
+// [Alignment]                                                                                    //
+// ============================================================================================== //
+
+#[inline(always)]
+fn align_ms(ms: i64, anchor: i64, freq: i64) -> i64 {
+    (ms - anchor) / freq * freq + anchor
+}
+
+/// Align all timestamps of a slice in place.
+///
+/// Equivalent to calling [`UtcTimeStamp::align_to_anchored`] on each element,
+/// but structured as a branch-free loop over fixed size chunks so the compiler
+/// can unroll and vectorize it.
+///
+/// Examples:
+///
+/// ```
+/// use utctimestamp::{align_slice_to, TimeDelta, UtcTimeStamp};
+///
+/// let mut ts = vec![UtcTimeStamp::from_seconds(61), UtcTimeStamp::from_seconds(179)];
+/// align_slice_to(&mut ts, UtcTimeStamp::zero(), TimeDelta::from_minutes(1));
+///
+/// assert_eq!(ts, vec![UtcTimeStamp::from_seconds(60), UtcTimeStamp::from_seconds(120)]);
+/// ```
+pub fn align_slice_to(timestamps: &mut [UtcTimeStamp], anchor: UtcTimeStamp, freq: TimeDelta) {
+    let (anchor, freq) = (anchor.as_milliseconds(), freq.as_milliseconds());
+    assert_ne!(freq, 0, "alignment frequency must not be zero");
+
+    let mut chunks = timestamps.chunks_exact_mut(8);
+    for chunk in &mut chunks {
+        for ts in chunk {
+            *ts = UtcTimeStamp::from_milliseconds(align_ms(ts.as_milliseconds(), anchor, freq));
+        }
+    }
+    for ts in chunks.into_remainder() {
+        *ts = UtcTimeStamp::from_milliseconds(align_ms(ts.as_milliseconds(), anchor, freq));
+    }
+}
+
+/// Align all timestamps of `src`, writing the results to `dst`.
+///
+/// Panics if the slices differ in length. See [`align_slice_to`].
+pub fn align_slice_into(
+    src: &[UtcTimeStamp],
+    dst: &mut [UtcTimeStamp],
+    anchor: UtcTimeStamp,
+    freq: TimeDelta,
+) {
+    assert_eq!(
+        src.len(),
+        dst.len(),
+        "source and destination lengths differ"
+    );
+    let (anchor, freq) = (anchor.as_milliseconds(), freq.as_milliseconds());
+    assert_ne!(freq, 0, "alignment frequency must not be zero");
+
+    let mut src_chunks = src.chunks_exact(8);
+    let mut dst_chunks = dst.chunks_exact_mut(8);
+    for (src, dst) in (&mut src_chunks).zip(&mut dst_chunks) {
+        for (src, dst) in src.iter().zip(dst) {
+            *dst = UtcTimeStamp::from_milliseconds(align_ms(src.as_milliseconds(), anchor, freq));
+        }
+    }
+
+    let tail = src_chunks
+        .remainder()
+        .iter()
+        .zip(dst_chunks.into_remainder());
+    for (src, dst) in tail {
+        *dst = UtcTimeStamp::from_milliseconds(align_ms(src.as_milliseconds(), anchor, freq));
+    }
+}
+
+// ============================================================================================== //
+// [chrono conversion]                                                                            //
+// ============================================================================================== //
+
+type ChronoUtc = chrono::DateTime<chrono::Utc>;
+
+/// Convert a slice of chrono date times into timestamps.
+pub fn from_chrono_slice(dates: &[ChronoUtc]) -> Vec<UtcTimeStamp> {
+    let mut out = Vec::with_capacity(dates.len());
+    out.extend(dates.iter().map(|x| UtcTimeStamp::from(*x)));
+    out
+}
+
+/// Convert a slice of timestamps into chrono date times.
+pub fn to_chrono_vec(timestamps: &[UtcTimeStamp]) -> Vec<ChronoUtc> {
+    let mut out = Vec::with_capacity(timestamps.len());
+    out.extend(timestamps.iter().map(|x| ChronoUtc::from(*x)));
+    out
+}
+
+/// Lazily convert chrono date times into timestamps.
+pub fn from_chrono_iter<I>(dates: I) -> iter::Map<I::IntoIter, fn(ChronoUtc) -> UtcTimeStamp>
+where
+    I: IntoIterator<Item = ChronoUtc>,
+{
+    dates.into_iter().map(UtcTimeStamp::from)
+}
+
+/// Lazily convert timestamps into chrono date times.
+pub fn to_chrono_iter<I>(timestamps: I) -> iter::Map<I::IntoIter, fn(UtcTimeStamp) -> ChronoUtc>
+where
+    I: IntoIterator<Item = UtcTimeStamp>,
+{
+    timestamps.into_iter().map(ChronoUtc::from)
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn align_slices() {
+        let anchor = UtcTimeStamp::from_milliseconds(7);
+        let freq = TimeDelta::from_milliseconds(100);
+        let src: Vec<_> = (-50..1000)
+            .map(|x| UtcTimeStamp::from_milliseconds(x * 37))
+            .collect();
+        let expected: Vec<_> = src
+            .iter()
+            .map(|x| x.align_to_anchored(anchor, freq))
+            .collect();
+
+        let mut dst = vec![UtcTimeStamp::zero(); src.len()];
+        align_slice_into(&src, &mut dst, anchor, freq);
+        assert_eq!(dst, expected);
+
+        let mut inplace = src.clone();
+        align_slice_to(&mut inplace, anchor, freq);
+        assert_eq!(inplace, expected);
+
+        align_slice_to(&mut [], anchor, freq);
+    }
+
+    #[test]
+    fn reinterpret() {
+        let mut ts = vec![
+            UtcTimeStamp::from_milliseconds(1),
+            UtcTimeStamp::from_milliseconds(-2),
+        ];
+        assert_eq!(as_millis_slice(&ts), &[1, -2]);
+        as_millis_slice_mut(&mut ts)[0] = 5;
+        assert_eq!(ts[0], UtcTimeStamp::from_milliseconds(5));
+
+        let mut raw = vec![10, 20];
+        assert_eq!(
+            from_millis_slice(&raw)[1],
+            UtcTimeStamp::from_milliseconds(20)
+        );
+        from_millis_slice_mut(&mut raw)[1] += TimeDelta::from_milliseconds(1);
+        assert_eq!(raw, vec![10, 21]);
+
+        let raw = into_millis_vec(ts);
+        assert_eq!(raw, vec![5, -2]);
+        assert_eq!(from_millis_vec(raw), vec![
+            UtcTimeStamp::from_milliseconds(5),
+            UtcTimeStamp::from_milliseconds(-2),
+        ]);
+    }
+
+    #[test]
+    fn chrono_conversion() {
+        use chrono::{offset::TimeZone, Utc};
+
+        let dates = vec![
+            Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2021, 6, 15, 12, 30, 0).unwrap(),
+        ];
+        let ts = from_chrono_slice(&dates);
+        assert_eq!(ts, vec![
+            UtcTimeStamp::from(dates[0]),
+            UtcTimeStamp::from(dates[1])
+        ]);
+        assert_eq!(to_chrono_vec(&ts), dates);
+        assert_eq!(from_chrono_iter(dates.clone()).collect::<Vec<_>>(), ts);
+        assert_eq!(to_chrono_iter(ts).collect::<Vec<_>>(), dates);
+    }
+}
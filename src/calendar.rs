@@ -0,0 +1,787 @@
+//! Wall-clock and calendar helpers.
+
+use crate::{TimeDelta, UtcTimeStamp};
+use chrono::Weekday;
+use core::fmt;
+use std::collections::BTreeSet;
+
+pub(crate) const MS_PER_DAY: i64 = 24 * 60 * 60 * 1000;
+
+/// Number of days since `1970-01-01` and milliseconds into that day.
+pub(crate) const fn split_day(ts: UtcTimeStamp) -> (i64, i64) {
+    let ms = ts.as_milliseconds();
+    (ms.div_euclid(MS_PER_DAY), ms.rem_euclid(MS_PER_DAY))
+}
+
+/// Weekday of a day counted from `1970-01-01` (a thursday).
+pub(crate) fn weekday_from_days(days: i64) -> Weekday {
+    match (days + 3).rem_euclid(7) {
+        0 => Weekday::Mon,
+        1 => Weekday::Tue,
+        2 => Weekday::Wed,
+        3 => Weekday::Thu,
+        4 => Weekday::Fri,
+        5 => Weekday::Sat,
+        _ => Weekday::Sun,
+    }
+}
+
+// ============================================================================================== //
+// [Civil dates]                                                                                  //
+// ============================================================================================== //
+
+// Proleptic Gregorian calendar arithmetic without going through chrono, see
+// http://howardhinnant.github.io/date_algorithms.html for the derivation.
+
+pub(crate) const fn is_leap_year(year: i64) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+pub(crate) const fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Number of days from `1970-01-01` to the given date.
+pub(crate) const fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year - era * 400;
+    let mp = (month as i64 + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day as i64 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+/// Date of the day `days` after `1970-01-01` as `(year, month, day)`.
+pub(crate) const fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+// ============================================================================================== //
+// [TimeOfDay]                                                                                    //
+// ============================================================================================== //
+
+/// A millisecond precision UTC wall-clock time, e.g. `16:30:00`.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TimeOfDay(u32);
+
+impl TimeOfDay {
+    /// Midnight, `00:00:00`.
+    pub const MIDNIGHT: TimeOfDay = TimeOfDay(0);
+
+    /// Create a time of day from hours, minutes and seconds.
+    ///
+    /// Returns `None` if any of the components is out of range.
+    #[inline]
+    pub const fn from_hms(hour: u32, min: u32, sec: u32) -> Option<Self> {
+        TimeOfDay::from_hms_milli(hour, min, sec, 0)
+    }
+
+    /// Create a time of day from hours, minutes, seconds and milliseconds.
+    ///
+    /// Returns `None` if any of the components is out of range.
+    pub const fn from_hms_milli(hour: u32, min: u32, sec: u32, milli: u32) -> Option<Self> {
+        if hour >= 24 || min >= 60 || sec >= 60 || milli >= 1000 {
+            return None;
+        }
+
+        Some(TimeOfDay(((hour * 60 + min) * 60 + sec) * 1000 + milli))
+    }
+
+    /// Milliseconds since midnight.
+    #[inline]
+    pub const fn as_milliseconds(self) -> u32 {
+        self.0
+    }
+
+    /// Offset from midnight as a timedelta.
+    #[inline]
+    pub const fn since_midnight(self) -> TimeDelta {
+        TimeDelta::from_milliseconds(self.0 as i64)
+    }
+
+    #[inline]
+    pub const fn hour(self) -> u32 {
+        self.0 / 3_600_000
+    }
+
+    #[inline]
+    pub const fn minute(self) -> u32 {
+        self.0 / 60_000 % 60
+    }
+
+    #[inline]
+    pub const fn second(self) -> u32 {
+        self.0 / 1000 % 60
+    }
+
+    #[inline]
+    pub const fn millisecond(self) -> u32 {
+        self.0 % 1000
+    }
+}
+
+impl fmt::Display for TimeOfDay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:02}:{:02}:{:02}",
+            self.hour(),
+            self.minute(),
+            self.second()
+        )?;
+        if self.millisecond() != 0 {
+            write!(f, ".{:03}", self.millisecond())?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for TimeOfDay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "TimeOfDay({})", self)
+    }
+}
+
+/// Create a time of day from a chrono time, truncating to milliseconds.
+///
+/// Leap seconds are clamped to the last millisecond of the minute.
+impl From<chrono::NaiveTime> for TimeOfDay {
+    fn from(other: chrono::NaiveTime) -> Self {
+        use chrono::Timelike;
+        let ms = (other.num_seconds_from_midnight() * 1000 + other.nanosecond() / 1_000_000)
+            .min(MS_PER_DAY as u32 - 1);
+        TimeOfDay(ms)
+    }
+}
+
+/// Create a chrono time from a time of day.
+impl From<TimeOfDay> for chrono::NaiveTime {
+    fn from(other: TimeOfDay) -> Self {
+        chrono::NaiveTime::from_num_seconds_from_midnight_opt(
+            other.0 / 1000,
+            other.millisecond() * 1_000_000,
+        )
+        .expect("time of day is always in range")
+    }
+}
+
+// ============================================================================================== //
+// [Scheduling]                                                                                   //
+// ============================================================================================== //
+
+impl UtcTimeStamp {
+    /// The wall-clock time of the timestamp.
+    pub const fn time_of_day(self) -> TimeOfDay {
+        TimeOfDay(split_day(self).1 as u32)
+    }
+
+    /// The weekday of the timestamp.
+    pub fn weekday(self) -> Weekday {
+        weekday_from_days(split_day(self).0)
+    }
+
+    /// The next timestamp strictly after `self` with the given wall-clock time.
+    ///
+    /// If `self` is exactly at `time`, the occurrence on the following day is returned.
+    /// Panics if the result is out of range; see [`checked_next_at`](Self::checked_next_at).
+    pub const fn next_at(self, time: TimeOfDay) -> UtcTimeStamp {
+        match self.checked_next_at(time) {
+            Some(ts) => ts,
+            None => panic!("next occurrence is out of range"),
+        }
+    }
+
+    /// Like [`next_at`](Self::next_at), but `None` if the result is out of range.
+    pub const fn checked_next_at(self, time: TimeOfDay) -> Option<UtcTimeStamp> {
+        let (days, ms) = split_day(self);
+        let days = if ms < time.0 as i64 { days } else { days + 1 };
+        at_day_and_time(days, time)
+    }
+
+    /// The next timestamp strictly after `self` falling onto the given weekday
+    /// and wall-clock time.
+    ///
+    /// Panics if the result is out of range; see
+    /// [`checked_next_weekday_at`](Self::checked_next_weekday_at).
+    pub fn next_weekday_at(self, weekday: Weekday, time: TimeOfDay) -> UtcTimeStamp {
+        self.checked_next_weekday_at(weekday, time)
+            .expect("next occurrence is out of range")
+    }
+
+    /// Like [`next_weekday_at`](Self::next_weekday_at), but `None` if the
+    /// result is out of range.
+    pub fn checked_next_weekday_at(
+        self,
+        weekday: Weekday,
+        time: TimeOfDay,
+    ) -> Option<UtcTimeStamp> {
+        let (days, ms) = split_day(self);
+        let cur = weekday_from_days(days).num_days_from_monday() as i64;
+        let mut ahead = (weekday.num_days_from_monday() as i64 - cur).rem_euclid(7);
+        if ahead == 0 && ms >= time.0 as i64 {
+            ahead = 7;
+        }
+
+        at_day_and_time(days + ahead, time)
+    }
+}
+
+/// The timestamp at `time` on the given day since the epoch, `None` on overflow.
+const fn at_day_and_time(days: i64, time: TimeOfDay) -> Option<UtcTimeStamp> {
+    match days.checked_mul(MS_PER_DAY) {
+        Some(ms) => match ms.checked_add(time.0 as i64) {
+            Some(ms) => Some(UtcTimeStamp::from_milliseconds(ms)),
+            None => None,
+        },
+        None => None,
+    }
+}
+
+// ============================================================================================== //
+// [Date parts]                                                                                   //
+// ============================================================================================== //
+
+/// A timestamp decomposed into its proleptic Gregorian calendar fields.
+///
+/// Ordering the parts field by field matches the order of the timestamps.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DateParts {
+    pub year: i32,
+    /// Month of the year, `1..=12`.
+    pub month: u32,
+    /// Day of the month, starting at `1`.
+    pub day: u32,
+    pub hour: u32,
+    pub minute: u32,
+    pub second: u32,
+    pub millisecond: u32,
+}
+
+impl UtcTimeStamp {
+    /// Decompose the timestamp into calendar fields.
+    ///
+    /// Examples:
+    ///
+    /// ```
+    /// use utctimestamp::{DateParts, UtcTimeStamp};
+    ///
+    /// let parts = DateParts { year: 2020, month: 9, day: 13, hour: 12, minute: 26, second: 40, millisecond: 250 };
+    /// let ts = UtcTimeStamp::from_parts(parts).unwrap();
+    ///
+    /// assert_eq!(ts.as_milliseconds(), 1_600_000_000_250);
+    /// assert_eq!(ts.to_parts(), parts);
+    /// assert_eq!(UtcTimeStamp::from_parts(DateParts { day: 31, ..parts }), None);
+    /// ```
+    pub const fn to_parts(self) -> DateParts {
+        let (days, ms) = split_day(self);
+        let (year, month, day) = civil_from_days(days);
+        let time = TimeOfDay(ms as u32);
+        DateParts {
+            year: year as i32,
+            month,
+            day,
+            hour: time.hour(),
+            minute: time.minute(),
+            second: time.second(),
+            millisecond: time.millisecond(),
+        }
+    }
+
+    /// Compose a timestamp from calendar fields.
+    ///
+    /// Returns `None` if any of the fields is out of range, e.g. for the
+    /// 30th of February, or if the timestamp is not representable.
+    pub const fn from_parts(parts: DateParts) -> Option<Self> {
+        let valid_day =
+            parts.day >= 1 && parts.day <= days_in_month(parts.year as i64, parts.month);
+        if parts.month < 1 || parts.month > 12 || !valid_day {
+            return None;
+        }
+
+        let time = match TimeOfDay::from_hms_milli(
+            parts.hour,
+            parts.minute,
+            parts.second,
+            parts.millisecond,
+        ) {
+            Some(time) => time,
+            None => return None,
+        };
+
+        // The day start alone may underflow for the earliest representable day.
+        let days = days_from_civil(parts.year as i64, parts.month, parts.day);
+        let ms = days as i128 * MS_PER_DAY as i128 + time.0 as i128;
+        if ms < i64::MIN as i128 || ms > i64::MAX as i128 {
+            return None;
+        }
+
+        Some(UtcTimeStamp::from_milliseconds(ms as i64))
+    }
+}
+
+// ============================================================================================== //
+// [BusinessCalendar]                                                                             //
+// ============================================================================================== //
+
+/// Days since `1970-01-01` of a chrono date.
+fn days_from_date(date: chrono::NaiveDate) -> i64 {
+    let epoch = chrono::NaiveDate::from_ymd_opt(1970, 1, 1).unwrap();
+    date.signed_duration_since(epoch).num_days()
+}
+
+/// A calendar of business days, defined by a set of weekend days and holidays.
+///
+/// The default calendar treats saturdays and sundays as weekend and has no holidays.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BusinessCalendar {
+    /// Indexed by `Weekday::num_days_from_monday`.
+    weekend: [bool; 7],
+    /// Holidays as days since `1970-01-01`.
+    holidays: BTreeSet<i64>,
+}
+
+impl Default for BusinessCalendar {
+    fn default() -> Self {
+        BusinessCalendar::new(&[Weekday::Sat, Weekday::Sun], None)
+    }
+}
+
+impl BusinessCalendar {
+    /// Create a calendar with custom weekend days and holidays.
+    pub fn new(weekend: &[Weekday], holidays: impl IntoIterator<Item = chrono::NaiveDate>) -> Self {
+        let mut mask = [false; 7];
+        for day in weekend {
+            mask[day.num_days_from_monday() as usize] = true;
+        }
+
+        BusinessCalendar {
+            weekend: mask,
+            holidays: holidays.into_iter().map(days_from_date).collect(),
+        }
+    }
+
+    /// Mark an additional day as holiday.
+    pub fn add_holiday(&mut self, date: chrono::NaiveDate) {
+        self.holidays.insert(days_from_date(date));
+    }
+
+    /// Check whether the day containing the timestamp is a business day.
+    pub fn is_business_day(&self, ts: UtcTimeStamp) -> bool {
+        self.is_business_day_idx(split_day(ts).0)
+    }
+
+    fn is_business_day_idx(&self, days: i64) -> bool {
+        let weekday = weekday_from_days(days).num_days_from_monday() as usize;
+        !self.weekend[weekday] && !self.holidays.contains(&days)
+    }
+
+    /// Count the business days in the day index range `[from, to)`, `from <= to`.
+    fn count_days(&self, from: i64, to: i64) -> i64 {
+        let per_week = self.weekend.iter().filter(|x| !**x).count() as i64;
+        let weeks = (to - from) / 7;
+        let mut count = weeks * per_week;
+        for day in from + weeks * 7..to {
+            let weekday = weekday_from_days(day).num_days_from_monday() as usize;
+            count += !self.weekend[weekday] as i64;
+        }
+
+        let holidays = self.holidays.range(from..to);
+        count - holidays.filter(|x| self.is_weekday_open(**x)).count() as i64
+    }
+
+    fn is_weekday_open(&self, days: i64) -> bool {
+        !self.weekend[weekday_from_days(days).num_days_from_monday() as usize]
+    }
+
+    /// Number of business days between the days containing `a` and `b`.
+    ///
+    /// The day of `a` is included, the day of `b` isn't. The result is negative
+    /// if `b` lies before `a`.
+    pub fn business_days_between(&self, a: UtcTimeStamp, b: UtcTimeStamp) -> i64 {
+        let (from, to) = (split_day(a).0, split_day(b).0);
+        if from <= to {
+            self.count_days(from, to)
+        } else {
+            -self.count_days(to, from)
+        }
+    }
+
+    /// Number of business days between `a` and `b`, counting partial days.
+    ///
+    /// Only time that falls onto business days contributes to the result. The
+    /// result is negative if `b` lies before `a`.
+    pub fn business_days_between_frac(&self, a: UtcTimeStamp, b: UtcTimeStamp) -> f64 {
+        if b < a {
+            return -self.business_days_between_frac(b, a);
+        }
+
+        let ((day_a, ms_a), (day_b, ms_b)) = (split_day(a), split_day(b));
+        let frac = |days, ms| {
+            if self.is_business_day_idx(days) {
+                ms as f64 / MS_PER_DAY as f64
+            } else {
+                0.0
+            }
+        };
+
+        if day_a == day_b {
+            return frac(day_a, ms_b - ms_a);
+        }
+
+        let middle = self.count_days(day_a + 1, day_b) as f64;
+        frac(day_a, MS_PER_DAY - ms_a) + middle + frac(day_b, ms_b)
+    }
+}
+
+// ============================================================================================== //
+// [FiscalCalendar]                                                                               //
+// ============================================================================================== //
+
+/// A fiscal calendar with a custom year start.
+///
+/// Fiscal years are labelled by the calendar year they end in, so with a year
+/// start of October 1st, fiscal year 2024 spans `2023-10-01` up to `2024-10-01`.
+/// Quarters are three months long, starting at the fiscal year start.
+///
+/// All period ends are exclusive, i.e. the start of the following period.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FiscalCalendar {
+    month: u32,
+    day: u32,
+}
+
+impl Default for FiscalCalendar {
+    /// A fiscal calendar matching the calendar year.
+    fn default() -> Self {
+        FiscalCalendar { month: 1, day: 1 }
+    }
+}
+
+impl FiscalCalendar {
+    /// Create a fiscal calendar whose years start on the given month and day.
+    ///
+    /// To keep all quarters well defined, `day` is limited to `1..=28`.
+    pub const fn new(month: u32, day: u32) -> Option<Self> {
+        if month < 1 || month > 12 || day < 1 || day > 28 {
+            return None;
+        }
+
+        Some(FiscalCalendar { month, day })
+    }
+
+    /// Difference between a fiscal year's label and the calendar year it begins in.
+    const fn label_offset(self) -> i32 {
+        if self.month == 1 && self.day == 1 {
+            0
+        } else {
+            1
+        }
+    }
+
+    /// Calculate the start date of the `n`-th month of the fiscal year.
+    fn month_start(self, fy: i32, n: u32) -> chrono::NaiveDate {
+        use chrono::Datelike;
+        let first = chrono::NaiveDate::from_ymd_opt(fy - self.label_offset(), self.month, self.day)
+            .expect("fiscal year out of range");
+        first
+            .checked_add_months(chrono::Months::new(n))
+            .expect("fiscal year out of range")
+            .with_day(self.day)
+            .expect("day is always valid")
+    }
+
+    /// Fiscal year and zero based month within it.
+    fn locate(self, ts: UtcTimeStamp) -> (i32, u32) {
+        use chrono::Datelike;
+        let date = chrono::DateTime::<chrono::Utc>::from(ts).date_naive();
+        let mut months = date.month0() as i32 - (self.month as i32 - 1);
+        if date.day() < self.day {
+            months -= 1;
+        }
+
+        let start_year = date.year() + months.div_euclid(12);
+        (
+            start_year + self.label_offset(),
+            months.rem_euclid(12) as u32,
+        )
+    }
+
+    /// The fiscal year containing the timestamp.
+    pub fn fiscal_year(self, ts: UtcTimeStamp) -> i32 {
+        self.locate(ts).0
+    }
+
+    /// The fiscal quarter (`1..=4`) containing the timestamp.
+    pub fn fiscal_quarter(self, ts: UtcTimeStamp) -> u32 {
+        self.locate(ts).1 / 3 + 1
+    }
+
+    /// The start of the fiscal year `fy`.
+    pub fn year_start(self, fy: i32) -> UtcTimeStamp {
+        date_to_ts(self.month_start(fy, 0))
+    }
+
+    /// The end of the fiscal year `fy`, exclusive.
+    pub fn year_end(self, fy: i32) -> UtcTimeStamp {
+        date_to_ts(self.month_start(fy, 12))
+    }
+
+    /// The start of quarter `q` (`1..=4`) of the fiscal year `fy`.
+    pub fn quarter_start(self, fy: i32, q: u32) -> UtcTimeStamp {
+        assert!((1..=4).contains(&q), "quarter out of range");
+        date_to_ts(self.month_start(fy, (q - 1) * 3))
+    }
+
+    /// The end of quarter `q` (`1..=4`) of the fiscal year `fy`, exclusive.
+    pub fn quarter_end(self, fy: i32, q: u32) -> UtcTimeStamp {
+        assert!((1..=4).contains(&q), "quarter out of range");
+        date_to_ts(self.month_start(fy, q * 3))
+    }
+}
+
+fn date_to_ts(date: chrono::NaiveDate) -> UtcTimeStamp {
+    date.and_time(chrono::NaiveTime::MIN).and_utc().into()
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use chrono::{offset::TimeZone, NaiveTime, Utc, Weekday};
+
+    fn ts(y: i32, m: u32, d: u32, h: u32, mi: u32, s: u32) -> UtcTimeStamp {
+        Utc.with_ymd_and_hms(y, m, d, h, mi, s).unwrap().into()
+    }
+
+    #[test]
+    fn time_of_day() {
+        let tod = TimeOfDay::from_hms_milli(16, 30, 5, 12).unwrap();
+        assert_eq!(
+            (tod.hour(), tod.minute(), tod.second(), tod.millisecond()),
+            (16, 30, 5, 12)
+        );
+        assert_eq!(tod.to_string(), "16:30:05.012");
+        assert_eq!(TimeOfDay::from(NaiveTime::from(tod)), tod);
+        assert_eq!(TimeOfDay::from_hms(24, 0, 0), None);
+        assert_eq!(
+            ts(2023, 5, 1, 16, 30, 5).time_of_day(),
+            TimeOfDay::from_hms(16, 30, 5).unwrap()
+        );
+        assert_eq!(
+            ts(1969, 12, 31, 23, 0, 0).time_of_day(),
+            TimeOfDay::from_hms(23, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn next_at() {
+        let settle = TimeOfDay::from_hms(16, 30, 0).unwrap();
+        assert_eq!(
+            ts(2024, 2, 29, 9, 0, 0).next_at(settle),
+            ts(2024, 2, 29, 16, 30, 0)
+        );
+        assert_eq!(
+            ts(2024, 2, 29, 16, 30, 0).next_at(settle),
+            ts(2024, 3, 1, 16, 30, 0)
+        );
+        assert_eq!(
+            ts(2024, 12, 31, 23, 59, 59).next_at(settle),
+            ts(2025, 1, 1, 16, 30, 0)
+        );
+        assert_eq!(
+            ts(1969, 12, 31, 20, 0, 0).next_at(settle),
+            ts(1970, 1, 1, 16, 30, 0)
+        );
+
+        let max = UtcTimeStamp::from_milliseconds(i64::MAX);
+        assert_eq!(max.checked_next_at(settle), None);
+        let min = UtcTimeStamp::from_milliseconds(i64::MIN);
+        assert!(min.checked_next_at(TimeOfDay::MIDNIGHT).is_some());
+    }
+
+    #[test]
+    fn next_weekday_at() {
+        let open = TimeOfDay::from_hms(9, 30, 0).unwrap();
+        assert_eq!(ts(1970, 1, 1, 0, 0, 0).weekday(), Weekday::Thu);
+        assert_eq!(ts(1969, 12, 29, 0, 0, 0).weekday(), Weekday::Mon);
+
+        // Wednesday.
+        let wed = ts(2024, 1, 3, 12, 0, 0);
+        assert_eq!(
+            wed.next_weekday_at(Weekday::Mon, open),
+            ts(2024, 1, 8, 9, 30, 0)
+        );
+        assert_eq!(
+            wed.next_weekday_at(Weekday::Wed, open),
+            ts(2024, 1, 10, 9, 30, 0)
+        );
+        assert_eq!(
+            wed.next_weekday_at(Weekday::Wed, TimeOfDay::from_hms(18, 0, 0).unwrap()),
+            ts(2024, 1, 3, 18, 0, 0),
+        );
+
+        let max = UtcTimeStamp::from_milliseconds(i64::MAX);
+        assert_eq!(max.checked_next_weekday_at(Weekday::Mon, open), None);
+    }
+
+    #[test]
+    fn business_days_between() {
+        let mut cal = BusinessCalendar::default();
+        // Mon 2024-01-01 .. Mon 2024-01-15
+        let (a, b) = (ts(2024, 1, 1, 10, 0, 0), ts(2024, 1, 15, 10, 0, 0));
+        assert_eq!(cal.business_days_between(a, b), 10);
+        assert_eq!(cal.business_days_between(b, a), -10);
+        assert_eq!(cal.business_days_between(a, a), 0);
+
+        cal.add_holiday(chrono::NaiveDate::from_ymd_opt(2024, 1, 1).unwrap());
+        cal.add_holiday(chrono::NaiveDate::from_ymd_opt(2024, 1, 6).unwrap()); // saturday
+        assert_eq!(cal.business_days_between(a, b), 9);
+        assert!(!cal.is_business_day(a));
+        assert!(cal.is_business_day(b));
+
+        let cal = BusinessCalendar::new(&[Weekday::Fri, Weekday::Sat], None);
+        assert_eq!(
+            cal.business_days_between(ts(2024, 1, 4, 0, 0, 0), ts(2024, 1, 8, 0, 0, 0)),
+            2
+        );
+    }
+
+    #[test]
+    fn business_days_between_frac() {
+        let cal = BusinessCalendar::default();
+        // Fri 18:00 .. Mon 06:00 covers a quarter of each business day.
+        let (a, b) = (ts(2024, 1, 5, 18, 0, 0), ts(2024, 1, 8, 6, 0, 0));
+        assert_eq!(cal.business_days_between_frac(a, b), 0.5);
+        assert_eq!(cal.business_days_between_frac(b, a), -0.5);
+
+        let (a, b) = (ts(2024, 1, 2, 6, 0, 0), ts(2024, 1, 2, 18, 0, 0));
+        assert_eq!(cal.business_days_between_frac(a, b), 0.5);
+
+        let (a, b) = (ts(2024, 1, 1, 12, 0, 0), ts(2024, 1, 10, 12, 0, 0));
+        assert_eq!(cal.business_days_between_frac(a, b), 7.0);
+    }
+
+    #[test]
+    fn fiscal_calendar() {
+        let us_gov = FiscalCalendar::new(10, 1).unwrap();
+        assert_eq!(us_gov.fiscal_year(ts(2023, 9, 30, 23, 59, 59)), 2023);
+        assert_eq!(us_gov.fiscal_year(ts(2023, 10, 1, 0, 0, 0)), 2024);
+        assert_eq!(us_gov.fiscal_quarter(ts(2023, 10, 1, 0, 0, 0)), 1);
+        assert_eq!(us_gov.fiscal_quarter(ts(2024, 2, 15, 0, 0, 0)), 2);
+        assert_eq!(us_gov.fiscal_quarter(ts(2024, 9, 30, 0, 0, 0)), 4);
+        assert_eq!(us_gov.year_start(2024), ts(2023, 10, 1, 0, 0, 0));
+        assert_eq!(us_gov.year_end(2024), ts(2024, 10, 1, 0, 0, 0));
+        assert_eq!(us_gov.quarter_start(2024, 2), ts(2024, 1, 1, 0, 0, 0));
+        assert_eq!(us_gov.quarter_end(2024, 4), ts(2024, 10, 1, 0, 0, 0));
+
+        let uk = FiscalCalendar::new(4, 6).unwrap();
+        assert_eq!(uk.fiscal_year(ts(2024, 4, 5, 12, 0, 0)), 2024);
+        assert_eq!(uk.fiscal_year(ts(2024, 4, 6, 0, 0, 0)), 2025);
+        assert_eq!(uk.fiscal_quarter(ts(2024, 7, 5, 0, 0, 0)), 1);
+        assert_eq!(uk.fiscal_quarter(ts(2024, 7, 6, 0, 0, 0)), 2);
+
+        let calendar = FiscalCalendar::default();
+        assert_eq!(calendar.fiscal_year(ts(2024, 1, 1, 0, 0, 0)), 2024);
+        assert_eq!(calendar.fiscal_year(ts(2023, 12, 31, 0, 0, 0)), 2023);
+        assert_eq!(calendar.fiscal_quarter(ts(2023, 12, 31, 0, 0, 0)), 4);
+        assert_eq!(calendar.quarter_start(2023, 3), ts(2023, 7, 1, 0, 0, 0));
+
+        assert_eq!(FiscalCalendar::new(2, 29), None);
+        assert_eq!(FiscalCalendar::new(13, 1), None);
+    }
+
+    #[test]
+    fn date_parts() {
+        let parts = |year, month, day, hour, minute, second, millisecond| DateParts {
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+            millisecond,
+        };
+
+        let dt = Utc.with_ymd_and_hms(1969, 12, 31, 23, 59, 59).unwrap();
+        let ts = UtcTimeStamp::from(dt) + TimeDelta::from_milliseconds(999);
+        assert_eq!(ts.to_parts(), parts(1969, 12, 31, 23, 59, 59, 999));
+        assert_eq!(UtcTimeStamp::from_parts(ts.to_parts()), Some(ts));
+        assert_eq!(
+            UtcTimeStamp::from_parts(parts(2000, 2, 29, 0, 0, 0, 0)),
+            Some(ts!("2000-02-29T00:00:00Z"))
+        );
+
+        for &(month, day) in &[(0, 1), (13, 1), (2, 30), (4, 31), (1, 0)] {
+            assert_eq!(
+                UtcTimeStamp::from_parts(parts(2000, month, day, 0, 0, 0, 0)),
+                None
+            );
+        }
+        assert_eq!(
+            UtcTimeStamp::from_parts(parts(1900, 2, 29, 0, 0, 0, 0)),
+            None
+        );
+        assert_eq!(
+            UtcTimeStamp::from_parts(parts(2000, 1, 1, 24, 0, 0, 0)),
+            None
+        );
+        assert_eq!(
+            UtcTimeStamp::from_parts(parts(2000, 1, 1, 0, 0, 0, 1000)),
+            None
+        );
+        assert_eq!(
+            UtcTimeStamp::from_parts(parts(i32::MAX, 1, 1, 0, 0, 0, 0)),
+            None
+        );
+
+        for &ms in &[i64::MIN, -1, 0, 1_600_000_000_250, i64::MAX] {
+            let ts = UtcTimeStamp::from_milliseconds(ms);
+            assert_eq!(UtcTimeStamp::from_parts(ts.to_parts()), Some(ts));
+        }
+    }
+
+    #[test]
+    fn civil_dates() {
+        use super::{civil_from_days, days_from_civil, days_in_month};
+        use chrono::NaiveDate;
+
+        let epoch = NaiveDate::from_ymd_opt(1970, 1, 1).unwrap();
+        for &(y, m, d) in &[
+            (1970, 1, 1),
+            (2000, 2, 29),
+            (1900, 3, 1),
+            (-1, 12, 31),
+            (2400, 12, 31),
+        ] {
+            let date = NaiveDate::from_ymd_opt(y, m, d).unwrap();
+            let days = (date - epoch).num_days();
+            assert_eq!(days_from_civil(y as i64, m, d), days);
+            assert_eq!(civil_from_days(days), (y as i64, m, d));
+        }
+        for days in -800_000..800_000 {
+            let (y, m, d) = civil_from_days(days);
+            assert_eq!(days_from_civil(y, m, d), days);
+        }
+        assert_eq!(days_in_month(2000, 2), 29);
+        assert_eq!(days_in_month(1900, 2), 28);
+        assert_eq!(days_in_month(2023, 4), 30);
+    }
+}
@@ -0,0 +1,331 @@
+//! Delta-of-delta bit packing, as described in Facebook's Gorilla paper.
+//!
+//! Each timestamp is stored as the change of its delta to the previous one,
+//! using a variable length prefix code:
+//!
+//! | delta-of-delta       | encoding               |
+//! |----------------------|------------------------|
+//! | `0`                  | `0`                    |
+//! | `-64..=63`           | `10` + 7 bit value     |
+//! | `-256..=255`         | `110` + 9 bit value    |
+//! | `-2048..=2047`       | `1110` + 12 bit value  |
+//! | anything else        | `1111` + 64 bit value  |
+//!
+//! Unlike the paper, the buckets are plain two's complement ranges and the
+//! fallback stores the full 64 bits, so arbitrary millisecond timestamps
+//! round-trip. The stream starts with the number of timestamps (64 bits) and
+//! the first timestamp (64 bits). Near-regular series take about 1-2 bits per
+//! timestamp.
+//!
+//! Examples:
+//!
+//! ```
+//! use utctimestamp::{codec::gorilla, UtcTimeStamp};
+//!
+//! let ts: Vec<_> = (0..1000).map(|i| UtcTimeStamp::from_seconds(1_600_000_000 + i)).collect();
+//! let bytes = gorilla::encode(&ts);
+//!
+//! assert!(bytes.len() < 16 + 1000 / 8 + 4);
+//! assert_eq!(gorilla::decode(&bytes).unwrap(), ts);
+//! ```
+
+use super::DecodeError;
+use crate::UtcTimeStamp;
+
+/// `(prefix, prefix bits, value bits)` of the non-zero buckets, in order of preference.
+const BUCKETS: [(u64, u32, u32); 4] = [
+    (0b10, 2, 7),
+    (0b110, 3, 9),
+    (0b1110, 4, 12),
+    (0b1111, 4, 64),
+];
+
+// ============================================================================================== //
+// [Bit I/O]                                                                                      //
+// ============================================================================================== //
+
+/// MSB-first bit writer.
+#[derive(Debug, Default)]
+struct BitWriter {
+    out: Vec<u8>,
+    acc: u128,
+    len: u32,
+}
+
+impl BitWriter {
+    fn write(&mut self, value: u64, bits: u32) {
+        let mask = if bits == 64 {
+            u64::MAX
+        } else {
+            (1 << bits) - 1
+        };
+        self.acc = (self.acc << bits) | u128::from(value & mask);
+        self.len += bits;
+
+        while self.len >= 8 {
+            self.len -= 8;
+            self.out.push((self.acc >> self.len) as u8);
+        }
+        self.acc &= (1 << self.len) - 1;
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.len > 0 {
+            self.out.push((self.acc << (8 - self.len)) as u8);
+        }
+        self.out
+    }
+}
+
+/// MSB-first bit reader.
+#[derive(Debug, Clone)]
+struct BitReader<'a> {
+    input: &'a [u8],
+    pos: usize,
+}
+
+impl BitReader<'_> {
+    fn read(&mut self, bits: u32) -> Result<u64, DecodeError> {
+        let mut value = 0u64;
+        let mut remaining = bits;
+        while remaining > 0 {
+            let byte = *self.input.get(self.pos / 8).ok_or(DecodeError::Truncated)?;
+            let avail = 8 - (self.pos % 8) as u32;
+            let take = avail.min(remaining);
+            let chunk = (u32::from(byte) >> (avail - take)) & ((1 << take) - 1);
+            value = (value << take) | u64::from(chunk);
+            self.pos += take as usize;
+            remaining -= take;
+        }
+
+        Ok(value)
+    }
+}
+
+/// Interpret the lower `bits` bits of `value` as two's complement number.
+#[inline]
+fn sign_extend(value: u64, bits: u32) -> i64 {
+    ((value << (64 - bits)) as i64) >> (64 - bits)
+}
+
+// ============================================================================================== //
+// [Bulk API]                                                                                     //
+// ============================================================================================== //
+
+/// Encode a slice of timestamps.
+pub fn encode(timestamps: &[UtcTimeStamp]) -> Vec<u8> {
+    let mut enc = Encoder::new();
+    for ts in timestamps {
+        enc.push(*ts);
+    }
+    enc.finish()
+}
+
+/// Decode a buffer produced by [`encode`].
+pub fn decode(input: &[u8]) -> Result<Vec<UtcTimeStamp>, DecodeError> {
+    Decoder::new(input)?.collect()
+}
+
+// ============================================================================================== //
+// [Streaming API]                                                                                //
+// ============================================================================================== //
+
+/// Streaming encoder, producing the buffer on [`Encoder::finish`].
+#[derive(Debug)]
+pub struct Encoder {
+    bits: BitWriter,
+    count: u64,
+    prev: i64,
+    prev_delta: i64,
+}
+
+impl Default for Encoder {
+    fn default() -> Self {
+        Encoder::new()
+    }
+}
+
+impl Encoder {
+    pub fn new() -> Self {
+        let mut bits = BitWriter::default();
+        // Placeholder for the count, patched in `finish`.
+        bits.write(0, 64);
+        Encoder {
+            bits,
+            count: 0,
+            prev: 0,
+            prev_delta: 0,
+        }
+    }
+
+    /// Encode the next timestamp.
+    pub fn push(&mut self, ts: UtcTimeStamp) {
+        let ms = ts.as_milliseconds();
+        if self.count == 0 {
+            self.bits.write(ms as u64, 64);
+        } else {
+            let delta = ms.wrapping_sub(self.prev);
+            let dod = delta.wrapping_sub(self.prev_delta);
+            if dod == 0 {
+                self.bits.write(0, 1);
+            } else {
+                let (prefix, prefix_bits, bits) = BUCKETS
+                    .iter()
+                    .copied()
+                    .find(|(_, _, bits)| *bits == 64 || sign_extend(dod as u64, *bits) == dod)
+                    .expect("last bucket fits everything");
+                self.bits.write(prefix, prefix_bits);
+                self.bits.write(dod as u64, bits);
+            }
+            self.prev_delta = delta;
+        }
+
+        self.prev = ms;
+        self.count += 1;
+    }
+
+    /// Number of timestamps encoded so far.
+    pub fn len(&self) -> u64 {
+        self.count
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Finish the stream, returning the encoded bytes.
+    pub fn finish(self) -> Vec<u8> {
+        let count = self.count;
+        let mut out = self.bits.finish();
+        out[..8].copy_from_slice(&count.to_be_bytes());
+        out
+    }
+}
+
+/// Streaming decoder yielding timestamps from a buffer.
+///
+/// After an error, the decoder yields `None`.
+#[derive(Debug, Clone)]
+pub struct Decoder<'a> {
+    bits: BitReader<'a>,
+    remaining: u64,
+    prev: i64,
+    prev_delta: Option<i64>,
+}
+
+impl<'a> Decoder<'a> {
+    /// Create a decoder, reading the stream header.
+    pub fn new(input: &'a [u8]) -> Result<Self, DecodeError> {
+        let mut bits = BitReader { input, pos: 0 };
+        let remaining = bits.read(64)?;
+        Ok(Decoder {
+            bits,
+            remaining,
+            prev: 0,
+            prev_delta: None,
+        })
+    }
+
+    fn read_next(&mut self) -> Result<UtcTimeStamp, DecodeError> {
+        let ms = match self.prev_delta {
+            None => {
+                self.prev_delta = Some(0);
+                self.bits.read(64)? as i64
+            }
+            Some(prev_delta) => {
+                let dod = if self.bits.read(1)? == 0 {
+                    0
+                } else {
+                    let bits = if self.bits.read(1)? == 0 {
+                        7
+                    } else if self.bits.read(1)? == 0 {
+                        9
+                    } else if self.bits.read(1)? == 0 {
+                        12
+                    } else {
+                        64
+                    };
+                    sign_extend(self.bits.read(bits)?, bits)
+                };
+
+                let delta = prev_delta.wrapping_add(dod);
+                self.prev_delta = Some(delta);
+                self.prev.wrapping_add(delta)
+            }
+        };
+
+        self.prev = ms;
+        Ok(UtcTimeStamp::from_milliseconds(ms))
+    }
+}
+
+impl Iterator for Decoder<'_> {
+    type Item = Result<UtcTimeStamp, DecodeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        self.remaining -= 1;
+        let result = self.read_next();
+        if result.is_err() {
+            self.remaining = 0;
+        }
+        Some(result)
+    }
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ts(ms: &[i64]) -> Vec<UtcTimeStamp> {
+        ms.iter()
+            .map(|&x| UtcTimeStamp::from_milliseconds(x))
+            .collect()
+    }
+
+    #[test]
+    fn roundtrip() {
+        let cases = [
+            ts(&[]),
+            ts(&[42]),
+            ts(&[0, 1000, 2000, 3001, 3999, 5000, 5100, 5000, 9000, 400_000]),
+            ts(&[i64::MIN, i64::MAX, 0, -1, i64::MAX, i64::MIN]),
+            ts(&[10, 10, 10, 73, 74, 330, 331, 2378, 2379]),
+        ];
+
+        for case in &cases {
+            assert_eq!(decode(&encode(case)).as_ref(), Ok(case));
+        }
+    }
+
+    #[test]
+    fn compact() {
+        // 10k samples at 250ms with occasional 1ms jitter.
+        let ms: Vec<_> = (0..10_000)
+            .map(|i| 1_700_000_000_000 + i * 250 + (i % 97 == 0) as i64)
+            .collect();
+        let bytes = encode(&ts(&ms));
+        let bits_per_sample = (bytes.len() - 16) as f64 * 8.0 / ms.len() as f64;
+        assert!(bits_per_sample < 1.5, "{}", bits_per_sample);
+    }
+
+    #[test]
+    fn malformed() {
+        assert_eq!(decode(&[0; 4]), Err(DecodeError::Truncated));
+
+        let mut bytes = encode(&ts(&[1, 2, 3]));
+        bytes.truncate(15);
+        assert_eq!(decode(&bytes), Err(DecodeError::Truncated));
+
+        let mut dec = Decoder::new(&bytes).unwrap();
+        assert_eq!(dec.next(), Some(Err(DecodeError::Truncated)));
+        assert_eq!(dec.next(), None);
+    }
+}
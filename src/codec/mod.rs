@@ -0,0 +1,29 @@
+//! Compact binary encodings for timestamp columns.
+//!
+//! - [`varint`]: delta + zigzag varint encoding, suitable for any sorted series
+//! - [`gorilla`]: delta-of-delta bit packing, best for near-regular series
+
+use core::fmt;
+
+pub mod gorilla;
+pub mod varint;
+
+/// Error returned when decoding malformed or truncated input.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DecodeError {
+    /// The input ended in the middle of a value.
+    Truncated,
+    /// A value doesn't fit into 64 bits.
+    Overflow,
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            DecodeError::Truncated => "input truncated",
+            DecodeError::Overflow => "encoded value overflows 64 bits",
+        })
+    }
+}
+
+impl std::error::Error for DecodeError {}
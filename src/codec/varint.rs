@@ -0,0 +1,213 @@
+//! Delta + varint encoding.
+//!
+//! The first timestamp is stored as is, every following one as the difference
+//! to its predecessor. All values are zigzag encoded LEB128 varints, so sorted
+//! millisecond timestamps with regular spacing typically take 1-3 bytes each.
+//! Unsorted input is supported, but compresses worse.
+//!
+//! Examples:
+//!
+//! ```
+//! use utctimestamp::{codec::varint, UtcTimeStamp};
+//!
+//! let ts: Vec<_> = (0..1000).map(|i| UtcTimeStamp::from_seconds(1_600_000_000 + i)).collect();
+//! let bytes = varint::encode(&ts);
+//!
+//! assert!(bytes.len() < ts.len() * 3);
+//! assert_eq!(varint::decode(&bytes).unwrap(), ts);
+//! ```
+
+use super::DecodeError;
+use crate::UtcTimeStamp;
+use std::io;
+
+#[inline]
+fn zigzag(x: i64) -> u64 {
+    ((x << 1) ^ (x >> 63)) as u64
+}
+
+#[inline]
+fn unzigzag(x: u64) -> i64 {
+    (x >> 1) as i64 ^ -((x & 1) as i64)
+}
+
+/// Append a LEB128 varint to `out`, returning the number of bytes written.
+fn write_varint(mut x: u64, out: &mut [u8; 10]) -> usize {
+    let mut len = 0;
+    loop {
+        let byte = (x & 0x7f) as u8;
+        x >>= 7;
+        if x == 0 {
+            out[len] = byte;
+            return len + 1;
+        }
+        out[len] = byte | 0x80;
+        len += 1;
+    }
+}
+
+/// Read a LEB128 varint, advancing `input`.
+fn read_varint(input: &mut &[u8]) -> Result<u64, DecodeError> {
+    let mut x = 0u64;
+    for (i, &byte) in input.iter().enumerate() {
+        if i == 9 && byte > 1 {
+            return Err(DecodeError::Overflow);
+        }
+
+        x |= u64::from(byte & 0x7f) << (7 * i);
+        if byte & 0x80 == 0 {
+            *input = &input[i + 1..];
+            return Ok(x);
+        }
+    }
+
+    Err(DecodeError::Truncated)
+}
+
+// ============================================================================================== //
+// [Bulk API]                                                                                     //
+// ============================================================================================== //
+
+/// Encode a slice of timestamps.
+pub fn encode(timestamps: &[UtcTimeStamp]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(timestamps.len() * 2);
+    encode_into(timestamps, &mut out);
+    out
+}
+
+/// Encode a slice of timestamps, appending to `out`.
+pub fn encode_into(timestamps: &[UtcTimeStamp], out: &mut Vec<u8>) {
+    let mut enc = Encoder::new(out);
+    for ts in timestamps {
+        enc.write(*ts).expect("writing to a Vec never fails");
+    }
+}
+
+/// Decode a buffer produced by [`encode`].
+pub fn decode(input: &[u8]) -> Result<Vec<UtcTimeStamp>, DecodeError> {
+    Decoder::new(input).collect()
+}
+
+// ============================================================================================== //
+// [Streaming API]                                                                                //
+// ============================================================================================== //
+
+/// Streaming encoder writing into any `io::Write`.
+#[derive(Debug)]
+pub struct Encoder<W> {
+    writer: W,
+    prev: i64,
+}
+
+impl<W: io::Write> Encoder<W> {
+    pub fn new(writer: W) -> Self {
+        Encoder { writer, prev: 0 }
+    }
+
+    /// Encode the next timestamp.
+    pub fn write(&mut self, ts: UtcTimeStamp) -> io::Result<()> {
+        let ms = ts.as_milliseconds();
+        let mut buf = [0; 10];
+        let len = write_varint(zigzag(ms.wrapping_sub(self.prev)), &mut buf);
+        self.writer.write_all(&buf[..len])?;
+        self.prev = ms;
+        Ok(())
+    }
+
+    /// Unwrap the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+/// Streaming decoder yielding timestamps from a buffer.
+///
+/// After an error, the decoder yields `None`.
+#[derive(Debug, Clone)]
+pub struct Decoder<'a> {
+    input: &'a [u8],
+    prev: i64,
+}
+
+impl<'a> Decoder<'a> {
+    pub fn new(input: &'a [u8]) -> Self {
+        Decoder { input, prev: 0 }
+    }
+}
+
+impl Iterator for Decoder<'_> {
+    type Item = Result<UtcTimeStamp, DecodeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.input.is_empty() {
+            return None;
+        }
+
+        match read_varint(&mut self.input) {
+            Ok(x) => {
+                self.prev = self.prev.wrapping_add(unzigzag(x));
+                Some(Ok(UtcTimeStamp::from_milliseconds(self.prev)))
+            }
+            Err(e) => {
+                self.input = &[];
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zigzag_roundtrip() {
+        for &x in &[0, 1, -1, 63, -64, 64, i64::MAX, i64::MIN] {
+            assert_eq!(unzigzag(zigzag(x)), x);
+        }
+        assert_eq!(zigzag(-1), 1);
+        assert_eq!(zigzag(1), 2);
+    }
+
+    #[test]
+    fn roundtrip() {
+        let ms = [
+            0,
+            1_600_000_000_000,
+            1_600_000_000_250,
+            1_599_999_999_000,
+            i64::MIN,
+            i64::MAX,
+            -5,
+        ];
+        let ts: Vec<_> = ms
+            .iter()
+            .map(|&x| UtcTimeStamp::from_milliseconds(x))
+            .collect();
+        let bytes = encode(&ts);
+        assert_eq!(decode(&bytes), Ok(ts.clone()));
+
+        let mut enc = Encoder::new(Vec::new());
+        for x in &ts {
+            enc.write(*x).unwrap();
+        }
+        assert_eq!(enc.into_inner(), bytes);
+
+        assert_eq!(decode(&[]), Ok(vec![]));
+    }
+
+    #[test]
+    fn malformed() {
+        assert_eq!(decode(&[0x02, 0x80]), Err(DecodeError::Truncated));
+        assert_eq!(decode(&[0xff; 10]), Err(DecodeError::Overflow));
+
+        let mut dec = Decoder::new(&[0x02, 0x80]);
+        assert_eq!(dec.next(), Some(Ok(UtcTimeStamp::from_milliseconds(1))));
+        assert_eq!(dec.next(), Some(Err(DecodeError::Truncated)));
+        assert_eq!(dec.next(), None);
+    }
+}
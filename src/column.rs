@@ -0,0 +1,263 @@
+//! Append-optimized timestamp column.
+
+use crate::{bulk, SortedTimestamps, UtcTimeStamp};
+use core::{iter::FromIterator, ops};
+
+/// A growable timestamp column tracking sortedness and bounds while appending.
+///
+/// Tracking is amortized into the appends, so querying [`min`], [`max`] and
+/// [`is_sorted`] is free and converting a sorted column into a
+/// [`SortedTimestamps`] index doesn't need to re-verify the order.
+///
+/// [`min`]: TimestampVec::min
+/// [`max`]: TimestampVec::max
+/// [`is_sorted`]: TimestampVec::is_sorted
+///
+/// Examples:
+///
+/// ```
+/// use utctimestamp::{TimestampVec, UtcTimeStamp};
+///
+/// let mut col = TimestampVec::new();
+/// col.extend_from_millis(&[1_000, 2_000, 3_000]);
+/// assert!(col.is_sorted());
+///
+/// col.push(UtcTimeStamp::from_milliseconds(500));
+/// assert!(!col.is_sorted());
+/// assert_eq!(col.min(), Some(UtcTimeStamp::from_milliseconds(500)));
+/// assert_eq!(col.max(), Some(UtcTimeStamp::from_milliseconds(3_000)));
+///
+/// let index = col.into_sorted();
+/// assert_eq!(index[0], UtcTimeStamp::from_milliseconds(500));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TimestampVec {
+    data: Vec<UtcTimeStamp>,
+    sorted: bool,
+    bounds: Option<(UtcTimeStamp, UtcTimeStamp)>,
+}
+
+impl TimestampVec {
+    /// Create an empty column.
+    pub const fn new() -> Self {
+        TimestampVec {
+            data: Vec::new(),
+            sorted: true,
+            bounds: None,
+        }
+    }
+
+    /// Create an empty column with room for `capacity` timestamps.
+    pub fn with_capacity(capacity: usize) -> Self {
+        TimestampVec {
+            data: Vec::with_capacity(capacity),
+            ..Self::new()
+        }
+    }
+
+    /// Append a timestamp.
+    pub fn push(&mut self, ts: UtcTimeStamp) {
+        self.track(self.data.last().copied(), ts);
+        self.data.push(ts);
+    }
+
+    /// Append all timestamps of a slice.
+    pub fn extend_from_slice(&mut self, timestamps: &[UtcTimeStamp]) {
+        self.data.reserve(timestamps.len());
+        let mut prev = self.data.last().copied();
+        for &ts in timestamps {
+            self.track(prev, ts);
+            prev = Some(ts);
+        }
+        self.data.extend_from_slice(timestamps);
+    }
+
+    /// Append raw milliseconds since the epoch.
+    pub fn extend_from_millis(&mut self, millis: &[i64]) {
+        self.extend_from_slice(bulk::from_millis_slice(millis));
+    }
+
+    #[inline]
+    fn track(&mut self, prev: Option<UtcTimeStamp>, ts: UtcTimeStamp) {
+        self.bounds = match self.bounds {
+            None => Some((ts, ts)),
+            Some((min, max)) => {
+                if let Some(prev) = prev {
+                    self.sorted &= prev <= ts;
+                }
+                Some((min.min(ts), max.max(ts)))
+            }
+        };
+    }
+
+    /// Remove all timestamps, keeping the allocation.
+    pub fn clear(&mut self) {
+        self.data.clear();
+        self.sorted = true;
+        self.bounds = None;
+    }
+
+    /// Whether the timestamps are in ascending order.
+    #[inline]
+    pub fn is_sorted(&self) -> bool {
+        self.sorted
+    }
+
+    /// The smallest timestamp, if any.
+    #[inline]
+    pub fn min(&self) -> Option<UtcTimeStamp> {
+        self.bounds.map(|x| x.0)
+    }
+
+    /// The largest timestamp, if any.
+    #[inline]
+    pub fn max(&self) -> Option<UtcTimeStamp> {
+        self.bounds.map(|x| x.1)
+    }
+
+    #[inline]
+    pub fn as_slice(&self) -> &[UtcTimeStamp] {
+        &self.data
+    }
+
+    /// View the column as raw milliseconds since the epoch.
+    #[inline]
+    pub fn as_millis(&self) -> &[i64] {
+        bulk::as_millis_slice(&self.data)
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> Vec<UtcTimeStamp> {
+        self.data
+    }
+
+    /// Convert into a sorted index, sorting only if the column isn't already.
+    pub fn into_sorted(mut self) -> SortedTimestamps {
+        if !self.sorted {
+            self.data.sort_unstable();
+        }
+        SortedTimestamps::from_sorted_unchecked(self.data)
+    }
+}
+
+impl Default for TimestampVec {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ops::Deref for TimestampVec {
+    type Target = [UtcTimeStamp];
+
+    fn deref(&self) -> &Self::Target {
+        &self.data
+    }
+}
+
+impl AsRef<[UtcTimeStamp]> for TimestampVec {
+    fn as_ref(&self) -> &[UtcTimeStamp] {
+        &self.data
+    }
+}
+
+impl Extend<UtcTimeStamp> for TimestampVec {
+    fn extend<I: IntoIterator<Item = UtcTimeStamp>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.data.reserve(iter.size_hint().0);
+        for ts in iter {
+            self.push(ts);
+        }
+    }
+}
+
+impl FromIterator<UtcTimeStamp> for TimestampVec {
+    fn from_iter<I: IntoIterator<Item = UtcTimeStamp>>(iter: I) -> Self {
+        let mut col = TimestampVec::new();
+        col.extend(iter);
+        col
+    }
+}
+
+impl From<Vec<UtcTimeStamp>> for TimestampVec {
+    fn from(other: Vec<UtcTimeStamp>) -> Self {
+        let mut col = TimestampVec::with_capacity(other.len());
+        col.extend_from_slice(&other);
+        col
+    }
+}
+
+impl From<SortedTimestamps> for TimestampVec {
+    fn from(other: SortedTimestamps) -> Self {
+        let data = other.into_inner();
+        let bounds = data.first().map(|&min| (min, *data.last().unwrap()));
+        TimestampVec {
+            data,
+            sorted: true,
+            bounds,
+        }
+    }
+}
+
+impl From<TimestampVec> for Vec<UtcTimeStamp> {
+    fn from(other: TimestampVec) -> Self {
+        other.data
+    }
+}
+
+impl<'a> IntoIterator for &'a TimestampVec {
+    type Item = &'a UtcTimeStamp;
+    type IntoIter = core::slice::Iter<'a, UtcTimeStamp>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.iter()
+    }
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn ts(ms: i64) -> UtcTimeStamp {
+        UtcTimeStamp::from_milliseconds(ms)
+    }
+
+    #[test]
+    fn timestamp_vec() {
+        let mut col = TimestampVec::default();
+        assert!(col.is_sorted());
+        assert_eq!(col.min(), None);
+
+        col.extend_from_millis(&[3, 5, 5, 9]);
+        col.push(ts(10));
+        assert!(col.is_sorted());
+        assert_eq!((col.min(), col.max()), (Some(ts(3)), Some(ts(10))));
+        assert_eq!(col.as_millis(), &[3, 5, 5, 9, 10]);
+
+        col.extend(vec![ts(-1), ts(20)]);
+        assert!(!col.is_sorted());
+        assert_eq!((col.min(), col.max()), (Some(ts(-1)), Some(ts(20))));
+        assert_eq!(col.len(), 7);
+
+        let index = col.clone().into_sorted();
+        assert_eq!(index.as_slice(), &[
+            ts(-1),
+            ts(3),
+            ts(5),
+            ts(5),
+            ts(9),
+            ts(10),
+            ts(20)
+        ]);
+        assert!(TimestampVec::from(index).is_sorted());
+
+        col.clear();
+        assert!(col.is_sorted() && col.is_empty() && col.max().is_none());
+
+        let col: TimestampVec = vec![ts(2), ts(1)].into();
+        assert!(!col.is_sorted());
+    }
+}
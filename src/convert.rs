@@ -0,0 +1,226 @@
+//! Conversions and arithmetic with `std::time` types.
+
+use crate::{TimeDelta, UtcTimeStamp};
+use core::{convert::TryFrom, fmt, ops};
+use std::time::{Duration, SystemTime};
+
+/// Error returned when converting a negative [`TimeDelta`] into an unsigned duration.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct NegativeDelta;
+
+impl fmt::Display for NegativeDelta {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("negative delta can't be represented as a duration")
+    }
+}
+
+impl std::error::Error for NegativeDelta {}
+
+/// Error returned when a value doesn't fit into the millisecond range of the target type.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct OutOfRangeError;
+
+impl fmt::Display for OutOfRangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("value out of range")
+    }
+}
+
+impl std::error::Error for OutOfRangeError {}
+
+/// Error returned when a conversion would drop sub-millisecond precision.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct PrecisionLossError;
+
+impl fmt::Display for PrecisionLossError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("value has sub-millisecond precision")
+    }
+}
+
+impl std::error::Error for PrecisionLossError {}
+
+// ============================================================================================== //
+// [Duration]                                                                                     //
+// ============================================================================================== //
+
+/// Convert a std duration, truncating sub-millisecond precision.
+///
+/// Fails for durations longer than `i64::MAX` milliseconds.
+impl TryFrom<Duration> for TimeDelta {
+    type Error = OutOfRangeError;
+
+    fn try_from(other: Duration) -> Result<Self, Self::Error> {
+        i64::try_from(other.as_millis())
+            .map(TimeDelta::from_milliseconds)
+            .map_err(|_| OutOfRangeError)
+    }
+}
+
+/// Calculate the timestamp advanced by a std duration, truncated to milliseconds.
+///
+/// Panics if the duration exceeds `i64::MAX` milliseconds.
+impl ops::Add<Duration> for UtcTimeStamp {
+    type Output = UtcTimeStamp;
+
+    fn add(self, rhs: Duration) -> Self::Output {
+        self + TimeDelta::try_from(rhs).expect("duration out of range")
+    }
+}
+
+impl ops::AddAssign<Duration> for UtcTimeStamp {
+    fn add_assign(&mut self, rhs: Duration) {
+        *self = *self + rhs;
+    }
+}
+
+/// Calculate the timestamp lessened by a std duration, truncated to milliseconds.
+///
+/// Panics if the duration exceeds `i64::MAX` milliseconds.
+impl ops::Sub<Duration> for UtcTimeStamp {
+    type Output = UtcTimeStamp;
+
+    fn sub(self, rhs: Duration) -> Self::Output {
+        self - TimeDelta::try_from(rhs).expect("duration out of range")
+    }
+}
+
+impl ops::SubAssign<Duration> for UtcTimeStamp {
+    fn sub_assign(&mut self, rhs: Duration) {
+        *self = *self - rhs;
+    }
+}
+
+impl TimeDelta {
+    /// Convert to a std duration, e.g. for sleeping or timers.
+    ///
+    /// Fails for negative deltas, which std durations can't represent.
+    ///
+    /// Examples:
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use utctimestamp::{NegativeDelta, TimeDelta};
+    ///
+    /// assert_eq!(TimeDelta::from_seconds(2).to_std(), Ok(Duration::from_secs(2)));
+    /// assert_eq!(TimeDelta::from_seconds(-2).to_std(), Err(NegativeDelta));
+    /// ```
+    pub fn to_std(self) -> Result<Duration, NegativeDelta> {
+        u64::try_from(self.as_milliseconds())
+            .map(Duration::from_millis)
+            .map_err(|_| NegativeDelta)
+    }
+}
+
+// ============================================================================================== //
+// [SystemTime]                                                                                   //
+// ============================================================================================== //
+
+/// Convert a system time, rounding towards the past to whole milliseconds.
+///
+/// Fails for times more than `i64::MAX` milliseconds away from the epoch.
+impl TryFrom<SystemTime> for UtcTimeStamp {
+    type Error = OutOfRangeError;
+
+    fn try_from(other: SystemTime) -> Result<Self, Self::Error> {
+        let ms = match other.duration_since(SystemTime::UNIX_EPOCH) {
+            Ok(after) => i64::try_from(after.as_millis()).map_err(|_| OutOfRangeError)?,
+            Err(before) => {
+                let before = before.duration();
+                let partial = before.subsec_nanos() % 1_000_000 != 0;
+                let ms = before.as_millis() + u128::from(partial);
+                let ms = i128::try_from(ms).map_err(|_| OutOfRangeError)?;
+                i64::try_from(-ms).map_err(|_| OutOfRangeError)?
+            }
+        };
+        Ok(UtcTimeStamp::from_milliseconds(ms))
+    }
+}
+
+impl UtcTimeStamp {
+    /// Convert to a system time, e.g. for comparing with file modification times.
+    ///
+    /// Panics if the platform's system time can't represent the timestamp.
+    ///
+    /// Examples:
+    ///
+    /// ```
+    /// use std::{convert::TryFrom, time::SystemTime};
+    /// use utctimestamp::UtcTimeStamp;
+    ///
+    /// let ts = UtcTimeStamp::from_milliseconds(1_600_000_000_250);
+    /// assert_eq!(UtcTimeStamp::try_from(ts.to_system_time()), Ok(ts));
+    /// assert_eq!(UtcTimeStamp::zero().to_system_time(), SystemTime::UNIX_EPOCH);
+    /// ```
+    pub fn to_system_time(self) -> SystemTime {
+        let ms = self.as_milliseconds();
+        let offset = Duration::from_millis(ms.unsigned_abs());
+        let time = if ms < 0 {
+            SystemTime::UNIX_EPOCH.checked_sub(offset)
+        } else {
+            SystemTime::UNIX_EPOCH.checked_add(offset)
+        };
+        time.expect("timestamp out of range for system time")
+    }
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use core::convert::TryFrom;
+    use std::time::{Duration, SystemTime};
+
+    #[test]
+    fn std_duration() {
+        let delta = |d| TimeDelta::try_from(d).map(TimeDelta::as_milliseconds);
+        assert_eq!(delta(Duration::from_micros(1999)), Ok(1));
+        assert_eq!(delta(Duration::from_secs(3)), Ok(3000));
+        assert_eq!(delta(Duration::from_millis(i64::MAX as u64)), Ok(i64::MAX));
+        assert_eq!(delta(Duration::from_secs(u64::MAX)), Err(OutOfRangeError));
+
+        assert_eq!(TimeDelta::zero().to_std(), Ok(Duration::ZERO));
+        assert_eq!(
+            TimeDelta::from_milliseconds(i64::MAX).to_std(),
+            Ok(Duration::from_millis(i64::MAX as u64))
+        );
+        assert_eq!(
+            TimeDelta::from_milliseconds(-1).to_std(),
+            Err(NegativeDelta)
+        );
+
+        let mut ts = UtcTimeStamp::from_seconds(10);
+        assert_eq!(
+            ts + Duration::from_micros(2500),
+            UtcTimeStamp::from_milliseconds(10_002)
+        );
+        assert_eq!(
+            ts - Duration::from_micros(2500),
+            UtcTimeStamp::from_milliseconds(9998)
+        );
+        ts += Duration::from_secs(1);
+        ts -= Duration::from_millis(1);
+        assert_eq!(ts, UtcTimeStamp::from_milliseconds(10_999));
+    }
+
+    #[test]
+    fn system_time() {
+        let epoch = SystemTime::UNIX_EPOCH;
+        let ms = |t| UtcTimeStamp::try_from(t).map(UtcTimeStamp::as_milliseconds);
+        assert_eq!(ms(epoch), Ok(0));
+        assert_eq!(ms(epoch + Duration::from_micros(1999)), Ok(1));
+        assert_eq!(ms(epoch - Duration::from_micros(1)), Ok(-1));
+        assert_eq!(ms(epoch - Duration::from_millis(1)), Ok(-1));
+        assert_eq!(ms(epoch - Duration::from_micros(1001)), Ok(-2));
+
+        for &x in &[-1_500, 0, 1_600_000_000_250, i64::MIN, i64::MAX] {
+            let ts = UtcTimeStamp::from_milliseconds(x);
+            assert_eq!(UtcTimeStamp::try_from(ts.to_system_time()), Ok(ts));
+        }
+        let far = UtcTimeStamp::from_milliseconds(i64::MAX).to_system_time();
+        assert_eq!(ms(far + Duration::from_millis(1)), Err(OutOfRangeError));
+    }
+}
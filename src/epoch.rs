@@ -0,0 +1,671 @@
+//! Conversions from and to foreign epochs and time representations.
+
+use crate::{calendar::MS_PER_DAY, DateParts, OutOfRangeError, TimeDelta, UtcTimeStamp};
+use core::convert::TryFrom;
+
+impl UtcTimeStamp {
+    /// Conversion from fractional days since `epoch_ms`, rounded to the nearest millisecond.
+    fn from_days_since(epoch_ms: i64, days: f64) -> Option<Self> {
+        crate::round_millis(days * MS_PER_DAY as f64)?
+            .checked_add(epoch_ms)
+            .map(UtcTimeStamp::from_milliseconds)
+    }
+
+    /// Fractional days since `epoch_ms`.
+    fn days_since(self, epoch_ms: i64) -> f64 {
+        (i128::from(self.as_milliseconds()) - i128::from(epoch_ms)) as f64 / MS_PER_DAY as f64
+    }
+}
+
+// ============================================================================================== //
+// [.NET ticks]                                                                                   //
+// ============================================================================================== //
+
+const TICKS_PER_MS: i64 = 10_000;
+/// Ticks of `1970-01-01T00:00:00Z`.
+const DOTNET_UNIX_TICKS: i64 = 621_355_968_000_000_000;
+/// Ticks of `9999-12-31T23:59:59.9999999`, .NET's `DateTime.MaxValue`.
+const DOTNET_MAX_TICKS: i64 = 3_155_378_975_999_999_999;
+
+impl UtcTimeStamp {
+    /// Conversion from .NET ticks, 100 ns intervals since `0001-01-01T00:00:00Z`.
+    ///
+    /// This is the value of `DateTime.Ticks` for UTC date times. Sub-millisecond
+    /// ticks are rounded towards the past. Fails for ticks outside of the
+    /// `DateTime.MinValue..=DateTime.MaxValue` range.
+    ///
+    /// Examples:
+    ///
+    /// ```
+    /// use utctimestamp::UtcTimeStamp;
+    ///
+    /// let ts = UtcTimeStamp::from_dotnet_ticks(637_355_968_002_500_000).unwrap();
+    /// assert_eq!(ts, UtcTimeStamp::from_milliseconds(1_600_000_000_250));
+    /// assert_eq!(ts.to_dotnet_ticks(), Ok(637_355_968_002_500_000));
+    /// assert!(UtcTimeStamp::from_dotnet_ticks(-1).is_err());
+    /// ```
+    pub fn from_dotnet_ticks(ticks: i64) -> Result<Self, OutOfRangeError> {
+        if !(0..=DOTNET_MAX_TICKS).contains(&ticks) {
+            return Err(OutOfRangeError);
+        }
+
+        let ms = (ticks - DOTNET_UNIX_TICKS).div_euclid(TICKS_PER_MS);
+        Ok(UtcTimeStamp::from_milliseconds(ms))
+    }
+
+    /// Conversion to .NET ticks, see [`from_dotnet_ticks`](Self::from_dotnet_ticks).
+    ///
+    /// Fails for timestamps before year 1 or after year 9999.
+    pub fn to_dotnet_ticks(self) -> Result<i64, OutOfRangeError> {
+        self.as_milliseconds()
+            .checked_mul(TICKS_PER_MS)
+            .and_then(|x| x.checked_add(DOTNET_UNIX_TICKS))
+            .filter(|x| (0..=DOTNET_MAX_TICKS).contains(x))
+            .ok_or(OutOfRangeError)
+    }
+}
+
+// ============================================================================================== //
+// [NTP]                                                                                          //
+// ============================================================================================== //
+
+/// Seconds from the NTP epoch `1900-01-01T00:00:00Z` to the UNIX epoch.
+const NTP_UNIX_SECS: i64 = 2_208_988_800;
+/// Seconds per NTP era, after which the 32 bit seconds field wraps around.
+const NTP_ERA_SECS: i64 = 1 << 32;
+
+/// Convert a binary fraction scaled by 1000 back to a fraction, rounding up.
+fn ntp_frac_ceil(scaled: u64) -> u64 {
+    let frac = scaled / 1000;
+    frac + u64::from(frac * 1000 < scaled)
+}
+
+impl UtcTimeStamp {
+    /// Conversion from a 64 bit NTP timestamp, 32 bit seconds since `1900-01-01T00:00:00Z`
+    /// followed by a 32 bit fraction.
+    ///
+    /// The era is resolved like in RFC 4330: timestamps with the most significant
+    /// bit set are placed in era 0, the others in era 1, covering the years 1968
+    /// to 2104. The fraction is rounded towards the past.
+    ///
+    /// Examples:
+    ///
+    /// ```
+    /// use utctimestamp::UtcTimeStamp;
+    ///
+    /// let ts = UtcTimeStamp::from_milliseconds(1_600_000_000_250);
+    /// let ntp = ts.to_ntp_timestamp();
+    /// assert_eq!(ntp >> 32, 3_808_988_800);
+    /// assert_eq!(UtcTimeStamp::from_ntp_timestamp(ntp), ts);
+    /// ```
+    pub fn from_ntp_timestamp(ntp: u64) -> Self {
+        let era_1 = UtcTimeStamp::from_seconds(NTP_ERA_SECS - NTP_UNIX_SECS);
+        UtcTimeStamp::from_ntp_timestamp_near(ntp, era_1)
+    }
+
+    /// Conversion from a 64 bit NTP timestamp in the era placing it closest to `pivot`.
+    ///
+    /// Any timestamp within 68 years of `pivot` is resolved correctly. Panics
+    /// if the result is out of range, which is only possible for pivots at the
+    /// very limits of the timestamp range.
+    pub fn from_ntp_timestamp_near(ntp: u64, pivot: UtcTimeStamp) -> Self {
+        let pivot_secs = pivot.as_seconds() + NTP_UNIX_SECS;
+        let offset = ((ntp >> 32) as u32).wrapping_sub(pivot_secs as u32) as i32;
+        let frac_ms = ((ntp & 0xffff_ffff) * 1000) >> 32;
+
+        (pivot_secs + i64::from(offset) - NTP_UNIX_SECS)
+            .checked_mul(1000)
+            .and_then(|x| x.checked_add(frac_ms as i64))
+            .map(UtcTimeStamp::from_milliseconds)
+            .expect("NTP timestamp out of range")
+    }
+
+    /// Conversion to a 64 bit NTP timestamp, dropping the era.
+    ///
+    /// The fraction is rounded up, so converting back yields the same timestamp.
+    pub fn to_ntp_timestamp(self) -> u64 {
+        let (secs, ms) = self.split_seconds();
+        let ntp_secs = (secs + NTP_UNIX_SECS) as u32;
+        let frac = ntp_frac_ceil(u64::from(ms) << 32);
+        (u64::from(ntp_secs) << 32) | frac
+    }
+
+    /// The NTP era of the timestamp, with era 0 starting at `1900-01-01T00:00:00Z`.
+    pub fn ntp_era(self) -> i64 {
+        (self.as_seconds() + NTP_UNIX_SECS).div_euclid(NTP_ERA_SECS)
+    }
+}
+
+impl TimeDelta {
+    /// Conversion from a 32 bit NTP short format value, 16 bit seconds followed
+    /// by a 16 bit fraction.
+    ///
+    /// The fraction is rounded towards zero.
+    pub fn from_ntp_short(ntp: u32) -> Self {
+        let frac_ms = ((ntp & 0xffff) * 1000) >> 16;
+        TimeDelta::from_milliseconds(i64::from(ntp >> 16) * 1000 + i64::from(frac_ms))
+    }
+
+    /// Conversion to a 32 bit NTP short format value.
+    ///
+    /// Fails for negative deltas and deltas of 65536 seconds or longer.
+    ///
+    /// Examples:
+    ///
+    /// ```
+    /// use utctimestamp::TimeDelta;
+    ///
+    /// let delay = TimeDelta::from_milliseconds(1_500);
+    /// assert_eq!(delay.to_ntp_short(), Ok(0x0001_8000));
+    /// assert_eq!(TimeDelta::from_ntp_short(0x0001_8000), delay);
+    /// ```
+    pub fn to_ntp_short(self) -> Result<u32, OutOfRangeError> {
+        let ms = self.as_milliseconds();
+        if !(0..65_536_000).contains(&ms) {
+            return Err(OutOfRangeError);
+        }
+
+        let frac = ntp_frac_ceil((ms % 1000) as u64 * 65_536) as u32;
+        Ok(((ms / 1000) as u32) << 16 | frac)
+    }
+}
+
+// ============================================================================================== //
+// [GPS time]                                                                                     //
+// ============================================================================================== //
+
+/// Leap seconds GPS time is ahead of UTC, valid since `2017-01-01T00:00:00Z`.
+///
+/// GPS time doesn't observe leap seconds, so this has to be updated whenever
+/// one is announced. Receivers broadcast the current value, which should be
+/// preferred when available.
+pub const GPS_LEAP_SECONDS: i32 = 18;
+
+/// The GPS epoch `1980-01-06T00:00:00Z` in milliseconds since the UNIX epoch.
+const GPS_EPOCH_MS: i64 = 315_964_800_000;
+const MS_PER_WEEK: i64 = 7 * 24 * 60 * 60 * 1000;
+
+impl UtcTimeStamp {
+    /// Conversion from a GPS week number and millisecond time of week.
+    ///
+    /// `week` is the full week number since the GPS epoch, not wrapped at 1024.
+    /// `leap_seconds` is the offset of GPS time to UTC, usually
+    /// [`GPS_LEAP_SECONDS`].
+    ///
+    /// Examples:
+    ///
+    /// ```
+    /// use utctimestamp::{UtcTimeStamp, GPS_LEAP_SECONDS};
+    ///
+    /// let ts = UtcTimeStamp::from_gps_time(2123, 44_818_250, GPS_LEAP_SECONDS);
+    /// assert_eq!(ts, UtcTimeStamp::from_milliseconds(1_600_000_000_250));
+    /// assert_eq!(ts.to_gps_time(GPS_LEAP_SECONDS), Some((2123, 44_818_250)));
+    /// ```
+    pub fn from_gps_time(week: u32, tow_ms: u32, leap_seconds: i32) -> Self {
+        let gps_ms = i64::from(week) * MS_PER_WEEK + i64::from(tow_ms);
+        // At most about 82 million years from the GPS epoch, well within range.
+        UtcTimeStamp::from_gps_milliseconds(gps_ms, leap_seconds)
+            .expect("GPS week and time of week are bounded")
+    }
+
+    /// Conversion to a GPS week number and millisecond time of week.
+    ///
+    /// Returns `None` for timestamps before the GPS epoch or too far after it.
+    pub fn to_gps_time(self, leap_seconds: i32) -> Option<(u32, u32)> {
+        let gps_ms = self.to_gps_milliseconds(leap_seconds).ok()?;
+        if gps_ms < 0 {
+            return None;
+        }
+
+        let week = u32::try_from(gps_ms / MS_PER_WEEK).ok()?;
+        Some((week, (gps_ms % MS_PER_WEEK) as u32))
+    }
+
+    /// Conversion from whole seconds since the GPS epoch.
+    ///
+    /// Fails if the result is out of range.
+    pub fn from_gps_seconds(secs: i64, leap_seconds: i32) -> Result<Self, OutOfRangeError> {
+        let gps_ms = secs.checked_mul(1000).ok_or(OutOfRangeError)?;
+        UtcTimeStamp::from_gps_milliseconds(gps_ms, leap_seconds)
+    }
+
+    /// Conversion to whole seconds since the GPS epoch, rounded towards the past.
+    ///
+    /// Fails if the result is out of range.
+    pub fn to_gps_seconds(self, leap_seconds: i32) -> Result<i64, OutOfRangeError> {
+        self.to_gps_milliseconds(leap_seconds)
+            .map(|x| x.div_euclid(1000))
+    }
+
+    fn from_gps_milliseconds(gps_ms: i64, leap_seconds: i32) -> Result<Self, OutOfRangeError> {
+        gps_ms
+            .checked_add(GPS_EPOCH_MS - i64::from(leap_seconds) * 1000)
+            .map(UtcTimeStamp::from_milliseconds)
+            .ok_or(OutOfRangeError)
+    }
+
+    fn to_gps_milliseconds(self, leap_seconds: i32) -> Result<i64, OutOfRangeError> {
+        self.as_milliseconds()
+            .checked_add(i64::from(leap_seconds) * 1000 - GPS_EPOCH_MS)
+            .ok_or(OutOfRangeError)
+    }
+}
+
+// ============================================================================================== //
+// [Excel serial dates]                                                                           //
+// ============================================================================================== //
+
+/// The OLE automation epoch `1899-12-30T00:00:00Z` in milliseconds since the UNIX epoch.
+const OLE_EPOCH_MS: i64 = -2_209_161_600_000;
+
+impl UtcTimeStamp {
+    /// Conversion from an Excel serial date, fractional days since `1899-12-30T00:00:00Z`,
+    /// rounded to the nearest millisecond.
+    ///
+    /// This is the OLE automation date used by `DateTime.FromOADate` and
+    /// spreadsheet exports. It matches Excel's 1900 date system from
+    /// `1900-03-01` (serial 61) onwards. Excel treats 1900 as a leap year for
+    /// compatibility with Lotus 1-2-3, so its serial 60 is the non-existent
+    /// `1900-02-29` and earlier serials are one day later than here. Negative
+    /// serials are treated as a continuation of the scale, unlike .NET, which
+    /// counts their fraction forward from the start of the day.
+    ///
+    /// Returns `None` for non-finite or out-of-range inputs.
+    ///
+    /// Examples:
+    ///
+    /// ```
+    /// use utctimestamp::UtcTimeStamp;
+    ///
+    /// let ts = UtcTimeStamp::from_excel_serial(44_197.75).unwrap();
+    /// assert_eq!(ts, utctimestamp::ts!("2021-01-01T18:00:00Z"));
+    /// assert_eq!(ts.to_excel_serial(), 44_197.75);
+    /// ```
+    pub fn from_excel_serial(serial: f64) -> Option<Self> {
+        UtcTimeStamp::from_days_since(OLE_EPOCH_MS, serial)
+    }
+
+    /// Conversion to an Excel serial date, see [`from_excel_serial`](Self::from_excel_serial).
+    pub fn to_excel_serial(self) -> f64 {
+        self.days_since(OLE_EPOCH_MS)
+    }
+}
+
+// ============================================================================================== //
+// [Julian days]                                                                                  //
+// ============================================================================================== //
+
+/// Julian day 0, `-4713-11-24T12:00:00Z`, in milliseconds since the UNIX epoch.
+const JD_EPOCH_MS: i64 = -210_866_760_000_000;
+/// Modified Julian day 0, `1858-11-17T00:00:00Z`, in milliseconds since the UNIX epoch.
+const MJD_EPOCH_MS: i64 = -3_506_716_800_000;
+
+impl UtcTimeStamp {
+    /// Conversion from a Julian day, fractional days since `-4713-11-24T12:00:00Z`
+    /// (proleptic Gregorian), rounded to the nearest millisecond.
+    ///
+    /// The day is counted in UTC, not in an astronomical time scale like TT.
+    /// Present-day Julian days are around 2.5 million, leaving a float
+    /// resolution of about 40 µs: enough for milliseconds, but prefer
+    /// [`from_mjd`](Self::from_mjd) where possible.
+    ///
+    /// Returns `None` for non-finite or out-of-range inputs.
+    ///
+    /// Examples:
+    ///
+    /// ```
+    /// use utctimestamp::{ts, UtcTimeStamp};
+    ///
+    /// let j2000 = ts!("2000-01-01T12:00:00Z");
+    /// assert_eq!(j2000.to_julian_day(), 2_451_545.0);
+    /// assert_eq!(j2000.to_mjd(), 51_544.5);
+    /// assert_eq!(UtcTimeStamp::from_julian_day(2_451_545.0), Some(j2000));
+    /// ```
+    pub fn from_julian_day(jd: f64) -> Option<Self> {
+        UtcTimeStamp::from_days_since(JD_EPOCH_MS, jd)
+    }
+
+    /// Conversion to a Julian day, see [`from_julian_day`](Self::from_julian_day).
+    pub fn to_julian_day(self) -> f64 {
+        self.days_since(JD_EPOCH_MS)
+    }
+
+    /// Conversion from a modified Julian day, fractional days since
+    /// `1858-11-17T00:00:00Z`, rounded to the nearest millisecond.
+    ///
+    /// Equal to the Julian day minus 2400000.5. Present-day values have a
+    /// float resolution of about 1 µs.
+    ///
+    /// Returns `None` for non-finite or out-of-range inputs.
+    pub fn from_mjd(mjd: f64) -> Option<Self> {
+        UtcTimeStamp::from_days_since(MJD_EPOCH_MS, mjd)
+    }
+
+    /// Conversion to a modified Julian day, see [`from_mjd`](Self::from_mjd).
+    pub fn to_mjd(self) -> f64 {
+        self.days_since(MJD_EPOCH_MS)
+    }
+}
+
+// ============================================================================================== //
+// [DOS date/time]                                                                                //
+// ============================================================================================== //
+
+impl UtcTimeStamp {
+    /// Conversion from the packed 16 bit date and time fields used by FAT file
+    /// systems and ZIP archives.
+    ///
+    /// The date packs `year - 1980` (7 bits), month and day, the time packs
+    /// hour, minute and seconds divided by two. DOS date times are local time
+    /// without an offset; they are interpreted as UTC here.
+    ///
+    /// Returns `None` for invalid fields, e.g. the all-zero date some tools write.
+    ///
+    /// Examples:
+    ///
+    /// ```
+    /// use utctimestamp::{ts, UtcTimeStamp};
+    ///
+    /// let ts = UtcTimeStamp::from_dos_datetime(0x512d, 0x6354).unwrap();
+    /// assert_eq!(ts, ts!("2020-09-13T12:26:40Z"));
+    /// assert_eq!(ts.to_dos_datetime(), Some((0x512d, 0x6354)));
+    /// ```
+    pub fn from_dos_datetime(date: u16, time: u16) -> Option<Self> {
+        UtcTimeStamp::from_parts(DateParts {
+            year: 1980 + i32::from(date >> 9),
+            month: u32::from(date >> 5 & 0xf),
+            day: u32::from(date & 0x1f),
+            hour: u32::from(time >> 11),
+            minute: u32::from(time >> 5 & 0x3f),
+            second: u32::from(time & 0x1f) * 2,
+            millisecond: 0,
+        })
+    }
+
+    /// Conversion to packed DOS date and time fields, see
+    /// [`from_dos_datetime`](Self::from_dos_datetime).
+    ///
+    /// Seconds are rounded down to an even number. Returns `None` outside of
+    /// the years 1980 to 2107.
+    pub fn to_dos_datetime(self) -> Option<(u16, u16)> {
+        let parts = self.to_parts();
+        if !(1980..=2107).contains(&parts.year) {
+            return None;
+        }
+
+        let date = ((parts.year - 1980) as u32) << 9 | parts.month << 5 | parts.day;
+        let time = parts.hour << 11 | parts.minute << 5 | (parts.second / 2);
+        Some((date as u16, time as u16))
+    }
+}
+
+// ============================================================================================== //
+// [systemd journal]                                                                              //
+// ============================================================================================== //
+
+impl UtcTimeStamp {
+    /// Conversion from a systemd journal realtime timestamp, unsigned
+    /// microseconds since the epoch as in `__REALTIME_TIMESTAMP`.
+    ///
+    /// Microseconds are rounded towards the past. Every `u64` value is in range.
+    ///
+    /// Examples:
+    ///
+    /// ```
+    /// use utctimestamp::UtcTimeStamp;
+    ///
+    /// let ts = UtcTimeStamp::from_journal_usec(1_600_000_000_250_999);
+    /// assert_eq!(ts, UtcTimeStamp::from_milliseconds(1_600_000_000_250));
+    /// assert_eq!(ts.to_journal_usec(), 1_600_000_000_250_000);
+    /// ```
+    #[inline]
+    pub fn from_journal_usec(usec: u64) -> Self {
+        UtcTimeStamp::from_milliseconds((usec / 1000) as i64)
+    }
+
+    /// Conversion to a systemd journal realtime timestamp.
+    ///
+    /// Saturates to `0` for timestamps before the epoch and to `u64::MAX`,
+    /// systemd's `USEC_INFINITY`, for timestamps after the year 586,524.
+    pub fn to_journal_usec(self) -> u64 {
+        let ms = self.as_milliseconds().max(0) as u64;
+        ms.saturating_mul(1000)
+    }
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn dotnet_ticks() {
+        let min = ts!("0001-01-01T00:00:00Z");
+        let max = ts!("9999-12-31T23:59:59.999Z");
+
+        assert_eq!(UtcTimeStamp::from_dotnet_ticks(0), Ok(min));
+        assert_eq!(min.to_dotnet_ticks(), Ok(0));
+        assert_eq!(
+            UtcTimeStamp::from_dotnet_ticks(3_155_378_975_999_999_999),
+            Ok(max)
+        );
+        assert_eq!(max.to_dotnet_ticks(), Ok(3_155_378_975_999_990_000));
+        assert_eq!(
+            UtcTimeStamp::from_dotnet_ticks(621_355_967_999_999_999),
+            Ok(UtcTimeStamp::from_milliseconds(-1))
+        );
+
+        assert_eq!(
+            UtcTimeStamp::from_dotnet_ticks(i64::MAX),
+            Err(OutOfRangeError)
+        );
+        assert_eq!(
+            (min - TimeDelta::from_milliseconds(1)).to_dotnet_ticks(),
+            Err(OutOfRangeError)
+        );
+        assert_eq!(
+            (max + TimeDelta::from_milliseconds(1)).to_dotnet_ticks(),
+            Err(OutOfRangeError)
+        );
+        assert_eq!(
+            UtcTimeStamp::from_milliseconds(i64::MIN).to_dotnet_ticks(),
+            Err(OutOfRangeError)
+        );
+    }
+
+    #[test]
+    fn ntp() {
+        // Era boundaries in NTP seconds.
+        let era_0 = UtcTimeStamp::from_seconds(-2_208_988_800);
+        let era_1 = UtcTimeStamp::from_seconds(2_085_978_496);
+
+        for &ts in &[
+            ts!("1968-01-20T03:14:08Z"),
+            ts!("1970-01-01T00:00:00.001Z"),
+            ts!("2036-02-07T06:28:15.999Z"),
+            era_1,
+            ts!("2104-02-26T09:42:23.999Z"),
+        ] {
+            assert_eq!(UtcTimeStamp::from_ntp_timestamp(ts.to_ntp_timestamp()), ts);
+        }
+        assert_eq!(era_1.to_ntp_timestamp(), 0);
+        assert_eq!(
+            UtcTimeStamp::from_ntp_timestamp(0x8000_0000_0000_0000),
+            ts!("1968-01-20T03:14:08Z")
+        );
+        assert_eq!(
+            UtcTimeStamp::from_ntp_timestamp(0x7fff_ffff_ffff_ffff),
+            ts!("2104-02-26T09:42:23.999Z")
+        );
+
+        assert_eq!(era_0.ntp_era(), 0);
+        assert_eq!(era_1.ntp_era(), 1);
+        assert_eq!((era_0 - TimeDelta::from_milliseconds(1)).ntp_era(), -1);
+        assert_eq!(UtcTimeStamp::from_ntp_timestamp_near(0, era_0), era_0);
+        assert_eq!(
+            UtcTimeStamp::from_ntp_timestamp_near(u64::MAX, era_0),
+            era_0 - TimeDelta::from_milliseconds(1)
+        );
+
+        let ms = |x| TimeDelta::from_milliseconds(x);
+        for &x in &[0, 1, 999, 1000, 65_535_999] {
+            assert_eq!(
+                TimeDelta::from_ntp_short(ms(x).to_ntp_short().unwrap()),
+                ms(x)
+            );
+        }
+        assert_eq!(ms(-1).to_ntp_short(), Err(OutOfRangeError));
+        assert_eq!(ms(65_536_000).to_ntp_short(), Err(OutOfRangeError));
+        assert_eq!(TimeDelta::from_ntp_short(u32::MAX), ms(65_535_999));
+    }
+
+    #[test]
+    fn gps_time() {
+        let epoch = ts!("1980-01-06T00:00:00Z");
+        assert_eq!(UtcTimeStamp::from_gps_time(0, 0, 0), epoch);
+        assert_eq!(epoch.to_gps_time(0), Some((0, 0)));
+        assert_eq!(epoch.to_gps_time(-1), None);
+        assert_eq!(epoch.to_gps_seconds(-1), Ok(-1));
+        assert_eq!(
+            (epoch - TimeDelta::from_milliseconds(1)).to_gps_seconds(0),
+            Ok(-1)
+        );
+
+        // 2017-01-01T00:00:00Z, the day the last leap second took effect.
+        let ts = ts!("2017-01-01T00:00:00Z");
+        assert_eq!(ts.to_gps_time(GPS_LEAP_SECONDS), Some((1930, 18_000)));
+        assert_eq!(ts.to_gps_seconds(GPS_LEAP_SECONDS), Ok(1_167_264_018));
+        assert_eq!(
+            UtcTimeStamp::from_gps_seconds(1_167_264_018, GPS_LEAP_SECONDS),
+            Ok(ts)
+        );
+        assert_eq!(
+            UtcTimeStamp::from_gps_time(1929, 7 * 86_400_000 + 18_000, GPS_LEAP_SECONDS),
+            ts
+        );
+
+        let ts = UtcTimeStamp::from_gps_time(u32::MAX, 604_799_999, 0);
+        assert_eq!(ts.to_gps_time(0), Some((u32::MAX, 604_799_999)));
+        assert_eq!((ts + TimeDelta::from_milliseconds(1)).to_gps_time(0), None);
+
+        let min = UtcTimeStamp::from_milliseconds(i64::MIN);
+        let max = UtcTimeStamp::from_milliseconds(i64::MAX);
+        assert_eq!(min.to_gps_time(GPS_LEAP_SECONDS), None);
+        assert_eq!(min.to_gps_seconds(GPS_LEAP_SECONDS), Err(OutOfRangeError));
+        assert_eq!(max.to_gps_seconds(i32::MAX), Err(OutOfRangeError));
+        assert_eq!(
+            UtcTimeStamp::from_gps_seconds(i64::MAX, 0),
+            Err(OutOfRangeError)
+        );
+        assert_eq!(
+            UtcTimeStamp::from_gps_seconds(i64::MAX / 1000, 0),
+            Err(OutOfRangeError)
+        );
+    }
+
+    #[test]
+    fn excel_serial() {
+        let serial = |x| UtcTimeStamp::from_excel_serial(x).unwrap();
+
+        assert_eq!(serial(0.0), ts!("1899-12-30T00:00:00Z"));
+        assert_eq!(serial(61.0), ts!("1900-03-01T00:00:00Z"));
+        assert_eq!(serial(25_569.0), UtcTimeStamp::zero());
+        assert_eq!(serial(-1.25), ts!("1899-12-28T18:00:00Z"));
+        assert_eq!(
+            serial(43_831.000_000_005_8),
+            ts!("2020-01-01T00:00:00.001Z")
+        );
+        assert_eq!(UtcTimeStamp::from_excel_serial(f64::NAN), None);
+        assert_eq!(UtcTimeStamp::from_excel_serial(1e20), None);
+
+        for &ms in &[-1, 0, 1_600_000_000_250, 253_402_300_799_999] {
+            let ts = UtcTimeStamp::from_milliseconds(ms);
+            assert_eq!(
+                UtcTimeStamp::from_excel_serial(ts.to_excel_serial()),
+                Some(ts)
+            );
+        }
+        assert_eq!(UtcTimeStamp::zero().to_excel_serial(), 25_569.0);
+        assert!(UtcTimeStamp::from_milliseconds(i64::MIN).to_excel_serial() < 0.0);
+    }
+
+    #[test]
+    fn julian_days() {
+        assert_eq!(UtcTimeStamp::zero().to_julian_day(), 2_440_587.5);
+        assert_eq!(UtcTimeStamp::zero().to_mjd(), 40_587.0);
+        assert_eq!(
+            UtcTimeStamp::from_mjd(0.0),
+            Some(ts!("1858-11-17T00:00:00Z"))
+        );
+        assert_eq!(
+            UtcTimeStamp::from_julian_day(0.0),
+            UtcTimeStamp::from_parts(DateParts {
+                year: -4713,
+                month: 11,
+                day: 24,
+                hour: 12,
+                minute: 0,
+                second: 0,
+                millisecond: 0,
+            })
+        );
+        assert_eq!(UtcTimeStamp::from_julian_day(f64::INFINITY), None);
+
+        for &ms in &[-1, 0, 1_600_000_000_250, 4_102_444_800_001] {
+            let ts = UtcTimeStamp::from_milliseconds(ms);
+            assert_eq!(UtcTimeStamp::from_julian_day(ts.to_julian_day()), Some(ts));
+            assert_eq!(UtcTimeStamp::from_mjd(ts.to_mjd()), Some(ts));
+            assert!((ts.to_julian_day() - ts.to_mjd() - 2_400_000.5).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn dos_datetime() {
+        let min = ts!("1980-01-01T00:00:00Z");
+        let max = ts!("2107-12-31T23:59:58Z");
+
+        assert_eq!(UtcTimeStamp::from_dos_datetime(0x0021, 0), Some(min));
+        assert_eq!(min.to_dos_datetime(), Some((0x0021, 0)));
+        assert_eq!(UtcTimeStamp::from_dos_datetime(0xff9f, 0xbf7d), Some(max));
+        assert_eq!(
+            (max + TimeDelta::from_milliseconds(1_999)).to_dos_datetime(),
+            Some((0xff9f, 0xbf7d))
+        );
+        assert_eq!((max + TimeDelta::from_seconds(2)).to_dos_datetime(), None);
+        assert_eq!(
+            (min - TimeDelta::from_milliseconds(1)).to_dos_datetime(),
+            None
+        );
+
+        assert_eq!(UtcTimeStamp::from_dos_datetime(0, 0), None);
+        assert_eq!(UtcTimeStamp::from_dos_datetime(0x0021, 0xc000), None);
+        assert_eq!(UtcTimeStamp::from_dos_datetime(0x0021, 0x001e), None);
+        assert_eq!(UtcTimeStamp::from_dos_datetime(0x025d, 0), None);
+    }
+
+    #[test]
+    fn journal_usec() {
+        assert_eq!(UtcTimeStamp::from_journal_usec(0), UtcTimeStamp::zero());
+        assert_eq!(UtcTimeStamp::from_journal_usec(999), UtcTimeStamp::zero());
+        assert_eq!(
+            UtcTimeStamp::from_journal_usec(u64::MAX),
+            UtcTimeStamp::from_milliseconds(18_446_744_073_709_551)
+        );
+        assert_eq!(
+            UtcTimeStamp::from_milliseconds(18_446_744_073_709_551).to_journal_usec(),
+            18_446_744_073_709_551_000
+        );
+        assert_eq!(
+            UtcTimeStamp::from_milliseconds(18_446_744_073_709_552).to_journal_usec(),
+            u64::MAX
+        );
+        assert_eq!(UtcTimeStamp::from_milliseconds(-1).to_journal_usec(), 0);
+        assert_eq!(
+            UtcTimeStamp::from_milliseconds(i64::MAX).to_journal_usec(),
+            u64::MAX
+        );
+    }
+}
@@ -0,0 +1,1056 @@
+//! Formatting timestamps as strings.
+
+use crate::{
+    calendar::{civil_from_days, split_day, MS_PER_DAY},
+    TimeDelta, UtcTimeStamp,
+};
+use core::{
+    convert::TryFrom,
+    fmt::{self, Write},
+};
+
+/// Longest possible output of [`UtcTimeStamp::write_rfc3339`].
+const RFC3339_MAX_LEN: usize = 32;
+
+/// Write `value` as exactly `N` decimal digits.
+#[inline(always)]
+fn put_digits<const N: usize>(buf: &mut [u8], mut value: u32) {
+    for x in buf[..N].iter_mut().rev() {
+        *x = b'0' + (value % 10) as u8;
+        value /= 10;
+    }
+}
+
+fn rfc3339_buf(ts: UtcTimeStamp, buf: &mut [u8; RFC3339_MAX_LEN]) -> usize {
+    let (days, ms) = split_day(ts);
+    let (year, month, day) = civil_from_days(days);
+    let ms = ms as u32;
+
+    let mut pos = 0;
+    if (0..=9999).contains(&year) {
+        put_digits::<4>(buf, year as u32);
+        pos += 4;
+    } else {
+        // Expanded representation as in ISO 8601, e.g. `+10000` or `-0001`.
+        buf[0] = if year < 0 { b'-' } else { b'+' };
+        let year = year.unsigned_abs();
+        let mut len = 4;
+        while len < 19 && year >= 10u64.pow(len as u32) {
+            len += 1;
+        }
+        let mut value = year;
+        for x in buf[1..=len].iter_mut().rev() {
+            *x = b'0' + (value % 10) as u8;
+            value /= 10;
+        }
+        pos += 1 + len;
+    }
+
+    let rest = &mut buf[pos..pos + 20];
+    rest[0] = b'-';
+    put_digits::<2>(&mut rest[1..], month);
+    rest[3] = b'-';
+    put_digits::<2>(&mut rest[4..], day);
+    rest[6] = b'T';
+    put_digits::<2>(&mut rest[7..], ms / 3_600_000);
+    rest[9] = b':';
+    put_digits::<2>(&mut rest[10..], ms / 60_000 % 60);
+    rest[12] = b':';
+    put_digits::<2>(&mut rest[13..], ms / 1000 % 60);
+    rest[15] = b'.';
+    put_digits::<3>(&mut rest[16..], ms % 1000);
+    rest[19] = b'Z';
+    pos + 20
+}
+
+/// Most sub-second digits printed when a precision is requested.
+const MAX_SUBSEC_DIGITS: usize = 9;
+
+/// Fixed capacity string for allocation-free formatting.
+struct StackStr<const N: usize> {
+    buf: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> StackStr<N> {
+    fn new() -> Self {
+        StackStr {
+            buf: [0; N],
+            len: 0,
+        }
+    }
+
+    fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.buf[..self.len]).expect("only complete strings are pushed")
+    }
+}
+
+impl<const N: usize> fmt::Write for StackStr<N> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        self.buf
+            .get_mut(self.len..end)
+            .ok_or(fmt::Error)?
+            .copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+/// Write `.` followed by exactly `digits` sub-second digits of `millis`.
+fn write_subsec(w: &mut impl fmt::Write, millis: u32, digits: usize) -> fmt::Result {
+    if digits == 0 {
+        return Ok(());
+    }
+    w.write_char('.')?;
+    for div in [100, 10, 1].iter().take(digits) {
+        w.write_char((b'0' + (millis / div % 10) as u8) as char)?;
+    }
+    (3..digits).try_for_each(|_| w.write_char('0'))
+}
+
+/// Write `s` honoring the formatter's width, fill and alignment.
+///
+/// Unlike [`fmt::Formatter::pad`], this ignores the precision, which the
+/// callers use for the number of sub-second digits instead.
+fn pad(f: &mut fmt::Formatter<'_>, s: &str) -> fmt::Result {
+    let len = s.chars().count();
+    let padding = f.width().unwrap_or(0).saturating_sub(len);
+    let (pre, post) = match f.align() {
+        Some(fmt::Alignment::Right) => (padding, 0),
+        Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+        _ => (0, padding),
+    };
+
+    let fill = f.fill();
+    (0..pre).try_for_each(|_| f.write_fmt(format_args!("{}", fill)))?;
+    f.write_str(s)?;
+    (0..post).try_for_each(|_| f.write_fmt(format_args!("{}", fill)))
+}
+
+/// Display as `YYYY-MM-DD HH:MM:SS[.mmm] UTC`, matching chrono's output.
+///
+/// The fraction is omitted for whole seconds. The precision sets the exact
+/// number of sub-second digits, truncating or padding with zeros up to nine
+/// digits. The alternate flag prints the date only. Width, fill and alignment
+/// apply to the whole output.
+///
+/// Examples:
+///
+/// ```
+/// use utctimestamp::UtcTimeStamp;
+///
+/// let ts = UtcTimeStamp::from_milliseconds(1_600_000_000_250);
+/// assert_eq!(format!("{}", ts), "2020-09-13 12:26:40.250 UTC");
+/// assert_eq!(format!("{:.1}", ts), "2020-09-13 12:26:40.2 UTC");
+/// assert_eq!(format!("{:.0}", ts), "2020-09-13 12:26:40 UTC");
+/// assert_eq!(format!("{:#}", ts), "2020-09-13");
+/// assert_eq!(format!("[{:>#12}]", ts), "[  2020-09-13]");
+/// ```
+impl fmt::Display for UtcTimeStamp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut buf = [0; RFC3339_MAX_LEN];
+        let len = rfc3339_buf(*self, &mut buf);
+        let text = |range: core::ops::Range<usize>| {
+            core::str::from_utf8(&buf[range]).expect("output is ASCII")
+        };
+
+        if f.alternate() {
+            return pad(f, text(0..len - 14));
+        }
+
+        let millis = self.subsec_milliseconds();
+        let digits = match f.precision() {
+            Some(digits) => digits.min(MAX_SUBSEC_DIGITS),
+            None if millis == 0 => 0,
+            None => 3,
+        };
+
+        let mut out = StackStr::<{ RFC3339_MAX_LEN + 16 }>::new();
+        out.write_str(text(0..len - 14))?;
+        out.write_char(' ')?;
+        out.write_str(text(len - 13..len - 5))?;
+        write_subsec(&mut out, millis, digits)?;
+        out.write_str(" UTC")?;
+        pad(f, out.as_str())
+    }
+}
+
+/// Display as an ISO 8601 duration in seconds such as `PT5400.25S`, matching chrono.
+///
+/// Zero is `P0D` and negative deltas carry a leading `-`. Trailing zeros of
+/// the fraction are omitted, unless a precision is given, which sets the exact
+/// number of sub-second digits like for [`UtcTimeStamp`]. The alternate flag
+/// prints the [humanized](TimeDelta::humanize) form instead. Width, fill and
+/// alignment apply to the whole output.
+///
+/// Examples:
+///
+/// ```
+/// use utctimestamp::TimeDelta;
+///
+/// let delta = TimeDelta::from_milliseconds(5_400_250);
+/// assert_eq!(format!("{}", delta), "PT5400.25S");
+/// assert_eq!(format!("{:.0}", delta), "PT5400S");
+/// assert_eq!(format!("{:#}", delta), "1h 30m 250ms");
+/// assert_eq!(format!("{:>#14}", delta), "  1h 30m 250ms");
+/// ```
+impl fmt::Display for TimeDelta {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut out = StackStr::<64>::new();
+        if f.alternate() {
+            write!(out, "{}", self.humanize())?;
+            return pad(f, out.as_str());
+        }
+
+        let ms = self.as_milliseconds();
+        let (secs, millis) = (ms.unsigned_abs() / 1000, (ms.unsigned_abs() % 1000) as u32);
+        let digits = match f.precision() {
+            Some(digits) => digits.min(MAX_SUBSEC_DIGITS),
+            None if ms == 0 => return pad(f, "P0D"),
+            None if millis % 100 == 0 => usize::from(millis != 0),
+            None if millis % 10 == 0 => 2,
+            None => 3,
+        };
+
+        if ms < 0 {
+            out.write_char('-')?;
+        }
+        write!(out, "PT{}", secs)?;
+        write_subsec(&mut out, millis, digits)?;
+        out.write_char('S')?;
+        pad(f, out.as_str())
+    }
+}
+
+/// Debug as the raw milliseconds followed by a readable rendering.
+///
+/// Examples:
+///
+/// ```
+/// use utctimestamp::{TimeDelta, UtcTimeStamp};
+///
+/// let ts = UtcTimeStamp::from_milliseconds(1_694_788_800_000);
+/// assert_eq!(format!("{:?}", ts), "UtcTimeStamp(1694788800000 /* 2023-09-15T14:40:00Z */)");
+/// ```
+impl fmt::Debug for UtcTimeStamp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut buf = [0; RFC3339_MAX_LEN];
+        let len = rfc3339_buf(*self, &mut buf);
+        let (date, frac) = (&buf[..len - 5], &buf[len - 5..len - 1]);
+        let frac = if frac == b".000" { &[][..] } else { frac };
+
+        write!(
+            f,
+            "UtcTimeStamp({} /* {}{}Z */)",
+            self.as_milliseconds(),
+            core::str::from_utf8(date).expect("output is ASCII"),
+            core::str::from_utf8(frac).expect("output is ASCII"),
+        )
+    }
+}
+
+/// Debug as the raw milliseconds followed by the [humanized](TimeDelta::humanize) form.
+///
+/// Examples:
+///
+/// ```
+/// use utctimestamp::TimeDelta;
+///
+/// let delta = TimeDelta::from_minutes(90);
+/// assert_eq!(format!("{:?}", delta), "TimeDelta(5400000 /* 1h 30m */)");
+/// ```
+impl fmt::Debug for TimeDelta {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "TimeDelta({} /* {} */)",
+            self.as_milliseconds(),
+            self.humanize()
+        )
+    }
+}
+
+impl UtcTimeStamp {
+    fn to_chrono_utc(self) -> Option<chrono::DateTime<chrono::Utc>> {
+        chrono::DateTime::from_timestamp_millis(self.as_milliseconds())
+    }
+
+    /// Convert to chrono if the year lies within `0000..=9999`.
+    fn to_chrono_four_digit_year(self) -> Option<chrono::DateTime<chrono::Utc>> {
+        /// `0000-01-01T00:00:00.000Z` and `9999-12-31T23:59:59.999Z`.
+        const RANGE: core::ops::RangeInclusive<i64> = -62_167_219_200_000..=253_402_300_799_999;
+        if RANGE.contains(&self.as_milliseconds()) {
+            self.to_chrono_utc()
+        } else {
+            None
+        }
+    }
+
+    /// Write as RFC 3339 with millisecond precision, e.g. `2020-09-13T12:26:40.250Z`.
+    ///
+    /// Doesn't allocate. Years outside of `0000..=9999` are written with a
+    /// sign and as many digits as needed, which is outside of RFC 3339 but
+    /// matches the expanded ISO 8601 representation.
+    ///
+    /// Examples:
+    ///
+    /// ```
+    /// use utctimestamp::UtcTimeStamp;
+    ///
+    /// let mut out = String::new();
+    /// UtcTimeStamp::from_milliseconds(1_600_000_000_250).format_into(&mut out).unwrap();
+    /// assert_eq!(out, "2020-09-13T12:26:40.250Z");
+    /// ```
+    pub fn format_into(self, w: &mut impl fmt::Write) -> fmt::Result {
+        let mut buf = [0; RFC3339_MAX_LEN];
+        let len = rfc3339_buf(self, &mut buf);
+        w.write_str(core::str::from_utf8(&buf[..len]).expect("output is ASCII"))
+    }
+
+    /// Write as RFC 3339 into a byte buffer, returning the number of bytes written.
+    ///
+    /// See [`format_into`](Self::format_into) for the format. The output is 24
+    /// bytes for years `0000..=9999` and never longer than 32 bytes. Panics if
+    /// `buf` is too short to hold it.
+    ///
+    /// Examples:
+    ///
+    /// ```
+    /// use utctimestamp::UtcTimeStamp;
+    ///
+    /// let mut buf = [0; 32];
+    /// let len = UtcTimeStamp::from_milliseconds(-1).write_rfc3339(&mut buf);
+    /// assert_eq!(&buf[..len], b"1969-12-31T23:59:59.999Z");
+    /// ```
+    pub fn write_rfc3339(self, buf: &mut [u8]) -> usize {
+        let mut tmp = [0; RFC3339_MAX_LEN];
+        let len = rfc3339_buf(self, &mut tmp);
+        buf[..len].copy_from_slice(&tmp[..len]);
+        len
+    }
+
+    /// Format as RFC 3339 with configurable sub-second precision, mirroring chrono.
+    ///
+    /// With `use_z`, the UTC offset is written as `Z` instead of `+00:00`.
+    /// `SecondsFormat::AutoSi` omits the fraction for whole seconds and uses
+    /// milliseconds otherwise. Microsecond and nanosecond formats are padded
+    /// with zeros.
+    ///
+    /// Examples:
+    ///
+    /// ```
+    /// use chrono::SecondsFormat;
+    /// use utctimestamp::UtcTimeStamp;
+    ///
+    /// let ts = UtcTimeStamp::from_seconds(1_600_000_000);
+    /// assert_eq!(ts.to_rfc3339_opts(SecondsFormat::Secs, true), "2020-09-13T12:26:40Z");
+    /// assert_eq!(ts.to_rfc3339_opts(SecondsFormat::Millis, false), "2020-09-13T12:26:40.000+00:00");
+    /// ```
+    pub fn to_rfc3339_opts(self, secform: chrono::SecondsFormat, use_z: bool) -> String {
+        use chrono::SecondsFormat;
+
+        let mut buf = [0; RFC3339_MAX_LEN];
+        let len = rfc3339_buf(self, &mut buf);
+        let (base, millis) = (&buf[..len - 5], &buf[len - 5..len - 1]);
+
+        let mut out = String::with_capacity(len + 12);
+        out.push_str(core::str::from_utf8(base).expect("output is ASCII"));
+        let zero_millis = millis == b".000";
+        match secform {
+            SecondsFormat::Secs => {}
+            SecondsFormat::AutoSi if zero_millis => {}
+            SecondsFormat::Micros => {
+                out.push_str(core::str::from_utf8(millis).expect("output is ASCII"));
+                out.push_str("000");
+            }
+            SecondsFormat::Nanos => {
+                out.push_str(core::str::from_utf8(millis).expect("output is ASCII"));
+                out.push_str("000000");
+            }
+            _ => out.push_str(core::str::from_utf8(millis).expect("output is ASCII")),
+        }
+        out.push_str(if use_z { "Z" } else { "+00:00" });
+        out
+    }
+
+    /// Format with a custom strftime-like format, in UTC.
+    ///
+    /// See [`chrono::format::strftime`] for the supported directives. The
+    /// result implements `Display`, so it can be written without allocating.
+    /// Returns `None` if the timestamp is out of range for chrono.
+    ///
+    /// Examples:
+    ///
+    /// ```
+    /// use utctimestamp::UtcTimeStamp;
+    ///
+    /// let ts = UtcTimeStamp::from_milliseconds(1_600_000_000_250);
+    /// assert_eq!(ts.format("%Y%m%d %H%M%S%.3f").unwrap().to_string(), "20200913 122640.250");
+    /// ```
+    pub fn format<'a>(self, fmt: &'a str) -> Option<impl fmt::Display + 'a> {
+        Some(self.to_chrono_utc()?.format(fmt))
+    }
+
+    /// Format using a chrono format string with names in the given locale.
+    ///
+    /// Like [`format`](Self::format), but month and weekday names as well as
+    /// the locale dependent specifiers such as `%x` follow `locale`.
+    /// Returns `None` if the timestamp is out of range for chrono.
+    ///
+    /// Examples:
+    ///
+    /// ```
+    /// use utctimestamp::{Locale, UtcTimeStamp};
+    ///
+    /// let ts = UtcTimeStamp::from_seconds(1_600_000_000);
+    /// let date = ts.format_localized("%A %-d %B %Y", Locale::de_DE).unwrap().to_string();
+    /// assert_eq!(date, "Sonntag 13 September 2020");
+    /// ```
+    #[cfg(feature = "locale-support")]
+    pub fn format_localized<'a>(
+        self,
+        fmt: &'a str,
+        locale: chrono::Locale,
+    ) -> Option<impl fmt::Display + 'a> {
+        Some(self.to_chrono_utc()?.format_localized(fmt, locale))
+    }
+
+    /// Convert to a chrono date time in the given IANA time zone.
+    ///
+    /// Returns `None` if the timestamp is out of range for chrono.
+    ///
+    /// Examples:
+    ///
+    /// ```
+    /// use chrono::Timelike;
+    /// use utctimestamp::{Tz, UtcTimeStamp};
+    ///
+    /// let ts = UtcTimeStamp::from_seconds(1_600_000_000);
+    /// assert_eq!(ts.to_datetime_in(Tz::Asia__Tokyo).unwrap().hour(), 21);
+    /// ```
+    #[cfg(feature = "chrono-tz-support")]
+    pub fn to_datetime_in(self, tz: chrono_tz::Tz) -> Option<chrono::DateTime<chrono_tz::Tz>> {
+        Some(self.to_chrono_utc()?.with_timezone(&tz))
+    }
+
+    /// Format in the given IANA time zone using a chrono format string.
+    ///
+    /// Offset specifiers such as `%z` and `%Z` refer to the zone's offset at
+    /// this point in time. Returns `None` if the timestamp is out of range for
+    /// chrono.
+    ///
+    /// Examples:
+    ///
+    /// ```
+    /// use utctimestamp::{Tz, UtcTimeStamp};
+    ///
+    /// let ts = UtcTimeStamp::from_seconds(1_600_000_000);
+    /// let local = ts.format_in_tz(Tz::America__New_York, "%Y-%m-%d %H:%M %Z").unwrap().to_string();
+    /// assert_eq!(local, "2020-09-13 08:26 EDT");
+    /// ```
+    #[cfg(feature = "chrono-tz-support")]
+    pub fn format_in_tz<'a>(
+        self,
+        tz: chrono_tz::Tz,
+        fmt: &'a str,
+    ) -> Option<impl fmt::Display + 'a> {
+        Some(self.to_datetime_in(tz)?.format(fmt))
+    }
+
+    /// Format as an HTTP date such as `Sun, 06 Nov 1994 08:49:37 GMT`.
+    ///
+    /// Uses the IMF-fixdate format preferred by RFC 7231. Sub-second digits
+    /// are truncated. Returns `None` for years outside of `0000..=9999`,
+    /// which HTTP dates can't represent.
+    ///
+    /// Examples:
+    ///
+    /// ```
+    /// use utctimestamp::UtcTimeStamp;
+    ///
+    /// let ts = UtcTimeStamp::from_milliseconds(784_111_777_999);
+    /// assert_eq!(ts.to_http_date().unwrap(), "Sun, 06 Nov 1994 08:49:37 GMT");
+    /// ```
+    pub fn to_http_date(self) -> Option<String> {
+        self.to_chrono_four_digit_year()
+            .map(|x| x.format("%a, %d %b %Y %H:%M:%S GMT").to_string())
+    }
+
+    /// Format as an RFC 2822 date such as `Tue, 1 Jul 2003 08:52:37 +0000`.
+    ///
+    /// Returns `None` for years outside of `0000..=9999`, which RFC 2822
+    /// can't represent.
+    ///
+    /// Examples:
+    ///
+    /// ```
+    /// use utctimestamp::UtcTimeStamp;
+    ///
+    /// let ts = UtcTimeStamp::from_seconds(1_057_049_557);
+    /// assert_eq!(ts.to_rfc2822().unwrap(), "Tue, 1 Jul 2003 08:52:37 +0000");
+    /// ```
+    pub fn to_rfc2822(self) -> Option<String> {
+        self.to_chrono_four_digit_year().map(|x| x.to_rfc2822())
+    }
+}
+
+// ============================================================================================== //
+// [Humanized output]                                                                             //
+// ============================================================================================== //
+
+const HUMANIZE_UNITS: [(i64, &str); 5] = [
+    (MS_PER_DAY, "d"),
+    (60 * 60 * 1000, "h"),
+    (60 * 1000, "m"),
+    (1000, "s"),
+    (1, "ms"),
+];
+
+/// Compact, human readable rendering of a [`TimeDelta`], see [`TimeDelta::humanize`].
+#[derive(Copy, Clone, Debug)]
+pub struct Humanize {
+    delta: TimeDelta,
+    granularity: TimeDelta,
+    max_units: usize,
+}
+
+impl Humanize {
+    /// Drop components finer than `granularity`, truncating towards zero.
+    ///
+    /// The finest printed unit is the largest one not exceeding `granularity`,
+    /// so e.g. 90 minutes print hours and minutes. Defaults to one
+    /// millisecond, i.e. nothing is dropped.
+    pub fn granularity(mut self, granularity: TimeDelta) -> Self {
+        self.granularity = granularity;
+        self
+    }
+
+    /// Print at most `max_units` consecutive units, truncating the remainder.
+    ///
+    /// Counting starts at the first non-zero component, so `1d 0h 5m` limited
+    /// to two units prints as `1d`. Unlimited by default.
+    pub fn max_units(mut self, max_units: usize) -> Self {
+        self.max_units = max_units;
+        self
+    }
+}
+
+impl fmt::Display for Humanize {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ms = self.delta.as_milliseconds();
+        let granularity = self.granularity.as_milliseconds();
+        let finest = HUMANIZE_UNITS
+            .iter()
+            .position(|(unit, _)| *unit <= granularity)
+            .unwrap_or(HUMANIZE_UNITS.len() - 1);
+        let units = HUMANIZE_UNITS[..=finest].iter().copied();
+
+        if ms < 0 {
+            f.write_str("-")?;
+        }
+
+        let mut rest = ms.unsigned_abs();
+        let (mut written, mut counted) = (false, 0);
+        let mut smallest = "";
+        for (unit, name) in units {
+            smallest = name;
+            let count = rest / unit as u64;
+            rest %= unit as u64;
+            if counted == self.max_units {
+                continue;
+            }
+            if count == 0 {
+                counted += usize::from(written);
+                continue;
+            }
+            if written {
+                f.write_str(" ")?;
+            }
+            write!(f, "{}{}", count, name)?;
+            written = true;
+            counted += 1;
+        }
+
+        if !written {
+            write!(f, "0{}", smallest)?;
+        }
+        Ok(())
+    }
+}
+
+impl TimeDelta {
+    /// Render compactly as e.g. `1d 4h 12m`.
+    ///
+    /// Components range from days down to milliseconds; zero components are
+    /// omitted. The result can be tuned with [`Humanize::granularity`] and
+    /// [`Humanize::max_units`] and parses back via [`FromStr`] as long as no
+    /// components were dropped.
+    ///
+    /// [`FromStr`]: core::str::FromStr
+    ///
+    /// Examples:
+    ///
+    /// ```
+    /// use utctimestamp::TimeDelta;
+    ///
+    /// let delta = TimeDelta::from_milliseconds(101_520_250);
+    /// assert_eq!(delta.humanize().to_string(), "1d 4h 12m 250ms");
+    /// assert_eq!(
+    ///     delta.humanize().granularity(TimeDelta::from_minutes(1)).to_string(),
+    ///     "1d 4h 12m"
+    /// );
+    /// assert_eq!(delta.humanize().max_units(2).to_string(), "1d 4h");
+    /// ```
+    pub fn humanize(self) -> Humanize {
+        Humanize {
+            delta: self,
+            granularity: TimeDelta::from_milliseconds(1),
+            max_units: usize::MAX,
+        }
+    }
+
+    /// Render as a decimal in the largest fitting unit, e.g. `1.5s` or `3.2h`.
+    ///
+    /// Units range from days down to milliseconds. By default, up to two
+    /// decimals are printed, omitting trailing zeros; a precision on the
+    /// formatter sets the exact number of decimals instead.
+    ///
+    /// Examples:
+    ///
+    /// ```
+    /// use utctimestamp::TimeDelta;
+    ///
+    /// assert_eq!(TimeDelta::from_milliseconds(1500).scaled().to_string(), "1.5s");
+    /// assert_eq!(TimeDelta::from_milliseconds(250).scaled().to_string(), "250ms");
+    /// assert_eq!(format!("{:.1}", TimeDelta::from_minutes(193).scaled()), "3.2h");
+    /// ```
+    pub fn scaled(self) -> Scaled {
+        Scaled(self)
+    }
+}
+
+/// Decimal rendering of a [`TimeDelta`] in a single unit, see [`TimeDelta::scaled`].
+#[derive(Copy, Clone, Debug)]
+pub struct Scaled(TimeDelta);
+
+impl fmt::Display for Scaled {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ms = self.0.as_milliseconds();
+        let &(unit, name) = HUMANIZE_UNITS
+            .iter()
+            .find(|(unit, _)| ms.unsigned_abs() >= *unit as u64)
+            .unwrap_or(&HUMANIZE_UNITS[HUMANIZE_UNITS.len() - 1]);
+        let value = ms as f64 / unit as f64;
+
+        let mut out = StackStr::<64>::new();
+        match f.precision() {
+            Some(precision) => write!(out, "{:.*}", precision.min(MAX_SUBSEC_DIGITS), value)?,
+            None => {
+                write!(out, "{:.2}", value)?;
+                let trimmed = out.as_str().trim_end_matches('0').trim_end_matches('.');
+                out.len = trimmed.len();
+            }
+        }
+        out.write_str(name)?;
+        pad(f, out.as_str())
+    }
+}
+
+/// Spell out a rounded magnitude in the largest fitting unit, e.g. `3 hours`.
+///
+/// Thresholds follow moment.js, so e.g. 40 minutes are `40 minutes`, but 50
+/// minutes are `an hour`.
+fn relative_magnitude(ms: u64) -> String {
+    const MINUTE: u64 = 60 * 1000;
+    const HOUR: u64 = 60 * MINUTE;
+    const DAY: u64 = 24 * HOUR;
+
+    let round = |unit: u64| ms / unit + u64::from(ms % unit >= unit / 2);
+    let (count, unit) = match ms {
+        x if x < 90 * 1000 => return "a minute".to_owned(),
+        x if x < 45 * MINUTE => (round(MINUTE), "minute"),
+        x if x < 90 * MINUTE => return "an hour".to_owned(),
+        x if x < 22 * HOUR => (round(HOUR), "hour"),
+        x if x < 36 * HOUR => return "a day".to_owned(),
+        x if x < 26 * DAY => (round(DAY), "day"),
+        x if x < 45 * DAY => return "a month".to_owned(),
+        // Average month and year lengths of the Gregorian calendar.
+        x if x < 320 * DAY => (round(2_629_746_000), "month"),
+        x if x < 548 * DAY => return "a year".to_owned(),
+        _ => (round(31_556_952_000), "year"),
+    };
+    format!("{} {}s", count, unit)
+}
+
+impl UtcTimeStamp {
+    /// Describe the timestamp relative to `now`, e.g. `3 hours ago` or `in 2 days`.
+    ///
+    /// Differences below 45 seconds are `just now`. Larger ones are rounded to
+    /// the largest fitting unit from minutes to years, switching to the next
+    /// unit a bit before it is reached in full: 50 minutes are `an hour ago`
+    /// and 23 hours are `a day ago`. Months and years use their average
+    /// Gregorian length.
+    ///
+    /// Examples:
+    ///
+    /// ```
+    /// use utctimestamp::{TimeDelta, UtcTimeStamp};
+    ///
+    /// let now = UtcTimeStamp::from_seconds(1_600_000_000);
+    /// let rel = |delta: TimeDelta| (now + delta).humanize_relative_to(now);
+    ///
+    /// assert_eq!(rel(TimeDelta::from_hours(-3)), "3 hours ago");
+    /// assert_eq!(rel(TimeDelta::from_hours(48)), "in 2 days");
+    /// assert_eq!(rel(TimeDelta::from_seconds(10)), "just now");
+    /// ```
+    pub fn humanize_relative_to(self, now: UtcTimeStamp) -> String {
+        let diff = self.as_milliseconds() as i128 - now.as_milliseconds() as i128;
+        let ms = u64::try_from(diff.unsigned_abs()).unwrap_or(u64::MAX);
+        if ms < 45 * 1000 {
+            "just now".to_owned()
+        } else if diff < 0 {
+            format!("{} ago", relative_magnitude(ms))
+        } else {
+            format!("in {}", relative_magnitude(ms))
+        }
+    }
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn rfc3339(ms: i64) -> String {
+        let mut out = String::new();
+        UtcTimeStamp::from_milliseconds(ms)
+            .format_into(&mut out)
+            .unwrap();
+        out
+    }
+
+    #[test]
+    fn rfc3339_native() {
+        assert_eq!(rfc3339(0), "1970-01-01T00:00:00.000Z");
+        assert_eq!(rfc3339(951_782_400_001), "2000-02-29T00:00:00.001Z");
+        assert_eq!(rfc3339(-62_167_219_200_000), "0000-01-01T00:00:00.000Z");
+        assert_eq!(rfc3339(-62_167_219_200_001), "-0001-12-31T23:59:59.999Z");
+        assert_eq!(rfc3339(253_402_300_800_000), "+10000-01-01T00:00:00.000Z");
+        assert_eq!(rfc3339(i64::MAX), "+292278994-08-17T07:12:55.807Z");
+        assert_eq!(rfc3339(i64::MIN), "-292275055-05-16T16:47:04.192Z");
+
+        // Agrees with chrono within its range.
+        for &ms in &[
+            -1,
+            1_600_000_000_250,
+            -8_000_000_000_000_000,
+            8_000_000_000_000_000,
+        ] {
+            let chrono = chrono::DateTime::from_timestamp_millis(ms).unwrap();
+            assert_eq!(
+                rfc3339(ms),
+                chrono.to_rfc3339_opts(chrono::SecondsFormat::Millis, true)
+            );
+        }
+
+        let mut buf = [0; 24];
+        assert_eq!(UtcTimeStamp::zero().write_rfc3339(&mut buf), 24);
+        assert_eq!(&buf, b"1970-01-01T00:00:00.000Z");
+    }
+
+    #[test]
+    fn display() {
+        let display = |ms| UtcTimeStamp::from_milliseconds(ms).to_string();
+        assert_eq!(display(0), "1970-01-01 00:00:00 UTC");
+        assert_eq!(display(-1), "1969-12-31 23:59:59.999 UTC");
+        assert_eq!(display(-1500), "1969-12-31 23:59:58.500 UTC");
+        assert_eq!(display(i64::MAX), "+292278994-08-17 07:12:55.807 UTC");
+
+        for &ms in &[
+            -1,
+            -62_167_219_200_001,
+            1_600_000_000_250,
+            1_600_000_000_000,
+        ] {
+            let chrono = chrono::DateTime::from_timestamp_millis(ms).unwrap();
+            assert_eq!(display(ms), chrono.to_string());
+        }
+    }
+
+    #[test]
+    fn humanize() {
+        let human = |ms| TimeDelta::from_milliseconds(ms).humanize();
+        assert_eq!(human(0).to_string(), "0ms");
+        assert_eq!(human(90_000).to_string(), "1m 30s");
+        assert_eq!(human(-3_600_001).to_string(), "-1h 1ms");
+        assert_eq!(
+            human(i64::MIN).to_string(),
+            "-106751991167d 7h 12m 55s 808ms"
+        );
+
+        let minutes = TimeDelta::from_minutes(1);
+        assert_eq!(human(59_999).granularity(minutes).to_string(), "0m");
+        assert_eq!(human(-61_000).granularity(minutes).to_string(), "-1m");
+        assert_eq!(
+            human(172_800_000)
+                .granularity(TimeDelta::from_hours(48))
+                .to_string(),
+            "2d"
+        );
+
+        // Counting starts at the first non-zero component.
+        assert_eq!(human(3_661_001).max_units(2).to_string(), "1h 1m");
+        assert_eq!(human(86_400_001).max_units(2).to_string(), "1d");
+        assert_eq!(human(1).max_units(0).to_string(), "0ms");
+
+        assert_eq!(
+            human(101_520_250).to_string().parse(),
+            Ok(TimeDelta::from_milliseconds(101_520_250))
+        );
+    }
+
+    #[test]
+    fn scaled() {
+        let scaled = |ms| TimeDelta::from_milliseconds(ms).scaled().to_string();
+        assert_eq!(scaled(0), "0ms");
+        assert_eq!(scaled(-250), "-250ms");
+        assert_eq!(scaled(1000), "1s");
+        assert_eq!(scaled(1234), "1.23s");
+        assert_eq!(scaled(5_400_000), "1.5h");
+        assert_eq!(scaled(-90_000), "-1.5m");
+        assert_eq!(scaled(129_600_000), "1.5d");
+        assert_eq!(scaled(i64::MIN), "-106751991167.3d");
+        assert_eq!(
+            format!("{:.0}", TimeDelta::from_milliseconds(1500).scaled()),
+            "2s"
+        );
+        assert_eq!(
+            format!("{:>6.3}|", TimeDelta::from_milliseconds(7).scaled()),
+            "7.000ms|"
+        );
+        assert_eq!(
+            format!("{:>6}|", TimeDelta::from_milliseconds(7).scaled()),
+            "   7ms|"
+        );
+    }
+
+    #[test]
+    fn humanize_relative() {
+        let now = UtcTimeStamp::from_seconds(1_600_000_000);
+        let rel = |secs| (now + TimeDelta::from_seconds(secs)).humanize_relative_to(now);
+
+        assert_eq!(rel(0), "just now");
+        assert_eq!(rel(-44), "just now");
+        assert_eq!(rel(45), "in a minute");
+        assert_eq!(rel(-150), "3 minutes ago");
+        assert_eq!(rel(50 * 60), "in an hour");
+        assert_eq!(rel(-3 * 3600 - 1700), "3 hours ago");
+        assert_eq!(rel(-22 * 3600), "a day ago");
+        assert_eq!(rel(36 * 3600), "in 2 days");
+        assert_eq!(rel(30 * 86400), "in a month");
+        assert_eq!(rel(-100 * 86400), "3 months ago");
+        assert_eq!(rel(400 * 86400), "in a year");
+        assert_eq!(rel(-1000 * 86400), "3 years ago");
+
+        let (min, max) = (
+            UtcTimeStamp::from_milliseconds(i64::MIN),
+            UtcTimeStamp::from_milliseconds(i64::MAX),
+        );
+        assert_eq!(min.humanize_relative_to(max), "584554049 years ago");
+    }
+
+    #[test]
+    fn display_flags() {
+        let ts = UtcTimeStamp::from_milliseconds(-1);
+        assert_eq!(format!("{:.2}", ts), "1969-12-31 23:59:59.99 UTC");
+        assert_eq!(format!("{:.6}", ts), "1969-12-31 23:59:59.999000 UTC");
+        assert_eq!(
+            format!("{:.20}", UtcTimeStamp::zero()),
+            "1970-01-01 00:00:00.000000000 UTC"
+        );
+        assert_eq!(format!("{:#.2}", ts), "1969-12-31");
+        assert_eq!(format!("{:*^#14}", ts), "**1969-12-31**");
+        assert_eq!(format!("{:<#12}|", ts), "1969-12-31  |");
+        assert_eq!(format!("{:5}", ts), "1969-12-31 23:59:59.999 UTC");
+
+        let delta = |ms| TimeDelta::from_milliseconds(ms);
+        assert_eq!(delta(0).to_string(), "P0D");
+        assert_eq!(format!("{:.1}", delta(0)), "PT0.0S");
+        assert_eq!(delta(-1500).to_string(), "-PT1.5S");
+        assert_eq!(delta(1010).to_string(), "PT1.01S");
+        assert_eq!(delta(1001).to_string(), "PT1.001S");
+        assert_eq!(delta(60_000).to_string(), "PT60S");
+        assert_eq!(delta(i64::MIN).to_string(), "-PT9223372036854775.808S");
+        assert_eq!(format!("{:8}|", delta(1000)), "PT1S    |");
+        assert_eq!(format!("{:#}", delta(-90_000)), "-1m 30s");
+
+        for &ms in &[1, -1, 999, 1500, -86_400_000, 123_456_789] {
+            assert_eq!(
+                delta(ms).to_string(),
+                chrono::Duration::milliseconds(ms).to_string()
+            );
+        }
+    }
+
+    #[test]
+    fn debug() {
+        let ts = UtcTimeStamp::from_milliseconds(-1);
+        assert_eq!(
+            format!("{:?}", ts),
+            "UtcTimeStamp(-1 /* 1969-12-31T23:59:59.999Z */)"
+        );
+        assert_eq!(
+            format!("{:?}", Some(UtcTimeStamp::from_milliseconds(i64::MIN))),
+            "Some(UtcTimeStamp(-9223372036854775808 /* -292275055-05-16T16:47:04.192Z */))"
+        );
+        assert_eq!(
+            format!("{:?}", TimeDelta::from_milliseconds(-1)),
+            "TimeDelta(-1 /* -1ms */)"
+        );
+        assert_eq!(format!("{:?}", TimeDelta::zero()), "TimeDelta(0 /* 0ms */)");
+    }
+
+    #[test]
+    fn rfc3339_opts() {
+        use chrono::SecondsFormat::*;
+
+        for &ms in &[0, -1, 1_600_000_000_250, 1_600_000_000_000] {
+            let ts = UtcTimeStamp::from_milliseconds(ms);
+            let chrono = chrono::DateTime::from_timestamp_millis(ms).unwrap();
+            for &format in &[Secs, Millis, Micros, Nanos, AutoSi] {
+                for &use_z in &[true, false] {
+                    assert_eq!(
+                        ts.to_rfc3339_opts(format, use_z),
+                        chrono.to_rfc3339_opts(format, use_z)
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn custom_format() {
+        let ts = UtcTimeStamp::from_milliseconds(-1);
+        assert_eq!(
+            ts.format("%d.%m.%Y %H:%M:%S%.3f").unwrap().to_string(),
+            "31.12.1969 23:59:59.999"
+        );
+        assert_eq!(ts.format("%s %z").unwrap().to_string(), "-1 +0000");
+        assert!(UtcTimeStamp::from_milliseconds(i64::MAX)
+            .format("%Y")
+            .is_none());
+        assert!(UtcTimeStamp::from_milliseconds(i64::MIN)
+            .format("%Y")
+            .is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "locale-support")]
+    fn localized() {
+        let ts = UtcTimeStamp::from_seconds(1_600_000_000);
+        let fmt = |locale| {
+            ts.format_localized("%a %d %b %Y", locale)
+                .unwrap()
+                .to_string()
+        };
+        assert_eq!(fmt(Locale::en_US), "Sun 13 Sep 2020");
+        assert_eq!(fmt(Locale::fr_FR), "dim. 13 sept. 2020");
+        assert_eq!(fmt(Locale::ja_JP), "日 13  9月 2020");
+        assert!(UtcTimeStamp::from_milliseconds(i64::MAX)
+            .format_localized("%Y", Locale::de_DE)
+            .is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "chrono-tz-support")]
+    fn time_zones() {
+        let winter = UtcTimeStamp::from_seconds(1_577_880_000);
+        let summer = UtcTimeStamp::from_seconds(1_593_604_800);
+        let fmt = "%H:%M %z";
+        assert_eq!(
+            winter
+                .format_in_tz(Tz::Europe__Berlin, fmt)
+                .unwrap()
+                .to_string(),
+            "13:00 +0100"
+        );
+        assert_eq!(
+            summer
+                .format_in_tz(Tz::Europe__Berlin, fmt)
+                .unwrap()
+                .to_string(),
+            "14:00 +0200"
+        );
+        assert_eq!(
+            winter.format_in_tz(Tz::UTC, fmt).unwrap().to_string(),
+            "12:00 +0000"
+        );
+
+        let local = summer.to_datetime_in(Tz::Australia__Sydney).unwrap();
+        assert_eq!(
+            UtcTimeStamp::from(local.with_timezone(&chrono::Utc)),
+            summer
+        );
+
+        let max = UtcTimeStamp::from_milliseconds(i64::MAX);
+        assert_eq!(max.to_datetime_in(Tz::UTC), None);
+        assert!(max.format_in_tz(Tz::UTC, fmt).is_none());
+    }
+
+    #[test]
+    fn http_date() {
+        for &ms in &[0, -1, 1_600_000_000_000, -62_135_596_800_000] {
+            let ts = UtcTimeStamp::from_milliseconds(ms);
+            let secs = UtcTimeStamp::from_seconds(ms.div_euclid(1000));
+            assert_eq!(
+                UtcTimeStamp::parse_http_date(&ts.to_http_date().unwrap()),
+                Ok(secs)
+            );
+        }
+        assert_eq!(
+            UtcTimeStamp::zero().to_http_date().unwrap(),
+            "Thu, 01 Jan 1970 00:00:00 GMT"
+        );
+
+        let ms = UtcTimeStamp::from_milliseconds;
+        assert_eq!(
+            ms(-62_167_219_200_000).to_http_date().unwrap(),
+            "Sat, 01 Jan 0000 00:00:00 GMT"
+        );
+        assert_eq!(
+            ms(253_402_300_799_999).to_http_date().unwrap(),
+            "Fri, 31 Dec 9999 23:59:59 GMT"
+        );
+        assert_eq!(ms(-62_167_219_200_001).to_http_date(), None);
+        assert_eq!(ms(253_402_300_800_000).to_http_date(), None);
+        assert_eq!(ms(i64::MAX).to_http_date(), None);
+    }
+
+    #[test]
+    fn rfc2822() {
+        let ms = UtcTimeStamp::from_milliseconds;
+        assert_eq!(
+            ms(0).to_rfc2822().unwrap(),
+            "Thu, 1 Jan 1970 00:00:00 +0000"
+        );
+        assert_eq!(
+            ms(-1).to_rfc2822().unwrap(),
+            "Wed, 31 Dec 1969 23:59:59 +0000"
+        );
+        assert_eq!(
+            ms(-62_167_219_200_000).to_rfc2822().unwrap(),
+            "Sat, 1 Jan 0000 00:00:00 +0000"
+        );
+        assert_eq!(
+            ms(253_402_300_799_999).to_rfc2822().unwrap(),
+            "Fri, 31 Dec 9999 23:59:59 +0000"
+        );
+        assert_eq!(ms(-62_167_219_200_001).to_rfc2822(), None);
+        assert_eq!(ms(253_402_300_800_000).to_rfc2822(), None);
+        assert_eq!(ms(i64::MIN).to_rfc2822(), None);
+    }
+}
@@ -0,0 +1,219 @@
+//! Detection and filling of gaps in timestamp sequences.
+
+use crate::{resample::bucket_start, TimeDelta, TimeInterval, UtcTimeStamp};
+use core::iter::Peekable;
+
+// ============================================================================================== //
+// [Gap detection]                                                                                //
+// ============================================================================================== //
+
+/// Find all spans where consecutive timestamps are more than `max_gap` apart.
+///
+/// Each gap is reported as the interval between the two timestamps enclosing
+/// it. The input is expected to be sorted.
+///
+/// Examples:
+///
+/// ```
+/// use utctimestamp::{find_gaps, TimeDelta, TimeInterval, UtcTimeStamp};
+///
+/// let ts: Vec<_> = [0, 1, 2, 10, 11].iter().map(|&s| UtcTimeStamp::from_seconds(s)).collect();
+/// let gaps = find_gaps(&ts, TimeDelta::from_seconds(5));
+///
+/// assert_eq!(gaps, vec![TimeInterval::new(ts[2], ts[3])]);
+/// ```
+pub fn find_gaps(timestamps: &[UtcTimeStamp], max_gap: TimeDelta) -> Vec<TimeInterval> {
+    Gaps::new(timestamps.iter().copied(), max_gap).collect()
+}
+
+/// Iterator variant of [`find_gaps`], working on any sorted timestamp stream.
+#[derive(Debug, Clone)]
+pub struct Gaps<I> {
+    iter: I,
+    prev: Option<UtcTimeStamp>,
+    max_gap: TimeDelta,
+}
+
+impl<I> Gaps<I>
+where
+    I: Iterator<Item = UtcTimeStamp>,
+{
+    pub fn new(iter: impl IntoIterator<IntoIter = I>, max_gap: TimeDelta) -> Self {
+        Gaps {
+            iter: iter.into_iter(),
+            prev: None,
+            max_gap,
+        }
+    }
+}
+
+impl<I> Iterator for Gaps<I>
+where
+    I: Iterator<Item = UtcTimeStamp>,
+{
+    type Item = TimeInterval;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for cur in &mut self.iter {
+            let prev = self.prev.replace(cur);
+            if let Some(prev) = prev {
+                if cur - prev > self.max_gap {
+                    return Some(TimeInterval::new(prev, cur));
+                }
+            }
+        }
+
+        None
+    }
+}
+
+// ============================================================================================== //
+// [Gap filling]                                                                                  //
+// ============================================================================================== //
+
+/// Complete a sorted timestamp stream with all missing points of a regular grid.
+///
+/// The grid consists of all timestamps `anchor + n * freq`. Between any two
+/// consecutive input timestamps, the grid points lying strictly in between are
+/// inserted. Input timestamps are passed through unchanged, even when they are
+/// not aligned. Nothing is inserted before the first or after the last input.
+///
+/// Examples:
+///
+/// ```
+/// use utctimestamp::{FillGaps, TimeDelta, UtcTimeStamp};
+///
+/// let ts = [0, 60, 240].iter().map(|&s| UtcTimeStamp::from_seconds(s));
+/// let filled: Vec<_> = FillGaps::new(ts, TimeDelta::from_minutes(1)).with_flags().collect();
+///
+/// assert_eq!(filled, vec![
+///     (UtcTimeStamp::from_seconds(0), false),
+///     (UtcTimeStamp::from_seconds(60), false),
+///     (UtcTimeStamp::from_seconds(120), true),
+///     (UtcTimeStamp::from_seconds(180), true),
+///     (UtcTimeStamp::from_seconds(240), false),
+/// ]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct FillGaps<I: Iterator<Item = UtcTimeStamp>> {
+    iter: Peekable<I>,
+    anchor: UtcTimeStamp,
+    freq: TimeDelta,
+    /// Next grid point to insert if it precedes the next input.
+    grid: Option<UtcTimeStamp>,
+}
+
+impl<I> FillGaps<I>
+where
+    I: Iterator<Item = UtcTimeStamp>,
+{
+    /// Fill gaps with a grid aligned to `1970-01-01 00:00:00 UTC`.
+    pub fn new(iter: impl IntoIterator<IntoIter = I>, freq: TimeDelta) -> Self {
+        FillGaps::anchored(iter, UtcTimeStamp::zero(), freq)
+    }
+
+    /// Fill gaps with a grid aligned to a custom anchor.
+    pub fn anchored(
+        iter: impl IntoIterator<IntoIter = I>,
+        anchor: UtcTimeStamp,
+        freq: TimeDelta,
+    ) -> Self {
+        assert!(freq.is_positive(), "grid frequency must be positive");
+        FillGaps {
+            iter: iter.into_iter().peekable(),
+            anchor,
+            freq,
+            grid: None,
+        }
+    }
+
+    /// Yield `(timestamp, synthetic)` pairs, flagging the inserted timestamps.
+    pub fn with_flags(self) -> FillGapsFlagged<I> {
+        FillGapsFlagged(self)
+    }
+
+    fn next_flagged(&mut self) -> Option<(UtcTimeStamp, bool)> {
+        let real = *self.iter.peek()?;
+        if let Some(grid) = self.grid {
+            if grid < real {
+                self.grid = Some(grid + self.freq);
+                return Some((grid, true));
+            }
+        }
+
+        self.iter.next();
+        self.grid = Some(bucket_start(real, self.anchor, self.freq) + self.freq);
+        Some((real, false))
+    }
+}
+
+impl<I> Iterator for FillGaps<I>
+where
+    I: Iterator<Item = UtcTimeStamp>,
+{
+    type Item = UtcTimeStamp;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_flagged().map(|x| x.0)
+    }
+}
+
+/// Gap filling iterator flagging synthetic timestamps, see [`FillGaps::with_flags`].
+#[derive(Debug, Clone)]
+pub struct FillGapsFlagged<I: Iterator<Item = UtcTimeStamp>>(FillGaps<I>);
+
+impl<I> Iterator for FillGapsFlagged<I>
+where
+    I: Iterator<Item = UtcTimeStamp>,
+{
+    type Item = (UtcTimeStamp, bool);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next_flagged()
+    }
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn gaps() {
+        let ts: Vec<_> = [0, 5, 10, 16, 21, 40, 100]
+            .iter()
+            .map(|&s| UtcTimeStamp::from_seconds(s))
+            .collect();
+
+        assert_eq!(find_gaps(&ts, TimeDelta::from_seconds(5)), vec![
+            TimeInterval::new(ts[2], ts[3]),
+            TimeInterval::new(ts[4], ts[5]),
+            TimeInterval::new(ts[5], ts[6]),
+        ]);
+        assert_eq!(find_gaps(&ts, TimeDelta::from_seconds(60)), vec![]);
+        assert_eq!(find_gaps(&[], TimeDelta::zero()), vec![]);
+        assert_eq!(Gaps::new(ts, TimeDelta::from_seconds(30)).count(), 1);
+    }
+
+    #[test]
+    fn fill_gaps() {
+        let ts =
+            |s: &[i64]| -> Vec<_> { s.iter().map(|&s| UtcTimeStamp::from_seconds(s)).collect() };
+        let freq = TimeDelta::from_seconds(10);
+
+        let filled: Vec<_> = FillGaps::new(ts(&[-15, 5, 10, 32, 33, 50]), freq).collect();
+        assert_eq!(filled, ts(&[-15, -10, 0, 5, 10, 20, 30, 32, 33, 40, 50]));
+
+        let filled: Vec<_> = FillGaps::anchored(ts(&[1, 31]), UtcTimeStamp::from_seconds(1), freq)
+            .with_flags()
+            .map(|x| x.1)
+            .collect();
+        assert_eq!(filled, vec![false, true, true, false]);
+
+        assert_eq!(FillGaps::new(ts(&[]), freq).count(), 0);
+        assert_eq!(FillGaps::new(ts(&[7]), freq).count(), 1);
+    }
+}
@@ -0,0 +1,113 @@
+//! Conversions from and to the `hifitime` crate.
+
+use crate::{OutOfRangeError, TimeDelta, UtcTimeStamp};
+use core::convert::TryFrom;
+use hifitime::{Duration, Epoch, UNIX_REF_EPOCH};
+
+const NANOS_PER_MILLI: i128 = 1_000_000;
+
+/// Nanoseconds since the UNIX epoch to nanoseconds since hifitime's UTC reference.
+fn unix_ref_nanos() -> i128 {
+    UNIX_REF_EPOCH.to_utc_duration().total_nanoseconds()
+}
+
+/// Convert a hifitime epoch, rounding towards the past to whole milliseconds.
+///
+/// The epoch is taken in the UTC time scale, so leap seconds are accounted
+/// for just like in UNIX time.
+impl From<Epoch> for UtcTimeStamp {
+    fn from(other: Epoch) -> Self {
+        let nanos = other.to_utc_duration().total_nanoseconds() - unix_ref_nanos();
+        UtcTimeStamp::from_milliseconds(nanos.div_euclid(NANOS_PER_MILLI) as i64)
+    }
+}
+
+/// Convert to a hifitime epoch in the UTC time scale.
+///
+/// Fails for timestamps beyond hifitime's range of about ±3 million years.
+impl TryFrom<UtcTimeStamp> for Epoch {
+    type Error = OutOfRangeError;
+
+    fn try_from(other: UtcTimeStamp) -> Result<Self, Self::Error> {
+        let nanos = i128::from(other.as_milliseconds()) * NANOS_PER_MILLI + unix_ref_nanos();
+        let range = Duration::MIN.total_nanoseconds()..=Duration::MAX.total_nanoseconds();
+        if !range.contains(&nanos) {
+            return Err(OutOfRangeError);
+        }
+        Ok(Epoch::from_utc_duration(Duration::from_total_nanoseconds(
+            nanos,
+        )))
+    }
+}
+
+/// Convert a hifitime duration, truncating sub-millisecond precision.
+impl From<Duration> for TimeDelta {
+    fn from(other: Duration) -> Self {
+        TimeDelta::from_milliseconds((other.total_nanoseconds() / NANOS_PER_MILLI) as i64)
+    }
+}
+
+/// Convert to a hifitime duration.
+///
+/// Fails for deltas beyond hifitime's range of about ±3 million years.
+impl TryFrom<TimeDelta> for Duration {
+    type Error = OutOfRangeError;
+
+    fn try_from(other: TimeDelta) -> Result<Self, Self::Error> {
+        let nanos = i128::from(other.as_milliseconds()) * NANOS_PER_MILLI;
+        let range = Duration::MIN.total_nanoseconds()..=Duration::MAX.total_nanoseconds();
+        if !range.contains(&nanos) {
+            return Err(OutOfRangeError);
+        }
+        Ok(Duration::from_total_nanoseconds(nanos))
+    }
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use core::convert::TryFrom;
+    use hifitime::{Duration, Epoch, TimeUnits};
+
+    #[test]
+    fn hifitime() {
+        let epoch = Epoch::from_gregorian_utc(2017, 1, 1, 0, 0, 0, 1_500_001);
+        let ts = UtcTimeStamp::from(epoch);
+        assert_eq!(ts.as_milliseconds(), 1_483_228_800_001);
+        assert_eq!(
+            Epoch::try_from(ts),
+            Ok(Epoch::from_gregorian_utc(2017, 1, 1, 0, 0, 0, 1_000_000))
+        );
+
+        // The leap second at the end of 2016 doesn't shift UNIX time.
+        let before = Epoch::from_gregorian_utc_at_midnight(2016, 12, 31);
+        assert_eq!(
+            UtcTimeStamp::from(epoch) - UtcTimeStamp::from(before),
+            TimeDelta::DAY + TimeDelta::from_milliseconds(1)
+        );
+
+        let pre_epoch = UtcTimeStamp::from_milliseconds(-1);
+        assert_eq!(
+            UtcTimeStamp::from(Epoch::try_from(pre_epoch).unwrap()),
+            pre_epoch
+        );
+        assert_eq!(
+            Epoch::try_from(UtcTimeStamp::from_milliseconds(i64::MAX)),
+            Err(OutOfRangeError)
+        );
+
+        assert_eq!(
+            TimeDelta::from((-1999).microseconds()),
+            TimeDelta::from_milliseconds(-1)
+        );
+        assert_eq!(Duration::try_from(TimeDelta::HOUR), Ok(1.hours()));
+        assert_eq!(
+            Duration::try_from(TimeDelta::from_milliseconds(i64::MIN)),
+            Err(OutOfRangeError)
+        );
+    }
+}
@@ -0,0 +1,197 @@
+//! Sorted timestamp index container.
+
+use crate::{search, TimeInterval, UtcTimeStamp};
+use core::{convert::TryFrom, fmt, ops};
+
+/// Error returned when constructing a [`SortedTimestamps`] from unsorted data.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct NotSortedError {
+    /// Index of the first timestamp that is smaller than its predecessor.
+    pub index: usize,
+}
+
+impl fmt::Display for NotSortedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "timestamps not sorted at index {}", self.index)
+    }
+}
+
+impl std::error::Error for NotSortedError {}
+
+// ============================================================================================== //
+// [SortedTimestamps]                                                                             //
+// ============================================================================================== //
+
+/// A vector of timestamps guaranteed to be sorted in ascending order.
+///
+/// Duplicates are permitted. Dereferences to a slice for read-only access.
+///
+/// Examples:
+///
+/// ```
+/// use utctimestamp::{SortedTimestamps, TimeInterval, UtcTimeStamp};
+///
+/// let ts = UtcTimeStamp::from_seconds;
+/// let index = SortedTimestamps::new(vec![ts(1), ts(2), ts(5), ts(8)]).unwrap();
+///
+/// assert_eq!(index.range(TimeInterval::new(ts(2), ts(8))), &[ts(2), ts(5)]);
+/// assert_eq!(index.position_asof(ts(6)), Some(2));
+/// assert!(SortedTimestamps::new(vec![ts(2), ts(1)]).is_err());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct SortedTimestamps(Vec<UtcTimeStamp>);
+
+impl SortedTimestamps {
+    /// Wrap a vector, verifying that it is sorted.
+    pub fn new(timestamps: Vec<UtcTimeStamp>) -> Result<Self, NotSortedError> {
+        match timestamps.windows(2).position(|x| x[0] > x[1]) {
+            Some(index) => Err(NotSortedError { index: index + 1 }),
+            None => Ok(SortedTimestamps(timestamps)),
+        }
+    }
+
+    /// Wrap a vector, sorting it first.
+    pub fn from_unsorted(mut timestamps: Vec<UtcTimeStamp>) -> Self {
+        timestamps.sort_unstable();
+        SortedTimestamps(timestamps)
+    }
+
+    /// Wrap a vector that is known to be sorted.
+    pub(crate) fn from_sorted_unchecked(timestamps: Vec<UtcTimeStamp>) -> Self {
+        debug_assert!(timestamps.windows(2).all(|x| x[0] <= x[1]));
+        SortedTimestamps(timestamps)
+    }
+
+    /// Insert a timestamp at its sorted position, after any equal timestamps.
+    pub fn insert(&mut self, ts: UtcTimeStamp) {
+        let idx = self.0.partition_point(|x| *x <= ts);
+        self.0.insert(idx, ts);
+    }
+
+    #[inline]
+    pub fn as_slice(&self) -> &[UtcTimeStamp] {
+        &self.0
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> Vec<UtcTimeStamp> {
+        self.0
+    }
+
+    /// Check whether the index contains the exact timestamp.
+    pub fn contains(&self, ts: UtcTimeStamp) -> bool {
+        self.0.binary_search(&ts).is_ok()
+    }
+
+    /// The index range of all timestamps within the interval.
+    pub fn range_indices(&self, interval: TimeInterval) -> ops::Range<usize> {
+        search::range_indices(&self.0, interval)
+    }
+
+    /// All timestamps within the interval.
+    pub fn range(&self, interval: TimeInterval) -> &[UtcTimeStamp] {
+        &self.0[self.range_indices(interval)]
+    }
+
+    /// Index of the latest timestamp at or before `ts`, see [`search::asof`].
+    pub fn position_asof(&self, ts: UtcTimeStamp) -> Option<usize> {
+        search::asof(&self.0, ts)
+    }
+
+    /// Index of the earliest timestamp at or after `ts`, see [`search::after`].
+    pub fn position_after(&self, ts: UtcTimeStamp) -> Option<usize> {
+        search::after(&self.0, ts)
+    }
+
+    /// Index of the timestamp closest to `ts`, see [`search::nearest`].
+    pub fn position_nearest(&self, ts: UtcTimeStamp) -> Option<usize> {
+        search::nearest(&self.0, ts)
+    }
+}
+
+impl ops::Deref for SortedTimestamps {
+    type Target = [UtcTimeStamp];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl AsRef<[UtcTimeStamp]> for SortedTimestamps {
+    fn as_ref(&self) -> &[UtcTimeStamp] {
+        &self.0
+    }
+}
+
+impl TryFrom<Vec<UtcTimeStamp>> for SortedTimestamps {
+    type Error = NotSortedError;
+
+    fn try_from(other: Vec<UtcTimeStamp>) -> Result<Self, Self::Error> {
+        SortedTimestamps::new(other)
+    }
+}
+
+impl From<SortedTimestamps> for Vec<UtcTimeStamp> {
+    fn from(other: SortedTimestamps) -> Self {
+        other.0
+    }
+}
+
+impl<'a> IntoIterator for &'a SortedTimestamps {
+    type Item = &'a UtcTimeStamp;
+    type IntoIter = core::slice::Iter<'a, UtcTimeStamp>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn ts(s: i64) -> UtcTimeStamp {
+        UtcTimeStamp::from_seconds(s)
+    }
+
+    #[test]
+    fn sorted_timestamps() {
+        assert_eq!(
+            SortedTimestamps::new(vec![ts(1), ts(3), ts(2)]),
+            Err(NotSortedError { index: 2 })
+        );
+
+        let mut index = SortedTimestamps::from_unsorted(vec![ts(5), ts(1), ts(3), ts(3)]);
+        assert_eq!(index.as_slice(), &[ts(1), ts(3), ts(3), ts(5)]);
+        index.insert(ts(4));
+        index.insert(ts(0));
+        assert_eq!(index.as_slice(), &[
+            ts(0),
+            ts(1),
+            ts(3),
+            ts(3),
+            ts(4),
+            ts(5)
+        ]);
+
+        assert!(index.contains(ts(4)));
+        assert!(!index.contains(ts(2)));
+        assert_eq!(index.len(), 6);
+
+        assert_eq!(index.range(TimeInterval::new(ts(1), ts(4))), &[
+            ts(1),
+            ts(3),
+            ts(3)
+        ]);
+        assert_eq!(index.range_indices(TimeInterval::new(ts(2), ts(3))), 2..2);
+        assert!(index.range(TimeInterval::new(ts(9), ts(1))).is_empty());
+
+        assert_eq!(index.position_asof(ts(3)), Some(3));
+        assert_eq!(index.position_after(ts(2)), Some(2));
+        assert_eq!(index.position_nearest(ts(10)), Some(5));
+    }
+}
@@ -0,0 +1,225 @@
+//! ISO 8601 duration strings such as `PT1H30M`.
+//!
+//! Only the day and time components are supported, as years and months don't
+//! have a fixed length. Days are treated as exactly 24 hours.
+
+use crate::{ParseError, TimeDelta};
+use core::fmt;
+
+const MS_PER_SECOND: i64 = 1000;
+const MS_PER_MINUTE: i64 = 60 * MS_PER_SECOND;
+const MS_PER_HOUR: i64 = 60 * MS_PER_MINUTE;
+const MS_PER_DAY: i64 = 24 * MS_PER_HOUR;
+
+/// Format a delta as `[-]PT[nH][nM][n[.fff]S]`, never emitting days.
+pub(crate) fn format(delta: TimeDelta, f: &mut impl fmt::Write) -> fmt::Result {
+    let ms = delta.as_milliseconds();
+    if ms < 0 {
+        f.write_char('-')?;
+    }
+
+    let ms = ms.unsigned_abs();
+    let (h, m) = (ms / MS_PER_HOUR as u64, ms / MS_PER_MINUTE as u64 % 60);
+    let (s, frac) = (ms / MS_PER_SECOND as u64 % 60, ms % MS_PER_SECOND as u64);
+
+    f.write_str("PT")?;
+    if h != 0 {
+        write!(f, "{}H", h)?;
+    }
+    if m != 0 {
+        write!(f, "{}M", m)?;
+    }
+    if frac != 0 {
+        write!(f, "{}.{:03}S", s, frac)
+    } else if s != 0 || ms == 0 {
+        write!(f, "{}S", s)
+    } else {
+        Ok(())
+    }
+}
+
+/// Parse `[+-]P[nW][nD][T[nH][nM][n[.f]S]]`. Fractions beyond milliseconds are truncated.
+pub(crate) fn parse(s: &str) -> Option<TimeDelta> {
+    let (negative, s) = match s.as_bytes().first()? {
+        b'-' => (true, &s[1..]),
+        b'+' => (false, &s[1..]),
+        _ => (false, s),
+    };
+
+    let s = s.strip_prefix('P')?;
+    let (date, time) = match s.find('T') {
+        Some(idx) => (&s[..idx], Some(&s[idx + 1..])),
+        None => (s, None),
+    };
+
+    let mut total: i64 = 0;
+    let mut any = false;
+    let mut add = |value: i64, unit: i64| -> Option<()> {
+        total = total.checked_add(value.checked_mul(unit)?)?;
+        any = true;
+        Some(())
+    };
+
+    let mut rest = date;
+    for &(designator, unit) in &[('W', 7 * MS_PER_DAY), ('D', MS_PER_DAY)] {
+        if let Some((value, tail)) = component(rest, designator) {
+            add(value.parse().ok()?, unit)?;
+            rest = tail;
+        }
+    }
+    if !rest.is_empty() {
+        return None;
+    }
+
+    if let Some(time) = time {
+        let mut rest = time;
+        for &(designator, unit) in &[('H', MS_PER_HOUR), ('M', MS_PER_MINUTE)] {
+            if let Some((value, tail)) = component(rest, designator) {
+                add(value.parse().ok()?, unit)?;
+                rest = tail;
+            }
+        }
+        if let Some((value, tail)) = component(rest, 'S') {
+            add(parse_seconds(value)?, 1)?;
+            rest = tail;
+        }
+        if !rest.is_empty() || time.is_empty() {
+            return None;
+        }
+    }
+
+    if !any {
+        return None;
+    }
+    Some(TimeDelta::from_milliseconds(if negative {
+        -total
+    } else {
+        total
+    }))
+}
+
+/// Split off a leading `<number><designator>` component.
+fn component(s: &str, designator: char) -> Option<(&str, &str)> {
+    let len = s.find(|c: char| !c.is_ascii_digit() && c != '.' && c != ',')?;
+    if len == 0 || !s[len..].starts_with(designator) {
+        return None;
+    }
+    Some((&s[..len], &s[len + 1..]))
+}
+
+/// Parse `n[.f]` seconds into milliseconds.
+fn parse_seconds(s: &str) -> Option<i64> {
+    let (int, frac) = match s.find(['.', ',']) {
+        Some(idx) => (&s[..idx], &s[idx + 1..]),
+        None => (s, ""),
+    };
+    if int.is_empty() || !int.bytes().all(|x| x.is_ascii_digit()) {
+        return None;
+    }
+    if !frac.bytes().all(|x| x.is_ascii_digit()) {
+        return None;
+    }
+
+    let frac_ms = frac
+        .bytes()
+        .chain(core::iter::repeat(b'0'))
+        .take(3)
+        .fold(0, |acc, x| acc * 10 + (x - b'0') as i64);
+    int.parse::<i64>()
+        .ok()?
+        .checked_mul(MS_PER_SECOND)?
+        .checked_add(frac_ms)
+}
+
+impl TimeDelta {
+    /// Parse an ISO 8601 duration such as `PT1H30M` or `-P1DT0.5S`.
+    ///
+    /// Accepts weeks, days, hours, minutes and (fractional) seconds with an
+    /// optional leading sign. A day is always 24 hours and a week 7 days,
+    /// since a delta isn't tied to a calendar. Years and months are rejected.
+    /// Fractions beyond milliseconds are truncated.
+    ///
+    /// Examples:
+    ///
+    /// ```
+    /// use utctimestamp::TimeDelta;
+    ///
+    /// assert_eq!(TimeDelta::parse_iso8601("PT1H30M"), Ok(TimeDelta::from_minutes(90)));
+    /// assert_eq!(TimeDelta::parse_iso8601("P1D"), Ok(TimeDelta::from_hours(24)));
+    /// assert!(TimeDelta::parse_iso8601("P1M").is_err());
+    /// ```
+    pub fn parse_iso8601(s: &str) -> Result<Self, ParseError> {
+        parse(s).ok_or(ParseError::Invalid)
+    }
+
+    /// Format as an ISO 8601 duration such as `PT1H30M`.
+    ///
+    /// The output only uses hours, minutes and seconds, so a delta of two days
+    /// becomes `PT48H`; this avoids ambiguity with calendar days for
+    /// consumers that apply durations in local time. Zero is `PT0S` and
+    /// negative deltas carry a leading `-`.
+    ///
+    /// Examples:
+    ///
+    /// ```
+    /// use utctimestamp::TimeDelta;
+    ///
+    /// assert_eq!(TimeDelta::from_hours(48).to_iso8601(), "PT48H");
+    /// assert_eq!(TimeDelta::from_milliseconds(-1500).to_iso8601(), "-PT1.500S");
+    /// ```
+    pub fn to_iso8601(self) -> String {
+        let mut s = String::new();
+        format(self, &mut s).expect("writing to a String never fails");
+        s
+    }
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fmt(ms: i64) -> String {
+        let mut s = String::new();
+        format(TimeDelta::from_milliseconds(ms), &mut s).unwrap();
+        s
+    }
+
+    fn ms(s: &str) -> Option<i64> {
+        parse(s).map(TimeDelta::as_milliseconds)
+    }
+
+    #[test]
+    fn format_iso() {
+        assert_eq!(fmt(0), "PT0S");
+        assert_eq!(fmt(5_400_000), "PT1H30M");
+        assert_eq!(fmt(90_061_001), "PT25H1M1.001S");
+        assert_eq!(fmt(-1500), "-PT1.500S");
+        assert_eq!(fmt(i64::MIN), "-PT2562047788015H12M55.808S");
+    }
+
+    #[test]
+    fn parse_iso() {
+        assert_eq!(ms("PT1H30M"), Some(5_400_000));
+        assert_eq!(ms("P1DT1S"), Some(86_401_000));
+        assert_eq!(ms("P2W"), Some(1_209_600_000));
+        assert_eq!(ms("-PT0.5S"), Some(-500));
+        assert_eq!(ms("PT1,25S"), Some(1250));
+        assert_eq!(ms("PT0.0019S"), Some(1));
+        assert_eq!(ms("PT0S"), Some(0));
+        assert_eq!(ms("P"), None);
+        assert_eq!(ms("PT"), None);
+        assert_eq!(ms("P1Y"), None);
+        assert_eq!(ms("PT1S1M"), None);
+        assert_eq!(ms("PT1.5H"), None);
+        assert_eq!(ms("1H"), None);
+        assert_eq!(ms("P9999999999999999D"), None);
+
+        for &x in &[0, 1, -59_999, 5_400_000, 123_456_789, i64::MAX] {
+            assert_eq!(ms(&fmt(x)), Some(x));
+        }
+    }
+}
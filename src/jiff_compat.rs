@@ -0,0 +1,132 @@
+//! Conversions from and to the `jiff` crate.
+
+use crate::{OutOfRangeError, TimeDelta, UtcTimeStamp};
+use ::jiff::{SignedDuration, Span, Timestamp, Zoned};
+use core::convert::TryFrom;
+
+/// Convert a jiff timestamp, rounding towards the past to whole milliseconds.
+impl From<Timestamp> for UtcTimeStamp {
+    fn from(other: Timestamp) -> Self {
+        let ms = other.as_nanosecond().div_euclid(1_000_000);
+        UtcTimeStamp::from_milliseconds(ms as i64)
+    }
+}
+
+/// Convert the instant of a zoned jiff date time, see `From<Timestamp>`.
+impl From<&Zoned> for UtcTimeStamp {
+    fn from(other: &Zoned) -> Self {
+        UtcTimeStamp::from(other.timestamp())
+    }
+}
+
+/// Convert the instant of a zoned jiff date time, see `From<Timestamp>`.
+impl From<Zoned> for UtcTimeStamp {
+    fn from(other: Zoned) -> Self {
+        UtcTimeStamp::from(&other)
+    }
+}
+
+/// Convert to a jiff timestamp.
+///
+/// Fails for years outside of jiff's supported range of ±9999.
+impl TryFrom<UtcTimeStamp> for Timestamp {
+    type Error = OutOfRangeError;
+
+    fn try_from(other: UtcTimeStamp) -> Result<Self, Self::Error> {
+        Timestamp::from_millisecond(other.as_milliseconds()).map_err(|_| OutOfRangeError)
+    }
+}
+
+/// Convert a jiff duration, truncating sub-millisecond precision.
+///
+/// Fails for durations longer than `i64::MAX` milliseconds.
+impl TryFrom<SignedDuration> for TimeDelta {
+    type Error = OutOfRangeError;
+
+    fn try_from(other: SignedDuration) -> Result<Self, Self::Error> {
+        i64::try_from(other.as_millis())
+            .map(TimeDelta::from_milliseconds)
+            .map_err(|_| OutOfRangeError)
+    }
+}
+
+/// Convert to a jiff duration.
+impl From<TimeDelta> for SignedDuration {
+    fn from(other: TimeDelta) -> Self {
+        SignedDuration::from_millis(other.as_milliseconds())
+    }
+}
+
+/// Convert a jiff span, truncating sub-millisecond precision.
+///
+/// Fails for spans with units of days or larger, whose length depends on a
+/// reference date, and for spans longer than `i64::MAX` milliseconds.
+impl TryFrom<Span> for TimeDelta {
+    type Error = OutOfRangeError;
+
+    fn try_from(other: Span) -> Result<Self, Self::Error> {
+        let duration = SignedDuration::try_from(other).map_err(|_| OutOfRangeError)?;
+        TimeDelta::try_from(duration)
+    }
+}
+
+/// Convert to a jiff span with units of seconds and milliseconds.
+///
+/// Fails for deltas beyond the range of a span, about ±20,000 years.
+impl TryFrom<TimeDelta> for Span {
+    type Error = OutOfRangeError;
+
+    fn try_from(other: TimeDelta) -> Result<Self, Self::Error> {
+        Span::try_from(SignedDuration::from(other)).map_err(|_| OutOfRangeError)
+    }
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use ::jiff::{tz::TimeZone, SignedDuration, Span, Timestamp, ToSpan};
+    use core::convert::TryFrom;
+
+    #[test]
+    fn jiff() {
+        let jts = Timestamp::from_nanosecond(-1_500_000_001).unwrap();
+        let ts = UtcTimeStamp::from(jts);
+        assert_eq!(ts.as_milliseconds(), -1501);
+        assert_eq!(
+            Timestamp::try_from(ts).unwrap(),
+            Timestamp::from_millisecond(-1501).unwrap()
+        );
+        assert_eq!(
+            Timestamp::try_from(UtcTimeStamp::from_milliseconds(i64::MIN)),
+            Err(OutOfRangeError)
+        );
+
+        let zoned = jts.to_zoned(TimeZone::fixed(::jiff::tz::offset(2)));
+        assert_eq!(UtcTimeStamp::from(&zoned), ts);
+        assert_eq!(UtcTimeStamp::from(zoned), ts);
+
+        let delta = TimeDelta::try_from(SignedDuration::from_micros(-1999)).unwrap();
+        assert_eq!(delta, TimeDelta::from_milliseconds(-1));
+        assert_eq!(SignedDuration::from(delta), SignedDuration::from_millis(-1));
+        assert_eq!(
+            TimeDelta::try_from(SignedDuration::MAX),
+            Err(OutOfRangeError)
+        );
+
+        let span = 1.hour().minutes(30).milliseconds(5);
+        assert_eq!(
+            TimeDelta::try_from(span),
+            Ok(TimeDelta::from_milliseconds(5_400_005))
+        );
+        assert_eq!(TimeDelta::try_from(1.day()), Err(OutOfRangeError));
+
+        let span = Span::try_from(TimeDelta::from_milliseconds(-61_001)).unwrap();
+        assert_eq!(span.get_seconds(), -61);
+        assert_eq!(span.get_milliseconds(), -1);
+        assert!(Span::try_from(TimeDelta::from_milliseconds(i64::MAX)).is_err());
+    }
+}
@@ -9,10 +9,130 @@
 //! `From` and `Into` traits. chrono is then used for all things that aren't expected
 //! to occur in big batches, such as formatting and displaying the timestamps.
 
-use core::{fmt, ops};
+use core::{cmp, iter, ops};
+
+mod bulk;
+mod calendar;
+mod column;
+mod convert;
+mod epoch;
+mod format;
+mod gaps;
+#[cfg(feature = "hifitime-support")]
+mod hifitime_compat;
+mod index;
+mod iso_duration;
+#[cfg(feature = "jiff-support")]
+mod jiff_compat;
+#[cfg(feature = "libc-support")]
+mod libc_compat;
+mod macros;
+mod merge;
+#[cfg(feature = "natural-language")]
+mod natural;
+mod parse;
+#[cfg(feature = "prost-support")]
+mod prost_compat;
+mod relative;
+mod resample;
+mod rrule;
+#[cfg(feature = "utoipa-support")]
+mod schema;
+mod series;
+mod stats;
+#[cfg(feature = "time-support")]
+mod time_compat;
+mod window;
+
+#[cfg(feature = "arrow-support")]
+pub mod arrow;
+pub mod avro;
+pub mod codec;
+pub mod otlp;
+#[cfg(feature = "rayon-support")]
+pub mod par;
+#[cfg(feature = "parquet-support")]
+pub mod parquet;
+#[cfg(feature = "polars-support")]
+pub mod polars;
+pub mod prometheus;
+pub mod search;
+#[cfg(feature = "serde-support")]
+pub mod serde;
+
+pub use crate::{
+    bulk::{
+        align_slice_into, align_slice_to, as_millis_slice, as_millis_slice_mut, from_chrono_iter,
+        from_chrono_slice, from_millis_slice, from_millis_slice_mut, from_millis_vec,
+        into_millis_vec, to_chrono_iter, to_chrono_vec,
+    },
+    calendar::{BusinessCalendar, DateParts, FiscalCalendar, TimeOfDay},
+    column::TimestampVec,
+    convert::{NegativeDelta, OutOfRangeError, PrecisionLossError},
+    epoch::GPS_LEAP_SECONDS,
+    format::{Humanize, Scaled},
+    gaps::{find_gaps, FillGaps, FillGapsFlagged, Gaps},
+    index::{NotSortedError, SortedTimestamps},
+    merge::{merge_sorted, MergeSorted, Timestamped},
+    parse::{parse_fixed_column, ParseError},
+    resample::{agg, Aggregate, AggregateWith, Aggregator, OhlcBar, Resampler},
+    rrule::{ByDay, Frequency, RecurrenceRule, RecurrenceRuleError, Recurrences},
+    series::TimeSeries,
+    stats::{
+        bucket_counts, delta_summary, infer_freq, infer_freq_within, timestamp_summary, Summary,
+    },
+    window::{rolling_windows, RollingWindow, RollingWindows},
+};
+
+#[doc(hidden)]
+pub use crate::macros::__private;
+
+#[cfg(feature = "locale-support")]
+pub use chrono::Locale;
+
+#[cfg(feature = "chrono-tz-support")]
+pub use chrono_tz::Tz;
 
 #[cfg(feature = "serde-support")]
-use serde::{Deserialize, Serialize};
+use ::serde::{Deserialize, Serialize};
+
+/// Round fractional milliseconds to the nearest integer, `None` if not representable.
+fn round_millis(ms: f64) -> Option<i64> {
+    let ms = ms.round();
+    if ms.is_finite() && ms >= i64::MIN as f64 && ms < i64::MAX as f64 {
+        Some(ms as i64)
+    } else {
+        None
+    }
+}
+
+/// Rounding mode for conversions from sources with sub-millisecond precision.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Rounding {
+    /// Round towards negative infinity, like the `From` conversions.
+    Floor,
+    /// Round towards positive infinity.
+    Ceil,
+    /// Round to the nearest millisecond, with halfway cases rounded up.
+    Nearest,
+}
+
+impl Rounding {
+    /// Round `floor + rem / divisor` to an integer, given `0 <= rem < divisor`.
+    pub(crate) fn apply(self, floor: i64, rem: i64, divisor: i64) -> i64 {
+        let up = match self {
+            Rounding::Floor => false,
+            Rounding::Ceil => rem > 0,
+            Rounding::Nearest => rem >= divisor - divisor / 2,
+        };
+        floor + up as i64
+    }
+
+    /// Divide `n` by a positive `divisor`, rounding the quotient.
+    pub(crate) fn div(self, n: i64, divisor: i64) -> i64 {
+        self.apply(n.div_euclid(divisor), n.rem_euclid(divisor), divisor)
+    }
+}
 
 // ============================================================================================== //
 // [UTC timestamp]                                                                                //
@@ -21,36 +141,56 @@ use serde::{Deserialize, Serialize};
 /// Represents a dumb but fast UTC timestamp.
 #[repr(transparent)]
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    all(feature = "serde-support", not(feature = "serde-human-readable")),
+    derive(Serialize, Deserialize)
+)]
 pub struct UtcTimeStamp(i64);
 
-/// Display timestamp using chrono.
-impl fmt::Display for UtcTimeStamp {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        chrono::DateTime::<chrono::Utc>::from(*self).fmt(f)
+/// Create a dumb timestamp from a chrono date time object in any time zone.
+impl<Tz: chrono::TimeZone> From<chrono::DateTime<Tz>> for UtcTimeStamp {
+    fn from(other: chrono::DateTime<Tz>) -> Self {
+        Self(other.timestamp_millis())
     }
 }
 
-impl fmt::Debug for UtcTimeStamp {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "UtcTimeStamp({})", self.0)
+/// Create a chrono date time object from a dumb timestamp.
+impl From<UtcTimeStamp> for chrono::DateTime<chrono::Utc> {
+    fn from(other: UtcTimeStamp) -> Self {
+        chrono::DateTime::from_timestamp_millis(other.0).expect("invalid or out-of-range datetime")
     }
 }
 
-/// Create a dumb timestamp from a chrono date time object.
-impl From<chrono::DateTime<chrono::Utc>> for UtcTimeStamp {
-    fn from(other: chrono::DateTime<chrono::Utc>) -> Self {
-        Self(other.timestamp_millis())
+/// Milliseconds and sub-millisecond nanoseconds, ordered like the date time itself.
+fn chrono_cmp_key(dt: &chrono::DateTime<chrono::Utc>) -> (i64, u32) {
+    (
+        dt.timestamp_millis(),
+        dt.timestamp_subsec_nanos() % 1_000_000,
+    )
+}
+
+/// Compare with a chrono date time, which is equal only if it has no sub-millisecond part.
+impl PartialEq<chrono::DateTime<chrono::Utc>> for UtcTimeStamp {
+    fn eq(&self, other: &chrono::DateTime<chrono::Utc>) -> bool {
+        (self.0, 0) == chrono_cmp_key(other)
     }
 }
 
-/// Create a chrono date time object from a dumb timestamp.
-impl From<UtcTimeStamp> for chrono::DateTime<chrono::Utc> {
-    fn from(other: UtcTimeStamp) -> Self {
-        let sec = other.0 / 1000;
-        let ns = (other.0 % 1000 * 1_000_000) as u32;
-        let naive = chrono::NaiveDateTime::from_timestamp(sec, ns);
-        chrono::DateTime::<chrono::Utc>::from_utc(naive, chrono::Utc)
+impl PartialEq<UtcTimeStamp> for chrono::DateTime<chrono::Utc> {
+    fn eq(&self, other: &UtcTimeStamp) -> bool {
+        other == self
+    }
+}
+
+impl PartialOrd<chrono::DateTime<chrono::Utc>> for UtcTimeStamp {
+    fn partial_cmp(&self, other: &chrono::DateTime<chrono::Utc>) -> Option<cmp::Ordering> {
+        Some((self.0, 0).cmp(&chrono_cmp_key(other)))
+    }
+}
+
+impl PartialOrd<UtcTimeStamp> for chrono::DateTime<chrono::Utc> {
+    fn partial_cmp(&self, other: &UtcTimeStamp) -> Option<cmp::Ordering> {
+        other.partial_cmp(self).map(cmp::Ordering::reverse)
     }
 }
 
@@ -78,12 +218,194 @@ impl UtcTimeStamp {
         UtcTimeStamp(int * 1000)
     }
 
+    /// Conversion from `i64` microseconds, rounded as specified.
+    #[inline]
+    pub fn from_microseconds(micros: i64, rounding: Rounding) -> Self {
+        UtcTimeStamp(rounding.div(micros, 1000))
+    }
+
+    /// Conversion from `i64` nanoseconds, rounded as specified.
+    ///
+    /// Examples:
+    ///
+    /// ```
+    /// use utctimestamp::{Rounding, UtcTimeStamp};
+    ///
+    /// let nanos = -1_500_001;
+    /// assert_eq!(UtcTimeStamp::from_nanoseconds(nanos, Rounding::Floor).as_milliseconds(), -2);
+    /// assert_eq!(UtcTimeStamp::from_nanoseconds(nanos, Rounding::Ceil).as_milliseconds(), -1);
+    /// assert_eq!(UtcTimeStamp::from_nanoseconds(nanos, Rounding::Nearest).as_milliseconds(), -2);
+    /// ```
+    #[inline]
+    pub fn from_nanoseconds(nanos: i64, rounding: Rounding) -> Self {
+        UtcTimeStamp(rounding.div(nanos, 1_000_000))
+    }
+
+    /// Conversion from fractional milliseconds, rounded to the nearest millisecond.
+    ///
+    /// Returns `None` for non-finite or out-of-range inputs.
+    pub fn from_milliseconds_f64(ms: f64) -> Option<Self> {
+        round_millis(ms).map(UtcTimeStamp)
+    }
+
+    /// Conversion from fractional seconds, rounded to the nearest millisecond.
+    ///
+    /// Returns `None` for non-finite or out-of-range inputs.
+    ///
+    /// Examples:
+    ///
+    /// ```
+    /// use utctimestamp::UtcTimeStamp;
+    ///
+    /// let ts = UtcTimeStamp::from_seconds_f64(1694791200.125).unwrap();
+    /// assert_eq!(ts.as_milliseconds(), 1_694_791_200_125);
+    /// assert_eq!(UtcTimeStamp::from_seconds_f64(f64::NAN), None);
+    /// ```
+    pub fn from_seconds_f64(secs: f64) -> Option<Self> {
+        round_millis(secs * 1000.0).map(UtcTimeStamp)
+    }
+
+    /// Conversion from a chrono date time, failing if it has a sub-millisecond part.
+    ///
+    /// Examples:
+    ///
+    /// ```
+    /// use chrono::{offset::TimeZone, Duration, Utc};
+    /// use utctimestamp::{Rounding, UtcTimeStamp};
+    ///
+    /// let dt = Utc.with_ymd_and_hms(2020, 9, 13, 12, 26, 40).unwrap();
+    /// assert_eq!(UtcTimeStamp::from_datetime_exact(dt), Ok(UtcTimeStamp::from_seconds(1_600_000_000)));
+    ///
+    /// let dt = dt + Duration::microseconds(1500);
+    /// assert!(UtcTimeStamp::from_datetime_exact(dt).is_err());
+    /// assert_eq!(UtcTimeStamp::from_datetime_rounded(dt, Rounding::Floor).as_milliseconds(), 1_600_000_000_001);
+    /// assert_eq!(UtcTimeStamp::from_datetime_rounded(dt, Rounding::Nearest).as_milliseconds(), 1_600_000_000_002);
+    /// ```
+    pub fn from_datetime_exact<Tz: chrono::TimeZone>(
+        dt: chrono::DateTime<Tz>,
+    ) -> Result<Self, PrecisionLossError> {
+        match dt.timestamp_subsec_nanos() % 1_000_000 {
+            0 => Ok(UtcTimeStamp(dt.timestamp_millis())),
+            _ => Err(PrecisionLossError),
+        }
+    }
+
+    /// Conversion from a chrono date time, rounding sub-millisecond parts as specified.
+    pub fn from_datetime_rounded<Tz: chrono::TimeZone>(
+        dt: chrono::DateTime<Tz>,
+        rounding: Rounding,
+    ) -> Self {
+        let rem = dt.timestamp_subsec_nanos() % 1_000_000;
+        UtcTimeStamp(rounding.apply(dt.timestamp_millis(), rem.into(), 1_000_000))
+    }
+
+    /// Conversion from a chrono naive date time known to be in UTC.
+    ///
+    /// Examples:
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use utctimestamp::UtcTimeStamp;
+    ///
+    /// let naive = NaiveDate::from_ymd_opt(2020, 9, 13).unwrap().and_hms_milli_opt(12, 26, 40, 250).unwrap();
+    /// let ts = UtcTimeStamp::from_naive_utc(naive);
+    /// assert_eq!(ts.as_milliseconds(), 1_600_000_000_250);
+    /// assert_eq!(ts.to_naive_utc(), naive);
+    /// ```
+    pub fn from_naive_utc(naive: chrono::NaiveDateTime) -> Self {
+        UtcTimeStamp(naive.and_utc().timestamp_millis())
+    }
+
+    /// Conversion to a chrono naive date time in UTC.
+    ///
+    /// Panics if the timestamp is out of range for chrono.
+    pub fn to_naive_utc(self) -> chrono::NaiveDateTime {
+        chrono::DateTime::<chrono::Utc>::from(self).naive_utc()
+    }
+
+    /// Conversion to a chrono date time in the given time zone.
+    ///
+    /// Panics if the timestamp is out of range for chrono.
+    ///
+    /// Examples:
+    ///
+    /// ```
+    /// use chrono::{FixedOffset, Timelike};
+    /// use utctimestamp::UtcTimeStamp;
+    ///
+    /// let ts = UtcTimeStamp::from_seconds(1_600_000_000);
+    /// let local = ts.to_datetime(FixedOffset::east_opt(2 * 3600).unwrap());
+    /// assert_eq!(local.hour(), 14);
+    /// ```
+    pub fn to_datetime<Tz: chrono::TimeZone>(self, tz: Tz) -> chrono::DateTime<Tz> {
+        chrono::DateTime::<chrono::Utc>::from(self).with_timezone(&tz)
+    }
+
     /// Explicit conversion to `i64`.
     #[inline]
     pub const fn as_milliseconds(self) -> i64 {
         self.0
     }
 
+    /// Milliseconds since the epoch as a float.
+    ///
+    /// Exact for timestamps within ±2^53 milliseconds (about 285,000 years).
+    #[inline]
+    pub fn as_milliseconds_f64(self) -> f64 {
+        self.0 as f64
+    }
+
+    /// Fractional seconds since the epoch, as used by Python, Prometheus and others.
+    ///
+    /// Most millisecond values have no exact binary representation, but the
+    /// result is correctly rounded and converts back losslessly through
+    /// [`from_seconds_f64`](Self::from_seconds_f64) for timestamps within
+    /// ±2^51 milliseconds (about 71,000 years).
+    ///
+    /// Examples:
+    ///
+    /// ```
+    /// use utctimestamp::UtcTimeStamp;
+    ///
+    /// let ts = UtcTimeStamp::from_milliseconds(1_694_791_200_125);
+    /// assert_eq!(ts.as_seconds_f64(), 1694791200.125);
+    /// assert_eq!(UtcTimeStamp::from_seconds_f64(ts.as_seconds_f64()), Some(ts));
+    /// ```
+    #[inline]
+    pub fn as_seconds_f64(self) -> f64 {
+        self.0 as f64 / 1000.0
+    }
+
+    /// Whole seconds since the epoch, floored towards negative infinity.
+    #[inline]
+    pub const fn as_seconds(self) -> i64 {
+        self.0.div_euclid(1000)
+    }
+
+    /// Milliseconds beyond [`as_seconds`](Self::as_seconds), always in `0..1000`.
+    #[inline]
+    pub const fn subsec_milliseconds(self) -> u32 {
+        self.0.rem_euclid(1000) as u32
+    }
+
+    /// Split into whole seconds and the milliseconds beyond them.
+    ///
+    /// The fraction is always non-negative, so timestamps before the epoch
+    /// yield the preceding second and the milliseconds elapsed since.
+    ///
+    /// Examples:
+    ///
+    /// ```
+    /// use utctimestamp::UtcTimeStamp;
+    ///
+    /// assert_eq!(UtcTimeStamp::from_milliseconds(1_250).split_seconds(), (1, 250));
+    /// assert_eq!(UtcTimeStamp::from_milliseconds(-1_250).split_seconds(), (-2, 750));
+    /// ```
+    #[inline]
+    pub const fn split_seconds(self) -> (i64, u32) {
+        (self.as_seconds(), self.subsec_milliseconds())
+    }
+
     /// Align a timestamp to a given frequency.
     pub const fn align_to(self, freq: TimeDelta) -> UtcTimeStamp {
         self.align_to_anchored(UtcTimeStamp::zero(), freq)
@@ -131,6 +453,24 @@ impl ops::SubAssign<TimeDelta> for UtcTimeStamp {
     }
 }
 
+/// Calculate the timestamp advanced by a chrono duration, truncated to milliseconds.
+impl ops::Add<chrono::Duration> for UtcTimeStamp {
+    type Output = UtcTimeStamp;
+
+    fn add(self, rhs: chrono::Duration) -> Self::Output {
+        self + TimeDelta::from(rhs)
+    }
+}
+
+/// Calculate the timestamp lessened by a chrono duration, truncated to milliseconds.
+impl ops::Sub<chrono::Duration> for UtcTimeStamp {
+    type Output = UtcTimeStamp;
+
+    fn sub(self, rhs: chrono::Duration) -> Self::Output {
+        self - TimeDelta::from(rhs)
+    }
+}
+
 /// Calculate signed timedelta between two timestamps.
 impl ops::Sub<UtcTimeStamp> for UtcTimeStamp {
     type Output = TimeDelta;
@@ -155,18 +495,14 @@ impl ops::Sub<UtcTimeStamp> for UtcTimeStamp {
 
 /// Millisecond precision time delta.
 #[repr(transparent)]
-#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
 pub struct TimeDelta(i64);
 
-/// Display timedelta using chrono.
-impl fmt::Display for TimeDelta {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        chrono::Duration::from(*self).fmt(f)
-    }
-}
-
 /// Create a simple timedelta from a chrono duration.
+///
+/// Sub-millisecond precision is truncated. Chrono's range of `±i64::MAX`
+/// milliseconds fits into ours, so this never overflows.
 impl From<chrono::Duration> for TimeDelta {
     fn from(other: chrono::Duration) -> Self {
         Self(other.num_milliseconds())
@@ -174,6 +510,9 @@ impl From<chrono::Duration> for TimeDelta {
 }
 
 /// Create a chrono duration from a simple timedelta.
+///
+/// Panics for `i64::MIN` milliseconds, which chrono can't represent; see
+/// [`TimeDelta::to_chrono`] for a fallible conversion.
 impl From<TimeDelta> for chrono::Duration {
     fn from(other: TimeDelta) -> Self {
         chrono::Duration::milliseconds(other.0)
@@ -188,6 +527,12 @@ impl ops::Add<TimeDelta> for TimeDelta {
     }
 }
 
+impl ops::AddAssign<TimeDelta> for TimeDelta {
+    fn add_assign(&mut self, rhs: TimeDelta) {
+        *self = *self + rhs;
+    }
+}
+
 impl ops::Sub<TimeDelta> for TimeDelta {
     type Output = TimeDelta;
 
@@ -196,6 +541,21 @@ impl ops::Sub<TimeDelta> for TimeDelta {
     }
 }
 
+impl ops::SubAssign<TimeDelta> for TimeDelta {
+    fn sub_assign(&mut self, rhs: TimeDelta) {
+        *self = *self - rhs;
+    }
+}
+
+/// Reverse the direction of the delta.
+impl ops::Neg for TimeDelta {
+    type Output = TimeDelta;
+
+    fn neg(self) -> Self::Output {
+        TimeDelta(-self.0)
+    }
+}
+
 /// Multiply the delta to be n times as long.
 impl ops::Mul<i64> for TimeDelta {
     type Output = TimeDelta;
@@ -205,6 +565,21 @@ impl ops::Mul<i64> for TimeDelta {
     }
 }
 
+impl ops::MulAssign<i64> for TimeDelta {
+    fn mul_assign(&mut self, rhs: i64) {
+        *self = *self * rhs;
+    }
+}
+
+/// Multiply the delta to be n times as long, with the factor first.
+impl ops::Mul<TimeDelta> for i64 {
+    type Output = TimeDelta;
+
+    fn mul(self, rhs: TimeDelta) -> Self::Output {
+        rhs * self
+    }
+}
+
 /// Shorten the delta by a given factor. Integer div.
 impl ops::Div<i64> for TimeDelta {
     type Output = TimeDelta;
@@ -214,6 +589,35 @@ impl ops::Div<i64> for TimeDelta {
     }
 }
 
+impl ops::DivAssign<i64> for TimeDelta {
+    fn div_assign(&mut self, rhs: i64) {
+        *self = *self / rhs;
+    }
+}
+
+/// Scale the delta by a fractional factor, rounded to the nearest millisecond.
+///
+/// Halfway cases round away from zero. Panics if the result is NaN or out of range.
+impl ops::Mul<f64> for TimeDelta {
+    type Output = TimeDelta;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        TimeDelta(round_millis(self.0 as f64 * rhs).expect("scaled delta out of range"))
+    }
+}
+
+/// Divide the delta by a fractional factor, rounded to the nearest millisecond.
+///
+/// Halfway cases round away from zero. Panics if the result is NaN or out of
+/// range, including on division by zero.
+impl ops::Div<f64> for TimeDelta {
+    type Output = TimeDelta;
+
+    fn div(self, rhs: f64) -> Self::Output {
+        TimeDelta(round_millis(self.0 as f64 / rhs).expect("scaled delta out of range"))
+    }
+}
+
 /// How many times does the timestamp fit into another?
 impl ops::Div<TimeDelta> for TimeDelta {
     type Output = i64;
@@ -232,13 +636,53 @@ impl ops::Rem<TimeDelta> for TimeDelta {
     }
 }
 
+/// Total length of all deltas. Overflow panics in debug builds, like `+`.
+///
+/// Examples:
+///
+/// ```
+/// use utctimestamp::TimeDelta;
+///
+/// let deltas = vec![TimeDelta::from_minutes(90), TimeDelta::from_minutes(30)];
+/// assert_eq!(deltas.iter().sum::<TimeDelta>(), TimeDelta::from_hours(2));
+/// assert_eq!(deltas.into_iter().sum::<TimeDelta>(), TimeDelta::from_hours(2));
+/// ```
+impl iter::Sum for TimeDelta {
+    fn sum<I: Iterator<Item = TimeDelta>>(iter: I) -> Self {
+        iter.fold(TimeDelta::zero(), ops::Add::add)
+    }
+}
+
+impl<'a> iter::Sum<&'a TimeDelta> for TimeDelta {
+    fn sum<I: Iterator<Item = &'a TimeDelta>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
 /// Explicit conversion from and to `i64`.
 impl TimeDelta {
+    pub const MILLISECOND: TimeDelta = TimeDelta(1);
+    pub const SECOND: TimeDelta = TimeDelta::from_seconds(1);
+    pub const MINUTE: TimeDelta = TimeDelta::from_minutes(1);
+    pub const HOUR: TimeDelta = TimeDelta::from_hours(1);
+    pub const DAY: TimeDelta = TimeDelta::from_days(1);
+    pub const WEEK: TimeDelta = TimeDelta::from_weeks(1);
+
     #[inline]
     pub const fn zero() -> Self {
         TimeDelta(0)
     }
 
+    #[inline]
+    pub const fn from_weeks(int: i64) -> Self {
+        TimeDelta::from_days(int * 7)
+    }
+
+    #[inline]
+    pub const fn from_days(int: i64) -> Self {
+        TimeDelta::from_hours(int * 24)
+    }
+
     #[inline]
     pub const fn from_hours(int: i64) -> Self {
         TimeDelta::from_minutes(int * 60)
@@ -264,6 +708,186 @@ impl TimeDelta {
         self.0
     }
 
+    /// Conversion from fractional seconds, rounded to the nearest millisecond.
+    ///
+    /// Halfway cases round away from zero. Returns `None` for non-finite or
+    /// out-of-range inputs.
+    ///
+    /// Examples:
+    ///
+    /// ```
+    /// use utctimestamp::TimeDelta;
+    ///
+    /// assert_eq!(TimeDelta::from_seconds_f64(0.0125), Some(TimeDelta::from_milliseconds(13)));
+    /// assert_eq!(TimeDelta::from_seconds_f64(-1.5), Some(TimeDelta::from_milliseconds(-1500)));
+    /// assert_eq!(TimeDelta::from_seconds_f64(f64::INFINITY), None);
+    /// ```
+    pub fn from_seconds_f64(secs: f64) -> Option<Self> {
+        round_millis(secs * 1000.0).map(TimeDelta)
+    }
+
+    /// Fractional seconds.
+    ///
+    /// Correctly rounded for deltas up to 2^53 milliseconds (about 285,000
+    /// years); larger deltas may be off by one rounding step more.
+    #[inline]
+    pub fn as_seconds_f64(self) -> f64 {
+        self.0 as f64 / 1000.0
+    }
+
+    /// Like [`from_weeks`](Self::from_weeks), but `None` on overflow.
+    #[inline]
+    pub const fn checked_from_weeks(int: i64) -> Option<Self> {
+        match int.checked_mul(7) {
+            Some(days) => TimeDelta::checked_from_days(days),
+            None => None,
+        }
+    }
+
+    /// Like [`from_days`](Self::from_days), but `None` on overflow.
+    #[inline]
+    pub const fn checked_from_days(int: i64) -> Option<Self> {
+        match int.checked_mul(24) {
+            Some(hours) => TimeDelta::checked_from_hours(hours),
+            None => None,
+        }
+    }
+
+    /// Like [`from_hours`](Self::from_hours), but `None` on overflow.
+    ///
+    /// Examples:
+    ///
+    /// ```
+    /// use utctimestamp::TimeDelta;
+    ///
+    /// assert_eq!(TimeDelta::checked_from_hours(2), Some(TimeDelta::from_minutes(120)));
+    /// assert_eq!(TimeDelta::checked_from_hours(i64::MAX / 1000), None);
+    /// ```
+    #[inline]
+    pub const fn checked_from_hours(int: i64) -> Option<Self> {
+        match int.checked_mul(60) {
+            Some(minutes) => TimeDelta::checked_from_minutes(minutes),
+            None => None,
+        }
+    }
+
+    /// Like [`from_minutes`](Self::from_minutes), but `None` on overflow.
+    #[inline]
+    pub const fn checked_from_minutes(int: i64) -> Option<Self> {
+        match int.checked_mul(60) {
+            Some(seconds) => TimeDelta::checked_from_seconds(seconds),
+            None => None,
+        }
+    }
+
+    /// Like [`from_seconds`](Self::from_seconds), but `None` on overflow.
+    #[inline]
+    pub const fn checked_from_seconds(int: i64) -> Option<Self> {
+        match int.checked_mul(1000) {
+            Some(ms) => Some(TimeDelta(ms)),
+            None => None,
+        }
+    }
+
+    /// Checked addition, `None` on overflow.
+    #[inline]
+    pub const fn checked_add(self, rhs: TimeDelta) -> Option<Self> {
+        match self.0.checked_add(rhs.0) {
+            Some(ms) => Some(TimeDelta(ms)),
+            None => None,
+        }
+    }
+
+    /// Checked subtraction, `None` on overflow.
+    #[inline]
+    pub const fn checked_sub(self, rhs: TimeDelta) -> Option<Self> {
+        match self.0.checked_sub(rhs.0) {
+            Some(ms) => Some(TimeDelta(ms)),
+            None => None,
+        }
+    }
+
+    /// Checked multiplication, `None` on overflow.
+    #[inline]
+    pub const fn checked_mul(self, rhs: i64) -> Option<Self> {
+        match self.0.checked_mul(rhs) {
+            Some(ms) => Some(TimeDelta(ms)),
+            None => None,
+        }
+    }
+
+    /// Checked integer division, `None` if `rhs` is zero or on overflow.
+    #[inline]
+    pub const fn checked_div(self, rhs: i64) -> Option<Self> {
+        match self.0.checked_div(rhs) {
+            Some(ms) => Some(TimeDelta(ms)),
+            None => None,
+        }
+    }
+
+    /// Saturating addition, clamping to the representable range.
+    #[inline]
+    pub const fn saturating_add(self, rhs: TimeDelta) -> Self {
+        TimeDelta(self.0.saturating_add(rhs.0))
+    }
+
+    /// Saturating subtraction, clamping to the representable range.
+    #[inline]
+    pub const fn saturating_sub(self, rhs: TimeDelta) -> Self {
+        TimeDelta(self.0.saturating_sub(rhs.0))
+    }
+
+    /// Saturating multiplication, clamping to the representable range.
+    #[inline]
+    pub const fn saturating_mul(self, rhs: i64) -> Self {
+        TimeDelta(self.0.saturating_mul(rhs))
+    }
+
+    /// Convert to a chrono duration, reporting overflow instead of panicking.
+    ///
+    /// Examples:
+    ///
+    /// ```
+    /// use utctimestamp::{OutOfRangeError, TimeDelta};
+    ///
+    /// let delta = TimeDelta::from_seconds(5);
+    /// assert_eq!(delta.to_chrono(), Ok(chrono::Duration::seconds(5)));
+    /// assert_eq!(TimeDelta::from_milliseconds(i64::MIN).to_chrono(), Err(OutOfRangeError));
+    /// ```
+    pub fn to_chrono(self) -> Result<chrono::Duration, OutOfRangeError> {
+        chrono::Duration::try_milliseconds(self.0).ok_or(OutOfRangeError)
+    }
+
+    /// Whole seconds, truncated towards zero.
+    #[inline]
+    pub const fn as_seconds(self) -> i64 {
+        self.0 / 1000
+    }
+
+    /// Whole minutes, truncated towards zero.
+    #[inline]
+    pub const fn as_minutes(self) -> i64 {
+        self.as_seconds() / 60
+    }
+
+    /// Whole hours, truncated towards zero.
+    #[inline]
+    pub const fn as_hours(self) -> i64 {
+        self.as_minutes() / 60
+    }
+
+    /// Whole days, truncated towards zero.
+    #[inline]
+    pub const fn as_days(self) -> i64 {
+        self.as_hours() / 24
+    }
+
+    /// Milliseconds beyond [`as_seconds`](Self::as_seconds), with the sign of the delta.
+    #[inline]
+    pub const fn subsec_milliseconds(self) -> i64 {
+        self.0 % 1000
+    }
+
     /// Check whether the timedelta is 0.
     #[inline]
     pub const fn is_zero(self) -> bool {
@@ -283,6 +907,68 @@ impl TimeDelta {
     pub const fn is_negative(self) -> bool {
         self.0 < 0
     }
+
+    /// Absolute length of the delta.
+    ///
+    /// Like [`i64::abs`], this overflows for the most negative delta.
+    #[inline]
+    pub const fn abs(self) -> Self {
+        TimeDelta(self.0.abs())
+    }
+
+    /// `-1`, `0` or `1` depending on the sign of the delta.
+    #[inline]
+    pub const fn signum(self) -> i64 {
+        self.0.signum()
+    }
+
+    /// Fractional ratio between two deltas.
+    ///
+    /// Unlike the truncating `Div<TimeDelta>`, this keeps the fraction.
+    /// Division by a zero delta yields an infinity or NaN.
+    ///
+    /// Examples:
+    ///
+    /// ```
+    /// use utctimestamp::TimeDelta;
+    ///
+    /// let busy = TimeDelta::from_seconds(45);
+    /// let window = TimeDelta::from_minutes(1);
+    /// assert_eq!(busy.div_f64(window), 0.75);
+    /// assert_eq!(busy / window, 0);
+    /// ```
+    #[inline]
+    pub fn div_f64(self, other: TimeDelta) -> f64 {
+        self.0 as f64 / other.0 as f64
+    }
+
+    /// Euclidean division, rounding towards negative infinity for positive `rhs`.
+    ///
+    /// Together with [`rem_euclid`](Self::rem_euclid), this buckets negative
+    /// deltas consistently with positive ones, unlike the truncating `/` and
+    /// `%` operators.
+    ///
+    /// Examples:
+    ///
+    /// ```
+    /// use utctimestamp::TimeDelta;
+    ///
+    /// let offset = TimeDelta::from_minutes(-1);
+    /// let bucket = TimeDelta::from_minutes(5);
+    /// assert_eq!(offset.div_euclid(bucket), -1);
+    /// assert_eq!(offset.rem_euclid(bucket), TimeDelta::from_minutes(4));
+    /// assert_eq!(offset / bucket, 0);
+    /// ```
+    #[inline]
+    pub const fn div_euclid(self, rhs: TimeDelta) -> i64 {
+        self.0.div_euclid(rhs.0)
+    }
+
+    /// The non-negative remainder of [`div_euclid`](Self::div_euclid).
+    #[inline]
+    pub const fn rem_euclid(self, rhs: TimeDelta) -> TimeDelta {
+        TimeDelta(self.0.rem_euclid(rhs.0))
+    }
 }
 
 // ============================================================================================== //
@@ -300,17 +986,17 @@ impl TimeDelta {
 /// use utctimestamp::TimeRange;
 /// use chrono::{offset::TimeZone, Duration, Utc};
 ///
-/// let start = Utc.ymd(2019, 4, 14).and_hms(0, 0, 0);
-/// let end = Utc.ymd(2019, 4, 16).and_hms(0, 0, 0);
+/// let start = Utc.with_ymd_and_hms(2019, 4, 14, 0, 0, 0).unwrap();
+/// let end = Utc.with_ymd_and_hms(2019, 4, 16, 0, 0, 0).unwrap();
 /// let step = Duration::hours(12);
 /// let tr: Vec<_> = TimeRange::right_closed(start, end, step).collect();
 ///
 /// assert_eq!(tr, vec![
-///     Utc.ymd(2019, 4, 14).and_hms(0, 0, 0).into(),
-///     Utc.ymd(2019, 4, 14).and_hms(12, 0, 0).into(),
-///     Utc.ymd(2019, 4, 15).and_hms(0, 0, 0).into(),
-///     Utc.ymd(2019, 4, 15).and_hms(12, 0, 0).into(),
-///     Utc.ymd(2019, 4, 16).and_hms(0, 0, 0).into(),
+///     Utc.with_ymd_and_hms(2019, 4, 14, 0, 0, 0).unwrap(),
+///     Utc.with_ymd_and_hms(2019, 4, 14, 12, 0, 0).unwrap(),
+///     Utc.with_ymd_and_hms(2019, 4, 15, 0, 0, 0).unwrap(),
+///     Utc.with_ymd_and_hms(2019, 4, 15, 12, 0, 0).unwrap(),
+///     Utc.with_ymd_and_hms(2019, 4, 16, 0, 0, 0).unwrap(),
 /// ]);
 /// ```
 #[derive(Debug)]
@@ -371,6 +1057,53 @@ impl Iterator for TimeRange {
     }
 }
 
+// ============================================================================================== //
+// [TimeInterval]                                                                                 //
+// ============================================================================================== //
+
+/// A right open interval of time, `[start, end)`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
+pub struct TimeInterval {
+    pub start: UtcTimeStamp,
+    pub end: UtcTimeStamp,
+}
+
+impl TimeInterval {
+    #[inline]
+    pub const fn new(start: UtcTimeStamp, end: UtcTimeStamp) -> Self {
+        TimeInterval { start, end }
+    }
+
+    /// Length of the interval.
+    #[inline]
+    pub const fn duration(self) -> TimeDelta {
+        TimeDelta(self.end.0 - self.start.0)
+    }
+
+    /// Check whether the interval contains no point in time.
+    #[inline]
+    pub const fn is_empty(self) -> bool {
+        self.end.0 <= self.start.0
+    }
+
+    /// Check whether the timestamp lies within the interval.
+    #[inline]
+    pub const fn contains(self, ts: UtcTimeStamp) -> bool {
+        self.start.0 <= ts.0 && ts.0 < self.end.0
+    }
+}
+
+impl ops::RangeBounds<UtcTimeStamp> for TimeInterval {
+    fn start_bound(&self) -> ops::Bound<&UtcTimeStamp> {
+        ops::Bound::Included(&self.start)
+    }
+
+    fn end_bound(&self) -> ops::Bound<&UtcTimeStamp> {
+        ops::Bound::Excluded(&self.end)
+    }
+}
+
 // ============================================================================================== //
 // [Tests]                                                                                        //
 // ============================================================================================== //
@@ -382,31 +1115,94 @@ mod tests {
 
     #[test]
     fn open_time_range() {
-        let start = Utc.ymd(2019, 4, 14).and_hms(0, 0, 0);
-        let end = Utc.ymd(2019, 4, 16).and_hms(0, 0, 0);
+        let start = Utc.with_ymd_and_hms(2019, 4, 14, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2019, 4, 16, 0, 0, 0).unwrap();
         let step = Duration::hours(12);
-        let tr: Vec<_> = Iterator::collect(TimeRange::right_closed(start, end, step));
+        let tr: Vec<UtcTimeStamp> = Iterator::collect(TimeRange::right_closed(start, end, step));
         assert_eq!(tr, vec![
-            Utc.ymd(2019, 4, 14).and_hms(0, 0, 0).into(),
-            Utc.ymd(2019, 4, 14).and_hms(12, 0, 0).into(),
-            Utc.ymd(2019, 4, 15).and_hms(0, 0, 0).into(),
-            Utc.ymd(2019, 4, 15).and_hms(12, 0, 0).into(),
-            Utc.ymd(2019, 4, 16).and_hms(0, 0, 0).into(),
+            Utc.with_ymd_and_hms(2019, 4, 14, 0, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2019, 4, 14, 12, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2019, 4, 15, 0, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2019, 4, 15, 12, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2019, 4, 16, 0, 0, 0).unwrap(),
         ]);
     }
 
     #[test]
     fn timestamp_and_delta_vs_chrono() {
-        let c_dt = Utc.ymd(2019, 3, 13).and_hms(16, 14, 9);
+        let c_dt = Utc.with_ymd_and_hms(2019, 3, 13, 16, 14, 9).unwrap();
         let c_td = Duration::milliseconds(123456);
 
-        let my_dt = UtcTimeStamp::from(c_dt.clone());
+        let my_dt = UtcTimeStamp::from(c_dt);
         let my_td = TimeDelta::from_milliseconds(123456);
-        assert_eq!(TimeDelta::from(c_td.clone()), my_td);
+        assert_eq!(TimeDelta::from(c_td), my_td);
 
         let c_result = c_dt + c_td * 555;
         let my_result = my_dt + my_td * 555;
-        assert_eq!(UtcTimeStamp::from(c_result.clone()), my_result);
+        assert_eq!(UtcTimeStamp::from(c_result), my_result);
+    }
+
+    #[test]
+    fn chrono_pre_epoch() {
+        let pre_epoch = UtcTimeStamp::from_milliseconds(-1500);
+        let c_pre_epoch = chrono::DateTime::<Utc>::from(pre_epoch);
+        assert_eq!(c_pre_epoch.timestamp_millis(), -1500);
+        assert_eq!(UtcTimeStamp::from(c_pre_epoch), pre_epoch);
+    }
+
+    #[test]
+    fn chrono_duration_range() {
+        let (c_max, c_min) = (
+            Duration::milliseconds(i64::MAX),
+            Duration::milliseconds(-i64::MAX),
+        );
+        assert_eq!(TimeDelta::from(c_max).as_milliseconds(), i64::MAX);
+        assert_eq!(TimeDelta::from(c_min).as_milliseconds(), -i64::MAX);
+        assert_eq!(TimeDelta::from(c_min).to_chrono(), Ok(c_min));
+        assert_eq!(
+            TimeDelta::from(Duration::microseconds(-1999)).as_milliseconds(),
+            -1
+        );
+    }
+
+    #[test]
+    fn chrono_duration_arithmetic() {
+        let c_dt = Utc.with_ymd_and_hms(2019, 3, 13, 16, 14, 9).unwrap();
+        let my_dt = UtcTimeStamp::from(c_dt);
+        let c_td = Duration::milliseconds(123456);
+
+        assert_eq!(UtcTimeStamp::from(c_dt + c_td), my_dt + c_td);
+        assert_eq!(UtcTimeStamp::from(c_dt - c_td), my_dt - c_td);
+        assert_eq!(
+            my_dt + Duration::microseconds(1999),
+            my_dt + TimeDelta::from_milliseconds(1)
+        );
+    }
+
+    #[test]
+    fn from_zoned_chrono() {
+        let c_dt = Utc.with_ymd_and_hms(2019, 3, 13, 16, 14, 9).unwrap();
+        let my_dt = UtcTimeStamp::from(c_dt);
+        let c_local = c_dt.with_timezone(&chrono::FixedOffset::west_opt(5 * 3600).unwrap());
+        assert_eq!(UtcTimeStamp::from(c_local), my_dt);
+    }
+
+    #[test]
+    fn naive_utc() {
+        let c_dt = Utc.with_ymd_and_hms(2019, 3, 13, 16, 14, 9).unwrap();
+        let my_dt = UtcTimeStamp::from(c_dt);
+        assert_eq!(UtcTimeStamp::from_naive_utc(c_dt.naive_utc()), my_dt);
+        assert_eq!(my_dt.to_naive_utc(), c_dt.naive_utc());
+    }
+
+    #[test]
+    fn to_datetime_zoned() {
+        let c_dt = Utc.with_ymd_and_hms(2019, 3, 13, 16, 14, 9).unwrap();
+        let my_dt = UtcTimeStamp::from(c_dt);
+        assert_eq!(
+            my_dt.to_datetime(chrono::Local),
+            c_dt.with_timezone(&chrono::Local)
+        );
     }
 
     #[test]
@@ -425,38 +1221,280 @@ mod tests {
         assert_ne!(ts1, ts3);
     }
 
+    #[test]
+    fn chrono_precision() {
+        let dt = Utc.with_ymd_and_hms(1969, 12, 31, 23, 59, 59).unwrap();
+        let ms = |x: UtcTimeStamp| x.as_milliseconds();
+        let rounded = |dt, mode| ms(UtcTimeStamp::from_datetime_rounded(dt, mode));
+
+        assert_eq!(UtcTimeStamp::from_datetime_exact(dt), Ok(dt.into()));
+        for &(nanos, floor, ceil, nearest) in &[
+            (0, -1000, -1000, -1000),
+            (1, -1000, -999, -1000),
+            (499_999, -1000, -999, -1000),
+            (500_000, -1000, -999, -999),
+            (1_999_999, -999, -998, -998),
+        ] {
+            let dt = dt + Duration::nanoseconds(nanos);
+            assert_eq!(rounded(dt, Rounding::Floor), floor);
+            assert_eq!(rounded(dt, Rounding::Ceil), ceil);
+            assert_eq!(rounded(dt, Rounding::Nearest), nearest);
+            assert_eq!(ms(dt.into()), floor);
+            assert_eq!(
+                UtcTimeStamp::from_datetime_exact(dt).is_ok(),
+                nanos % 1_000_000 == 0
+            );
+        }
+    }
+
+    #[test]
+    fn sub_millisecond_units() {
+        let from_us = |x, mode| UtcTimeStamp::from_microseconds(x, mode).as_milliseconds();
+        let from_ns = |x, mode| UtcTimeStamp::from_nanoseconds(x, mode).as_milliseconds();
+
+        for &(us, floor, ceil, nearest) in &[
+            (0, 0, 0, 0),
+            (1, 0, 1, 0),
+            (500, 0, 1, 1),
+            (-500, -1, 0, 0),
+            (-501, -1, 0, -1),
+            (2000, 2, 2, 2),
+            (
+                i64::MIN,
+                i64::MIN / 1000 - 1,
+                i64::MIN / 1000,
+                i64::MIN / 1000 - 1,
+            ),
+            (
+                i64::MAX,
+                i64::MAX / 1000,
+                i64::MAX / 1000 + 1,
+                i64::MAX / 1000 + 1,
+            ),
+        ] {
+            assert_eq!(from_us(us, Rounding::Floor), floor);
+            assert_eq!(from_us(us, Rounding::Ceil), ceil);
+            assert_eq!(from_us(us, Rounding::Nearest), nearest);
+        }
+
+        assert_eq!(from_ns(1_499_999, Rounding::Nearest), 1);
+        assert_eq!(from_ns(-1, Rounding::Floor), -1);
+        assert_eq!(from_ns(-1, Rounding::Ceil), 0);
+        assert_eq!(from_ns(i64::MIN, Rounding::Floor), i64::MIN / 1_000_000 - 1);
+    }
+
+    #[test]
+    fn float_epochs() {
+        let limit = 1i64 << 51;
+        let mut ms = -limit;
+        while ms <= limit {
+            for &ms in &[ms, ms + 1, ms + 999] {
+                let ts = UtcTimeStamp::from_milliseconds(ms);
+                assert_eq!(
+                    UtcTimeStamp::from_seconds_f64(ts.as_seconds_f64()),
+                    Some(ts)
+                );
+                assert_eq!(
+                    UtcTimeStamp::from_milliseconds_f64(ts.as_milliseconds_f64()),
+                    Some(ts)
+                );
+            }
+            ms += limit / 997;
+        }
+
+        assert_eq!(
+            UtcTimeStamp::from_milliseconds_f64(-0.5),
+            Some(UtcTimeStamp::from_milliseconds(-1))
+        );
+        assert_eq!(UtcTimeStamp::from_milliseconds_f64(f64::INFINITY), None);
+        assert_eq!(UtcTimeStamp::from_milliseconds_f64(2f64.powi(63)), None);
+        assert_eq!(UtcTimeStamp::from_seconds_f64(-1e17), None);
+    }
+
+    #[test]
+    fn seconds_split() {
+        for &(ms, secs, frac) in &[
+            (0, 0, 0),
+            (999, 0, 999),
+            (1000, 1, 0),
+            (-1, -1, 999),
+            (-1000, -1, 0),
+            (-1001, -2, 999),
+            (i64::MIN, i64::MIN / 1000 - 1, 192),
+            (i64::MAX, i64::MAX / 1000, 807),
+        ] {
+            let ts = UtcTimeStamp::from_milliseconds(ms);
+            assert_eq!(ts.split_seconds(), (secs, frac));
+            assert_eq!(ts.as_seconds(), secs);
+            assert_eq!(ts.subsec_milliseconds(), frac);
+        }
+    }
+
+    #[test]
+    fn cmp_with_chrono() {
+        let c_dt = Utc.with_ymd_and_hms(2019, 3, 13, 16, 14, 9).unwrap();
+        let my_dt = UtcTimeStamp::from(c_dt);
+        let sub_ms = c_dt + Duration::nanoseconds(1);
+
+        assert_eq!(my_dt, c_dt);
+        assert_eq!(c_dt, my_dt);
+        assert_ne!(my_dt, sub_ms);
+        assert!(my_dt < sub_ms);
+        assert!(sub_ms > my_dt);
+        assert!(my_dt + TimeDelta::from_milliseconds(1) > sub_ms);
+        assert!(c_dt - Duration::nanoseconds(1) < my_dt);
+        assert!(my_dt >= c_dt);
+        assert!(c_dt <= my_dt);
+    }
+
     #[test]
     fn align_to_anchored() {
-        let day = Utc.ymd(2020, 9, 28);
-        let ts: UtcTimeStamp = day.and_hms(19, 32, 51).into();
+        let day = |h, m, s| Utc.with_ymd_and_hms(2020, 9, 28, h, m, s).unwrap();
+        let ts: UtcTimeStamp = day(19, 32, 51).into();
 
         assert_eq!(
-            ts.align_to_anchored(day.and_hms(0, 0, 0).into(), TimeDelta::from_seconds(60 * 5)),
-            day.and_hms(19, 30, 0).into(),
+            ts.align_to_anchored(day(0, 0, 0).into(), TimeDelta::from_seconds(60 * 5)),
+            UtcTimeStamp::from(day(19, 30, 0)),
         );
 
         assert_eq!(
             ts.align_to_anchored(
-                day.and_hms(9 /* irrelevant */, 1, 3).into(),
+                day(9 /* irrelevant */, 1, 3).into(),
                 TimeDelta::from_seconds(60 * 5)
             ),
-            day.and_hms(19, 31, 3).into(),
+            UtcTimeStamp::from(day(19, 31, 3)),
         );
     }
 
     #[test]
     fn align_to_anchored_eq() {
-        let day = Utc.ymd(2020, 1, 1);
-        let anchor: UtcTimeStamp = day.and_hms(0, 0, 0).into();
+        let day = |h, m, s| Utc.with_ymd_and_hms(2020, 1, 1, h, m, s).unwrap();
+        let anchor: UtcTimeStamp = day(0, 0, 0).into();
         let freq = TimeDelta::from_seconds(5 * 60);
 
-        let ts1: UtcTimeStamp = day.and_hms(12, 1, 11).into();
-        let ts2: UtcTimeStamp = day.and_hms(12, 4, 11).into();
+        let ts1: UtcTimeStamp = day(12, 1, 11).into();
+        let ts2: UtcTimeStamp = day(12, 4, 11).into();
         assert_eq!(
             ts1.align_to_anchored(anchor, freq),
             ts2.align_to_anchored(anchor, freq),
         );
     }
+
+    #[test]
+    fn from_seconds_f64() {
+        let ms = |x| UtcTimeStamp::from_seconds_f64(x).map(UtcTimeStamp::as_milliseconds);
+        assert_eq!(ms(1.0005), Some(1001));
+        assert_eq!(ms(0.123), Some(123));
+        assert_eq!(ms(-0.0014), Some(-1));
+        assert_eq!(ms(-1.5), Some(-1500));
+        assert_eq!(ms(f64::INFINITY), None);
+        assert_eq!(ms(1e17), None);
+    }
+
+    #[test]
+    fn delta_days_weeks() {
+        assert_eq!(TimeDelta::from_weeks(2), TimeDelta::from_days(14));
+        assert_eq!(TimeDelta::from_days(-1), TimeDelta::from_hours(-24));
+        assert_eq!(TimeDelta::from_days(1).as_milliseconds(), 86_400_000);
+    }
+
+    #[test]
+    fn delta_unit_accessors() {
+        let delta = TimeDelta::from_milliseconds(-90_061_001);
+        assert_eq!(delta.as_seconds(), -90_061);
+        assert_eq!(delta.as_minutes(), -1501);
+        assert_eq!(delta.as_hours(), -25);
+        assert_eq!(delta.as_days(), -1);
+        assert_eq!(delta.subsec_milliseconds(), -1);
+        assert_eq!(TimeDelta::from_hours(23).as_days(), 0);
+    }
+
+    #[test]
+    fn delta_f64() {
+        let secs = |x| TimeDelta::from_seconds_f64(x).map(TimeDelta::as_milliseconds);
+        assert_eq!(secs(1.0005), Some(1001));
+        assert_eq!(secs(-0.0005), Some(-1));
+        assert_eq!(secs(f64::NAN), None);
+        assert_eq!(secs(-1e17), None);
+        assert_eq!(TimeDelta::from_milliseconds(-1250).as_seconds_f64(), -1.25);
+    }
+
+    #[test]
+    fn delta_neg() {
+        assert_eq!(-TimeDelta::from_seconds(3), TimeDelta::from_seconds(-3));
+        assert_eq!(-TimeDelta::zero(), TimeDelta::zero());
+    }
+
+    #[test]
+    fn delta_abs_signum() {
+        assert_eq!(
+            TimeDelta::from_seconds(-3).abs(),
+            TimeDelta::from_seconds(3)
+        );
+        assert_eq!(TimeDelta::from_seconds(3).abs(), TimeDelta::from_seconds(3));
+        assert_eq!(TimeDelta::from_seconds(-3).signum(), -1);
+        assert_eq!(TimeDelta::zero().signum(), 0);
+        assert_eq!(TimeDelta::from_milliseconds(1).signum(), 1);
+    }
+
+    #[test]
+    fn delta_mul_div_f64() {
+        let second = TimeDelta::from_seconds(1);
+        assert_eq!(second * 1.5, TimeDelta::from_milliseconds(1500));
+        assert_eq!(second * -0.0005, TimeDelta::from_milliseconds(-1));
+        assert_eq!(second / 3.0, TimeDelta::from_milliseconds(333));
+        assert_eq!(
+            TimeDelta::from_milliseconds(5) / 2.0,
+            TimeDelta::from_milliseconds(3)
+        );
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "attempt to add with overflow")]
+    fn delta_sum_overflow() {
+        let deltas = [
+            TimeDelta::from_milliseconds(i64::MAX),
+            TimeDelta::MILLISECOND,
+        ];
+        let _ = deltas.iter().sum::<TimeDelta>();
+    }
+
+    #[test]
+    fn i64_mul_delta() {
+        assert_eq!(5 * TimeDelta::MINUTE, TimeDelta::from_minutes(5));
+    }
+
+    #[test]
+    fn delta_assign_ops() {
+        let mut delta = TimeDelta::HOUR;
+        delta += TimeDelta::MINUTE;
+        delta -= TimeDelta::SECOND;
+        delta *= 2;
+        delta /= 4;
+        assert_eq!(delta, TimeDelta::from_milliseconds(1_829_500));
+    }
+
+    #[test]
+    fn delta_constants() {
+        assert_eq!(TimeDelta::MINUTE * 5, TimeDelta::from_minutes(5));
+        assert_eq!(TimeDelta::WEEK, TimeDelta::DAY * 7);
+        assert_eq!(TimeDelta::HOUR.as_milliseconds(), 3_600_000);
+        assert_eq!(TimeDelta::SECOND, TimeDelta::MILLISECOND * 1000);
+    }
+
+    #[test]
+    fn time_interval() {
+        let iv = TimeInterval::new(
+            UtcTimeStamp::from_seconds(10),
+            UtcTimeStamp::from_seconds(20),
+        );
+        assert_eq!(iv.duration(), TimeDelta::from_seconds(10));
+        assert!(iv.contains(UtcTimeStamp::from_seconds(10)));
+        assert!(!iv.contains(UtcTimeStamp::from_seconds(20)));
+        assert!(!iv.is_empty());
+        assert!(TimeInterval::new(iv.end, iv.start).is_empty());
+    }
 }
 
 // ============================================================================================== //
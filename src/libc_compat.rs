@@ -0,0 +1,148 @@
+//! Conversions from and to C's `time_t`, `struct timespec` and `struct timeval`.
+
+use crate::{OutOfRangeError, UtcTimeStamp};
+use core::convert::TryFrom;
+
+/// Floor `secs + frac / per_sec` to milliseconds, for fractions in any range.
+///
+/// Generic since the C types differ in width between platforms.
+fn floor_millis(
+    secs: impl Into<i64>,
+    frac: impl Into<i64>,
+    per_sec: i64,
+) -> Result<UtcTimeStamp, OutOfRangeError> {
+    let per_ms = i128::from(per_sec / 1000);
+    let frac = i128::from(frac.into());
+    let ms = i128::from(secs.into()) * 1000 + frac.div_euclid(per_ms);
+    i64::try_from(ms)
+        .map(UtcTimeStamp::from_milliseconds)
+        .map_err(|_| OutOfRangeError)
+}
+
+impl UtcTimeStamp {
+    /// Conversion from C seconds since the epoch.
+    ///
+    /// Fails if the seconds exceed the millisecond range.
+    pub fn from_time_t(secs: libc::time_t) -> Result<Self, OutOfRangeError> {
+        floor_millis(secs, 0, 1000)
+    }
+
+    /// Conversion to C seconds since the epoch, rounding towards the past.
+    ///
+    /// Fails on platforms with a 32 bit `time_t` for timestamps beyond 2038.
+    ///
+    /// Examples:
+    ///
+    /// ```
+    /// use utctimestamp::UtcTimeStamp;
+    ///
+    /// let ts = UtcTimeStamp::from_milliseconds(-1);
+    /// assert_eq!(ts.to_time_t(), Ok(-1));
+    /// assert_eq!(UtcTimeStamp::from_time_t(-1), Ok(UtcTimeStamp::from_seconds(-1)));
+    /// ```
+    #[allow(clippy::useless_conversion)]
+    pub fn to_time_t(self) -> Result<libc::time_t, OutOfRangeError> {
+        libc::time_t::try_from(self.as_seconds()).map_err(|_| OutOfRangeError)
+    }
+}
+
+/// Convert a C `timespec`, rounding towards the past to whole milliseconds.
+///
+/// Nanoseconds outside of `0..1_000_000_000` are carried into the seconds.
+impl TryFrom<libc::timespec> for UtcTimeStamp {
+    type Error = OutOfRangeError;
+
+    fn try_from(other: libc::timespec) -> Result<Self, Self::Error> {
+        floor_millis(other.tv_sec, other.tv_nsec, 1_000_000_000)
+    }
+}
+
+/// Convert to a C `timespec` with nanoseconds in `0..1_000_000_000`.
+///
+/// Fails on platforms with a 32 bit `time_t` for timestamps beyond 2038.
+impl TryFrom<UtcTimeStamp> for libc::timespec {
+    type Error = OutOfRangeError;
+
+    fn try_from(other: UtcTimeStamp) -> Result<Self, Self::Error> {
+        // Some targets have private padding fields, preventing a struct literal.
+        let mut out: libc::timespec = unsafe { core::mem::zeroed() };
+        out.tv_sec = other.to_time_t()?;
+        out.tv_nsec = (other.subsec_milliseconds() * 1_000_000) as _;
+        Ok(out)
+    }
+}
+
+/// Convert a C `timeval`, rounding towards the past to whole milliseconds.
+///
+/// Microseconds outside of `0..1_000_000` are carried into the seconds.
+impl TryFrom<libc::timeval> for UtcTimeStamp {
+    type Error = OutOfRangeError;
+
+    fn try_from(other: libc::timeval) -> Result<Self, Self::Error> {
+        floor_millis(other.tv_sec, other.tv_usec, 1_000_000)
+    }
+}
+
+/// Convert to a C `timeval` with microseconds in `0..1_000_000`.
+///
+/// Fails on platforms with a 32 bit `time_t` for timestamps beyond 2038.
+impl TryFrom<UtcTimeStamp> for libc::timeval {
+    type Error = OutOfRangeError;
+
+    fn try_from(other: UtcTimeStamp) -> Result<Self, Self::Error> {
+        let mut out: libc::timeval = unsafe { core::mem::zeroed() };
+        out.tv_sec = other.to_time_t()?;
+        out.tv_usec = (other.subsec_milliseconds() * 1000) as _;
+        Ok(out)
+    }
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use core::convert::TryFrom;
+
+    #[test]
+    fn c_types() {
+        let ts = UtcTimeStamp::from_milliseconds(-1_250);
+        assert_eq!(ts.to_time_t(), Ok(-2));
+        assert_eq!(
+            UtcTimeStamp::from_time_t(1_600_000_000),
+            Ok(UtcTimeStamp::from_seconds(1_600_000_000))
+        );
+
+        let spec = libc::timespec::try_from(ts).unwrap();
+        assert_eq!((spec.tv_sec, spec.tv_nsec), (-2, 750_000_000));
+        assert_eq!(UtcTimeStamp::try_from(spec), Ok(ts));
+
+        let val = libc::timeval::try_from(ts).unwrap();
+        assert_eq!((val.tv_sec, val.tv_usec), (-2, 750_000));
+        assert_eq!(UtcTimeStamp::try_from(val), Ok(ts));
+
+        let mut spec = spec;
+        spec.tv_nsec = 1_000_999_999;
+        assert_eq!(
+            UtcTimeStamp::try_from(spec),
+            Ok(UtcTimeStamp::from_milliseconds(-1_000))
+        );
+        spec.tv_nsec = -1;
+        assert_eq!(
+            UtcTimeStamp::try_from(spec),
+            Ok(UtcTimeStamp::from_milliseconds(-2_001))
+        );
+
+        if core::mem::size_of::<libc::time_t>() == 8 {
+            assert!(UtcTimeStamp::from_time_t(i64::MAX as _).is_err());
+            assert_eq!(
+                UtcTimeStamp::from_milliseconds(i64::MIN).to_time_t(),
+                Ok((i64::MIN / 1000 - 1) as _)
+            );
+        } else {
+            assert!(UtcTimeStamp::from_seconds(1 << 31).to_time_t().is_err());
+        }
+    }
+}
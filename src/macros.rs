@@ -0,0 +1,90 @@
+//! Literal macros validated at compile time.
+
+/// Create a [`UtcTimeStamp`] from a string literal, validated at compile time.
+///
+/// Accepts the layouts of [`UtcTimeStamp::parse_fixed`], i.e.
+/// `YYYY-MM-DDTHH:MM:SSZ` with optional milliseconds. Invalid literals fail to
+/// compile.
+///
+/// [`UtcTimeStamp`]: crate::UtcTimeStamp
+/// [`UtcTimeStamp::parse_fixed`]: crate::UtcTimeStamp::parse_fixed
+///
+/// Examples:
+///
+/// ```
+/// use utctimestamp::{ts, UtcTimeStamp};
+///
+/// const MARKET_OPEN: UtcTimeStamp = ts!("2024-01-15T09:30:00Z");
+/// assert_eq!(MARKET_OPEN.as_milliseconds(), 1_705_311_000_000);
+/// assert_eq!(ts!("1970-01-01T00:00:00.250Z").as_milliseconds(), 250);
+/// ```
+///
+/// ```compile_fail
+/// let ts = utctimestamp::ts!("2024-02-30T00:00:00Z");
+/// ```
+#[macro_export]
+macro_rules! ts {
+    ($s:literal) => {{
+        const TS: $crate::UtcTimeStamp = match $crate::__private::parse_fixed_const($s.as_bytes()) {
+            ::core::option::Option::Some(x) => x,
+            ::core::option::Option::None => panic!("invalid timestamp literal"),
+        };
+        TS
+    }};
+}
+
+/// Create a [`TimeDelta`] from a compact string literal, validated at compile time.
+///
+/// Accepts one or more `<integer><unit>` components with the units `w`, `d`,
+/// `h`, `m`, `s` and `ms`, optionally separated by spaces and preceded by a
+/// sign. Invalid literals fail to compile.
+///
+/// [`TimeDelta`]: crate::TimeDelta
+///
+/// Examples:
+///
+/// ```
+/// use utctimestamp::{td, TimeDelta};
+///
+/// const INTERVAL: TimeDelta = td!("15m");
+/// assert_eq!(INTERVAL, TimeDelta::from_minutes(15));
+/// assert_eq!(td!("1h 30m"), TimeDelta::from_minutes(90));
+/// assert_eq!(td!("-250ms"), TimeDelta::from_milliseconds(-250));
+/// ```
+///
+/// ```compile_fail
+/// let delta = utctimestamp::td!("15 minutes");
+/// ```
+#[macro_export]
+macro_rules! td {
+    ($s:literal) => {{
+        const TD: $crate::TimeDelta = match $crate::__private::parse_delta_const($s.as_bytes()) {
+            ::core::option::Option::Some(x) => x,
+            ::core::option::Option::None => panic!("invalid delta literal"),
+        };
+        TD
+    }};
+}
+
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::parse::{parse_delta_const, parse_fixed_const};
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn literals() {
+        assert_eq!(
+            ts!("2000-02-29 12:00:00Z"),
+            "2000-02-29T12:00:00Z".parse::<UtcTimeStamp>().unwrap()
+        );
+        assert_eq!(td!("1d"), TimeDelta::from_hours(24));
+    }
+}
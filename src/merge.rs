@@ -0,0 +1,163 @@
+//! Merging of multiple sorted timestamp streams.
+
+use crate::UtcTimeStamp;
+use core::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+/// Items carrying a timestamp, either plain timestamps or `(timestamp, value)` pairs.
+pub trait Timestamped {
+    fn timestamp(&self) -> UtcTimeStamp;
+}
+
+impl Timestamped for UtcTimeStamp {
+    #[inline]
+    fn timestamp(&self) -> UtcTimeStamp {
+        *self
+    }
+}
+
+impl<T> Timestamped for (UtcTimeStamp, T) {
+    #[inline]
+    fn timestamp(&self) -> UtcTimeStamp {
+        self.0
+    }
+}
+
+// ============================================================================================== //
+// [K-way merge]                                                                                  //
+// ============================================================================================== //
+
+/// Merge any number of individually sorted streams into one sorted stream.
+///
+/// Works with streams of plain timestamps as well as `(timestamp, value)`
+/// pairs. Items with equal timestamps are yielded in the order of the streams
+/// they originate from, making the merge stable.
+///
+/// Examples:
+///
+/// ```
+/// use utctimestamp::{merge_sorted, UtcTimeStamp};
+///
+/// let ts = UtcTimeStamp::from_milliseconds;
+/// let feed_a = vec![(ts(1), "a1"), (ts(5), "a5")];
+/// let feed_b = vec![(ts(2), "b2"), (ts(5), "b5")];
+/// let merged: Vec<_> = merge_sorted(vec![feed_a, feed_b]).map(|x| x.1).collect();
+///
+/// assert_eq!(merged, vec!["a1", "b2", "a5", "b5"]);
+/// ```
+pub fn merge_sorted<S>(streams: impl IntoIterator<Item = S>) -> MergeSorted<S::IntoIter>
+where
+    S: IntoIterator,
+    S::Item: Timestamped,
+{
+    let mut streams: Vec<_> = streams.into_iter().map(IntoIterator::into_iter).collect();
+    let heap = streams
+        .iter_mut()
+        .enumerate()
+        .filter_map(|(stream, iter)| {
+            Some(HeapEntry {
+                item: iter.next()?,
+                stream,
+            })
+        })
+        .collect();
+
+    MergeSorted { streams, heap }
+}
+
+/// Iterator merging sorted streams, see [`merge_sorted`].
+pub struct MergeSorted<I: Iterator> {
+    streams: Vec<I>,
+    heap: BinaryHeap<HeapEntry<I::Item>>,
+}
+
+impl<I> Iterator for MergeSorted<I>
+where
+    I: Iterator,
+    I::Item: Timestamped,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let HeapEntry { item, stream } = self.heap.pop()?;
+        if let Some(next) = self.streams[stream].next() {
+            self.heap.push(HeapEntry { item: next, stream });
+        }
+
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.streams
+            .iter()
+            .fold((self.heap.len(), Some(self.heap.len())), |(lo, hi), x| {
+                let (x_lo, x_hi) = x.size_hint();
+                (
+                    lo.saturating_add(x_lo),
+                    hi.and_then(|hi| hi.checked_add(x_hi?)),
+                )
+            })
+    }
+}
+
+/// Head of a stream, ordered such that the max-heap pops the earliest item.
+struct HeapEntry<T> {
+    item: T,
+    stream: usize,
+}
+
+impl<T: Timestamped> HeapEntry<T> {
+    fn key(&self) -> (UtcTimeStamp, usize) {
+        (self.item.timestamp(), self.stream)
+    }
+}
+
+impl<T: Timestamped> PartialEq for HeapEntry<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.key() == other.key()
+    }
+}
+
+impl<T: Timestamped> Eq for HeapEntry<T> {}
+
+impl<T: Timestamped> PartialOrd for HeapEntry<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Timestamped> Ord for HeapEntry<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.key().cmp(&self.key())
+    }
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn merge() {
+        let ts =
+            |s: &[i64]| -> Vec<_> { s.iter().map(|&s| UtcTimeStamp::from_seconds(s)).collect() };
+
+        let merged: Vec<_> =
+            merge_sorted(vec![ts(&[1, 4, 9]), ts(&[]), ts(&[2, 3, 10]), ts(&[4])]).collect();
+        assert_eq!(merged, ts(&[1, 2, 3, 4, 4, 9, 10]));
+
+        let merged = merge_sorted(vec![ts(&[1, 2]), ts(&[0])]);
+        assert_eq!(merged.size_hint(), (3, Some(3)));
+
+        let none: Vec<Vec<UtcTimeStamp>> = vec![];
+        assert_eq!(merge_sorted(none).next(), None);
+
+        let a: Vec<_> = ts(&[1, 2, 2]).into_iter().map(|x| (x, 'a')).collect();
+        let b: Vec<_> = ts(&[2, 3]).into_iter().map(|x| (x, 'b')).collect();
+        let merged: String = merge_sorted(vec![b, a]).map(|x| x.1).collect();
+        assert_eq!(merged, "abaab");
+    }
+}
@@ -0,0 +1,211 @@
+//! Natural-language date parsing.
+
+use crate::{
+    calendar::{split_day, weekday_from_days, MS_PER_DAY},
+    relative::{add, Unit},
+    ParseError, TimeOfDay, UtcTimeStamp,
+};
+use chrono::Weekday;
+
+fn unit_from_word(word: &str) -> Option<Unit> {
+    Some(match word.strip_suffix('s').unwrap_or(word) {
+        "sec" | "second" => Unit::Second,
+        "min" | "minute" => Unit::Minute,
+        "hour" => Unit::Hour,
+        "day" => Unit::Day,
+        "week" => Unit::Week,
+        "month" => Unit::Month,
+        "year" => Unit::Year,
+        _ => return None,
+    })
+}
+
+fn amount_from_word(word: &str) -> Option<i64> {
+    match word {
+        "a" | "an" => Some(1),
+        _ => word.parse().ok().filter(|x: &i64| *x >= 0),
+    }
+}
+
+/// Parse `14:00`, `14:00:30`, `2pm`, `2:30 pm`, `noon` and `midnight`.
+fn time_from_words(words: &[&str]) -> Option<TimeOfDay> {
+    let joined = words.concat();
+    let (clock, pm) = match (joined.strip_suffix("am"), joined.strip_suffix("pm")) {
+        (Some(clock), _) => (clock, Some(false)),
+        (_, Some(clock)) => (clock, Some(true)),
+        _ => match joined.as_str() {
+            "noon" => return TimeOfDay::from_hms(12, 0, 0),
+            "midnight" => return Some(TimeOfDay::MIDNIGHT),
+            clock => (clock, None),
+        },
+    };
+
+    let mut parts = clock.split(':').map(|x| x.parse::<u32>().ok());
+    let hour = parts.next()??;
+    let min = parts.next().unwrap_or(Some(0))?;
+    let sec = parts.next().unwrap_or(Some(0))?;
+    if parts.next().is_some() || (pm.is_none() && !clock.contains(':')) {
+        return None;
+    }
+
+    let hour = match pm {
+        Some(_) if hour == 0 || hour > 12 => return None,
+        Some(false) => hour % 12,
+        Some(true) => hour % 12 + 12,
+        None => hour,
+    };
+    TimeOfDay::from_hms(hour, min, sec)
+}
+
+/// Days from `today` to the requested weekday.
+fn weekday_offset(today: i64, weekday: Weekday, direction: i64) -> i64 {
+    let cur = weekday_from_days(today).num_days_from_monday() as i64;
+    let ahead = (weekday.num_days_from_monday() as i64 - cur).rem_euclid(7);
+    match direction {
+        1 if ahead == 0 => 7,
+        1 => ahead,
+        -1 if ahead == 0 => -7,
+        -1 => ahead - 7,
+        _ => ahead,
+    }
+}
+
+impl UtcTimeStamp {
+    /// Parse a natural-language date such as `yesterday 14:00` or `2 hours ago`.
+    ///
+    /// Relative phrases are resolved against `now`. Parsing is case-insensitive
+    /// and accepts:
+    ///
+    /// - `now`
+    /// - `<n> <unit> ago` and `in <n> <unit>`, with `n` a number, `a` or `an`
+    ///   and units from seconds to years
+    /// - a day followed by an optional time of day, optionally separated by
+    ///   `at`. Days are `today`, `yesterday`, `tomorrow` and weekdays, which
+    ///   refer to the current or upcoming one unless preceded by `next` or
+    ///   `last`. Times are `14:00[:30]`, `2pm`, `2:30 pm`, `noon` and
+    ///   `midnight` and default to midnight.
+    /// - a time of day on its own, referring to today
+    ///
+    /// All dates and times are UTC.
+    ///
+    /// Examples:
+    ///
+    /// ```
+    /// use utctimestamp::{ts, UtcTimeStamp};
+    ///
+    /// let now = ts!("2024-03-13T15:20:00Z"); // a wednesday
+    /// let parse = |s| UtcTimeStamp::parse_natural(s, now).unwrap();
+    ///
+    /// assert_eq!(parse("yesterday 14:00"), ts!("2024-03-12T14:00:00Z"));
+    /// assert_eq!(parse("next monday"), ts!("2024-03-18T00:00:00Z"));
+    /// assert_eq!(parse("2 hours ago"), ts!("2024-03-13T13:20:00Z"));
+    /// assert_eq!(parse("Friday at 9am"), ts!("2024-03-15T09:00:00Z"));
+    /// ```
+    pub fn parse_natural(s: &str, now: UtcTimeStamp) -> Result<Self, ParseError> {
+        let lower = s.to_lowercase();
+        let words: Vec<_> = lower.split_whitespace().collect();
+
+        match words.as_slice() {
+            ["now"] => return Ok(now),
+            [amount, unit, "ago"] | ["in", amount, unit] => {
+                let amount = amount_from_word(amount).ok_or(ParseError::Invalid)?;
+                let unit = unit_from_word(unit).ok_or(ParseError::Invalid)?;
+                let amount = if words[0] == "in" { amount } else { -amount };
+                return add(now, amount, unit).ok_or(ParseError::OutOfRange);
+            }
+            _ => {}
+        }
+
+        let (today, _) = split_day(now);
+        let (offset, rest) = match words.as_slice() {
+            ["today", rest @ ..] => (0, rest),
+            ["yesterday", rest @ ..] => (-1, rest),
+            ["tomorrow", rest @ ..] => (1, rest),
+            [modifier @ ("next" | "last"), weekday, rest @ ..] => {
+                let weekday: Weekday = weekday.parse().map_err(|_| ParseError::Invalid)?;
+                let direction = if *modifier == "next" { 1 } else { -1 };
+                (weekday_offset(today, weekday, direction), rest)
+            }
+            [first, rest @ ..] => match first.parse::<Weekday>() {
+                Ok(weekday) => (weekday_offset(today, weekday, 0), rest),
+                Err(_) => (0, &words[..]),
+            },
+            [] => return Err(ParseError::Invalid),
+        };
+
+        let rest = match rest {
+            ["at", rest @ ..] if !rest.is_empty() => rest,
+            rest => rest,
+        };
+        let time = if rest.is_empty() {
+            TimeOfDay::MIDNIGHT
+        } else {
+            time_from_words(rest).ok_or(ParseError::Invalid)?
+        };
+
+        today
+            .checked_add(offset)
+            .and_then(|day| day.checked_mul(MS_PER_DAY))
+            .and_then(|ms| ms.checked_add(time.as_milliseconds() as i64))
+            .map(UtcTimeStamp::from_milliseconds)
+            .ok_or(ParseError::OutOfRange)
+    }
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn natural() {
+        // A sunday.
+        let now = ts!("2023-01-01T10:30:00Z");
+        let parse = |s| UtcTimeStamp::parse_natural(s, now);
+
+        assert_eq!(parse("Now"), Ok(now));
+        assert_eq!(parse("today"), Ok(ts!("2023-01-01T00:00:00Z")));
+        assert_eq!(parse("tomorrow noon"), Ok(ts!("2023-01-02T12:00:00Z")));
+        assert_eq!(
+            parse("yesterday at 11:59:30pm"),
+            Ok(ts!("2022-12-31T23:59:30Z"))
+        );
+        assert_eq!(parse("12am"), Ok(ts!("2023-01-01T00:00:00Z")));
+        assert_eq!(parse("12 pm"), Ok(ts!("2023-01-01T12:00:00Z")));
+        assert_eq!(parse("sunday"), Ok(ts!("2023-01-01T00:00:00Z")));
+        assert_eq!(parse("next sunday"), Ok(ts!("2023-01-08T00:00:00Z")));
+        assert_eq!(parse("last sunday 8:00"), Ok(ts!("2022-12-25T08:00:00Z")));
+        assert_eq!(parse("last tue"), Ok(ts!("2022-12-27T00:00:00Z")));
+        assert_eq!(parse("Saturday midnight"), Ok(ts!("2023-01-07T00:00:00Z")));
+        assert_eq!(parse("an hour ago"), Ok(ts!("2023-01-01T09:30:00Z")));
+        assert_eq!(parse("in 3 days"), Ok(ts!("2023-01-04T10:30:00Z")));
+        assert_eq!(parse("1 month ago"), Ok(ts!("2022-12-01T10:30:00Z")));
+
+        assert_eq!(parse(""), Err(ParseError::Invalid));
+        assert_eq!(parse("someday"), Err(ParseError::Invalid));
+        assert_eq!(parse("13pm"), Err(ParseError::Invalid));
+        assert_eq!(parse("today 25:00"), Err(ParseError::Invalid));
+        assert_eq!(parse("today 14"), Err(ParseError::Invalid));
+        assert_eq!(parse("in -3 days"), Err(ParseError::Invalid));
+        assert_eq!(parse("3 fortnights ago"), Err(ParseError::Invalid));
+        assert_eq!(parse("next"), Err(ParseError::Invalid));
+
+        let max = UtcTimeStamp::from_milliseconds(i64::MAX);
+        for s in &["tomorrow", "next monday", "today 8am"] {
+            assert_eq!(
+                UtcTimeStamp::parse_natural(s, max),
+                Err(ParseError::OutOfRange)
+            );
+        }
+        let min = UtcTimeStamp::from_milliseconds(i64::MIN);
+        for s in &["yesterday", "last monday", "today"] {
+            assert_eq!(
+                UtcTimeStamp::parse_natural(s, min),
+                Err(ParseError::OutOfRange)
+            );
+        }
+    }
+}
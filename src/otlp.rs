@@ -0,0 +1,109 @@
+//! Helpers for OpenTelemetry's `fixed64` nanosecond timestamps.
+//!
+//! OTLP spans, logs and metric data points carry times as unsigned
+//! nanoseconds since the epoch, such as `start_time_unix_nano`. These can't
+//! represent times before 1970 or after 2554, so conversions towards OTLP
+//! either fail or saturate.
+//!
+//! Examples:
+//!
+//! ```
+//! use utctimestamp::{otlp, TimeDelta, UtcTimeStamp};
+//!
+//! let start = 1_600_000_000_250_999_999;
+//! let end = 1_600_000_001_000_000_000;
+//! assert_eq!(otlp::from_unix_nanos(start), UtcTimeStamp::from_milliseconds(1_600_000_000_250));
+//! assert_eq!(otlp::span_duration(start, end), TimeDelta::from_milliseconds(749));
+//! ```
+
+use crate::{OutOfRangeError, TimeDelta, UtcTimeStamp};
+use core::convert::TryFrom;
+
+const NANOS_PER_MS: u64 = 1_000_000;
+
+/// Convert OTLP nanoseconds, rounding towards the past.
+///
+/// Note that OTLP uses `0` for times that weren't set.
+#[inline]
+pub fn from_unix_nanos(nanos: u64) -> UtcTimeStamp {
+    UtcTimeStamp::from_milliseconds((nanos / NANOS_PER_MS) as i64)
+}
+
+/// Convert to OTLP nanoseconds.
+///
+/// Fails for timestamps before 1970 or after `2554-07-21T23:34:33.709Z`.
+pub fn to_unix_nanos(ts: UtcTimeStamp) -> Result<u64, OutOfRangeError> {
+    u64::try_from(ts.as_milliseconds())
+        .ok()
+        .and_then(|ms| ms.checked_mul(NANOS_PER_MS))
+        .ok_or(OutOfRangeError)
+}
+
+/// Convert to OTLP nanoseconds, clamping to the representable range.
+pub fn to_unix_nanos_saturating(ts: UtcTimeStamp) -> u64 {
+    match ts.as_milliseconds() {
+        ms if ms < 0 => 0,
+        ms => (ms as u64).saturating_mul(NANOS_PER_MS),
+    }
+}
+
+/// The duration of a span from its OTLP start and end times.
+///
+/// Sub-millisecond precision is truncated. Spans that end before they
+/// started yield a negative delta.
+pub fn span_duration(start_nanos: u64, end_nanos: u64) -> TimeDelta {
+    let nanos = i128::from(end_nanos) - i128::from(start_nanos);
+    TimeDelta::from_milliseconds((nanos / i128::from(NANOS_PER_MS)) as i64)
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ts;
+
+    #[test]
+    fn unix_nanos() {
+        let max = ts!("2554-07-21T23:34:33.709Z");
+
+        assert_eq!(from_unix_nanos(0), UtcTimeStamp::zero());
+        assert_eq!(
+            from_unix_nanos(1_999_999),
+            UtcTimeStamp::from_milliseconds(1)
+        );
+        assert_eq!(from_unix_nanos(u64::MAX), max);
+
+        assert_eq!(
+            to_unix_nanos(UtcTimeStamp::from_milliseconds(1)),
+            Ok(1_000_000)
+        );
+        assert_eq!(to_unix_nanos(max), Ok(u64::MAX - 551_615));
+        assert_eq!(
+            to_unix_nanos(max + TimeDelta::from_milliseconds(1)),
+            Err(OutOfRangeError)
+        );
+        assert_eq!(
+            to_unix_nanos(UtcTimeStamp::from_milliseconds(-1)),
+            Err(OutOfRangeError)
+        );
+
+        assert_eq!(
+            to_unix_nanos_saturating(UtcTimeStamp::from_milliseconds(-1)),
+            0
+        );
+        assert_eq!(
+            to_unix_nanos_saturating(UtcTimeStamp::from_milliseconds(i64::MAX)),
+            u64::MAX
+        );
+        assert_eq!(to_unix_nanos_saturating(max), u64::MAX - 551_615);
+
+        assert_eq!(span_duration(0, u64::MAX), max - UtcTimeStamp::zero());
+        assert_eq!(
+            span_duration(1_999_999, 0),
+            TimeDelta::from_milliseconds(-1)
+        );
+    }
+}
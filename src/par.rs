@@ -0,0 +1,190 @@
+//! Parallel versions of the bulk slice operations, powered by rayon.
+//!
+//! The functions mirror their sequential counterparts in the crate root and
+//! [`codec`], splitting the input into large chunks that are processed on the
+//! rayon thread pool. They only pay off for inputs of many thousand elements.
+//!
+//! [`codec`]: crate::codec
+
+use crate::{
+    bulk,
+    codec::{self, DecodeError},
+    stats, TimeDelta, UtcTimeStamp,
+};
+use rayon::prelude::*;
+
+/// Number of elements processed per task.
+const CHUNK_LEN: usize = 1 << 16;
+
+type ChronoUtc = chrono::DateTime<chrono::Utc>;
+
+// ============================================================================================== //
+// [Alignment]                                                                                    //
+// ============================================================================================== //
+
+/// Parallel version of [`align_slice_to`](crate::align_slice_to).
+pub fn align_slice_to(timestamps: &mut [UtcTimeStamp], anchor: UtcTimeStamp, freq: TimeDelta) {
+    assert_ne!(
+        freq.as_milliseconds(),
+        0,
+        "alignment frequency must not be zero"
+    );
+    timestamps
+        .par_chunks_mut(CHUNK_LEN)
+        .for_each(|chunk| bulk::align_slice_to(chunk, anchor, freq));
+}
+
+/// Parallel version of [`align_slice_into`](crate::align_slice_into).
+pub fn align_slice_into(
+    src: &[UtcTimeStamp],
+    dst: &mut [UtcTimeStamp],
+    anchor: UtcTimeStamp,
+    freq: TimeDelta,
+) {
+    assert_eq!(
+        src.len(),
+        dst.len(),
+        "source and destination lengths differ"
+    );
+    assert_ne!(
+        freq.as_milliseconds(),
+        0,
+        "alignment frequency must not be zero"
+    );
+    src.par_chunks(CHUNK_LEN)
+        .zip(dst.par_chunks_mut(CHUNK_LEN))
+        .for_each(|(src, dst)| bulk::align_slice_into(src, dst, anchor, freq));
+}
+
+// ============================================================================================== //
+// [chrono conversion]                                                                            //
+// ============================================================================================== //
+
+/// Parallel version of [`from_chrono_slice`](crate::from_chrono_slice).
+pub fn from_chrono_slice(dates: &[ChronoUtc]) -> Vec<UtcTimeStamp> {
+    dates
+        .par_iter()
+        .with_min_len(CHUNK_LEN)
+        .map(|x| UtcTimeStamp::from(*x))
+        .collect()
+}
+
+/// Parallel version of [`to_chrono_vec`](crate::to_chrono_vec).
+pub fn to_chrono_vec(timestamps: &[UtcTimeStamp]) -> Vec<ChronoUtc> {
+    timestamps
+        .par_iter()
+        .with_min_len(CHUNK_LEN)
+        .map(|x| ChronoUtc::from(*x))
+        .collect()
+}
+
+// ============================================================================================== //
+// [Compression]                                                                                  //
+// ============================================================================================== //
+
+/// Encode a slice of timestamps as independent [`varint`] blocks.
+///
+/// Each block holds up to `block_len` timestamps and can be decoded on its
+/// own with [`varint::decode`], or all at once with [`decode_blocks`].
+///
+/// [`varint`]: crate::codec::varint
+/// [`varint::decode`]: crate::codec::varint::decode
+pub fn encode_blocks(timestamps: &[UtcTimeStamp], block_len: usize) -> Vec<Vec<u8>> {
+    assert_ne!(block_len, 0, "block length must not be zero");
+    timestamps
+        .par_chunks(block_len)
+        .map(codec::varint::encode)
+        .collect()
+}
+
+/// Decode blocks produced by [`encode_blocks`] into one vector.
+pub fn decode_blocks<B>(blocks: &[B]) -> Result<Vec<UtcTimeStamp>, DecodeError>
+where
+    B: AsRef<[u8]> + Sync,
+{
+    let decoded: Vec<_> = blocks
+        .par_iter()
+        .map(|x| codec::varint::decode(x.as_ref()))
+        .collect::<Result<_, _>>()?;
+    Ok(decoded.concat())
+}
+
+// ============================================================================================== //
+// [Statistics]                                                                                   //
+// ============================================================================================== //
+
+/// Smallest and largest timestamp of an unsorted slice.
+pub fn min_max(timestamps: &[UtcTimeStamp]) -> Option<(UtcTimeStamp, UtcTimeStamp)> {
+    timestamps
+        .par_iter()
+        .with_min_len(CHUNK_LEN)
+        .map(|&x| (x, x))
+        .reduce_with(|(a_min, a_max), (b_min, b_max)| (a_min.min(b_min), a_max.max(b_max)))
+}
+
+/// Parallel version of [`infer_freq`](crate::infer_freq).
+pub fn infer_freq(sorted: &[UtcTimeStamp]) -> Option<TimeDelta> {
+    infer_freq_within(sorted, TimeDelta::zero())
+}
+
+/// Parallel version of [`infer_freq_within`](crate::infer_freq_within).
+pub fn infer_freq_within(sorted: &[UtcTimeStamp], tolerance: TimeDelta) -> Option<TimeDelta> {
+    let mut deltas: Vec<_> = sorted
+        .par_windows(2)
+        .with_min_len(CHUNK_LEN)
+        .map(|x| (x[1] - x[0]).as_milliseconds())
+        .filter(|x| *x > 0)
+        .collect();
+
+    deltas.par_sort_unstable();
+    stats::freq_from_sorted_deltas(&deltas, tolerance)
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn series(len: i64) -> Vec<UtcTimeStamp> {
+        (0..len)
+            .map(|x| UtcTimeStamp::from_milliseconds(x * 1000 + x % 7))
+            .collect()
+    }
+
+    #[test]
+    fn matches_sequential() {
+        let src = series(200_000);
+        let (anchor, freq) = (
+            UtcTimeStamp::from_milliseconds(5),
+            TimeDelta::from_seconds(60),
+        );
+
+        let mut expected = src.clone();
+        crate::align_slice_to(&mut expected, anchor, freq);
+        let mut aligned = src.clone();
+        align_slice_to(&mut aligned, anchor, freq);
+        assert_eq!(aligned, expected);
+        let mut dst = vec![UtcTimeStamp::zero(); src.len()];
+        align_slice_into(&src, &mut dst, anchor, freq);
+        assert_eq!(dst, expected);
+
+        let dates = to_chrono_vec(&src);
+        assert_eq!(dates, crate::to_chrono_vec(&src));
+        assert_eq!(from_chrono_slice(&dates), src);
+
+        let blocks = encode_blocks(&src, 30_000);
+        assert_eq!(blocks.len(), 7);
+        assert_eq!(decode_blocks(&blocks), Ok(src.clone()));
+
+        assert_eq!(min_max(&src), Some((src[0], src[src.len() - 1])));
+        assert_eq!(min_max(&[]), None);
+        assert_eq!(
+            infer_freq_within(&src, TimeDelta::from_milliseconds(6)),
+            crate::infer_freq_within(&src, TimeDelta::from_milliseconds(6))
+        );
+        assert_eq!(infer_freq(&src), crate::infer_freq(&src));
+    }
+}
@@ -0,0 +1,251 @@
+//! Helpers for Parquet timestamp columns.
+//!
+//! Timestamps are written as `INT64` columns annotated with the
+//! `TIMESTAMP(MILLIS, true)` logical type, i.e. UTC milliseconds. On read,
+//! `MICROS` and `NANOS` columns are accepted as well and rounded towards the
+//! past, as are columns only carrying the legacy `TIMESTAMP_MILLIS` and
+//! `TIMESTAMP_MICROS` converted types. Columns of local (not UTC adjusted)
+//! timestamps are rejected.
+//!
+//! Examples:
+//!
+//! ```
+//! use parquet::{basic::Repetition, schema::types::{ColumnDescriptor, ColumnPath}};
+//! use std::sync::Arc;
+//! use utctimestamp::UtcTimeStamp;
+//!
+//! let field = utctimestamp::parquet::field("ts", Repetition::REQUIRED).unwrap();
+//! let column = ColumnDescriptor::new(Arc::new(field), 0, 0, ColumnPath::from("ts"));
+//!
+//! let ts = utctimestamp::parquet::from_column_values(&column, vec![1_600_000_000_250]).unwrap();
+//! assert_eq!(ts, [UtcTimeStamp::from_milliseconds(1_600_000_000_250)]);
+//! ```
+
+use crate::{as_millis_slice, from_millis_vec, UtcTimeStamp};
+use ::parquet::{
+    basic::{ConvertedType, LogicalType, Repetition, TimeUnit, Type as PhysicalType},
+    column::{reader::ColumnReaderImpl, writer::ColumnWriterImpl},
+    data_type::Int64Type,
+    errors::{ParquetError, Result},
+    format::MilliSeconds,
+    schema::types::{ColumnDescriptor, Type},
+};
+
+/// Build the schema of an `INT64` `TIMESTAMP(MILLIS, true)` field.
+pub fn field(name: &str, repetition: Repetition) -> Result<Type> {
+    Type::primitive_type_builder(name, PhysicalType::INT64)
+        .with_repetition(repetition)
+        .with_logical_type(Some(LogicalType::Timestamp {
+            is_adjusted_to_u_t_c: true,
+            unit: TimeUnit::MILLIS(MilliSeconds {}),
+        }))
+        .build()
+}
+
+/// Number of stored units per millisecond of a UTC timestamp column.
+fn units_per_milli(column: &ColumnDescriptor) -> Result<i64> {
+    if column.physical_type() != PhysicalType::INT64 {
+        return Err(ParquetError::General(format!(
+            "column {} isn't an INT64 timestamp column",
+            column.path()
+        )));
+    }
+
+    match (column.logical_type(), column.converted_type()) {
+        (
+            Some(LogicalType::Timestamp {
+                is_adjusted_to_u_t_c: false,
+                ..
+            }),
+            _,
+        ) => Err(ParquetError::General(format!(
+            "column {} holds local timestamps that aren't adjusted to UTC",
+            column.path()
+        ))),
+        (Some(LogicalType::Timestamp { unit, .. }), _) => Ok(match unit {
+            TimeUnit::MILLIS(_) => 1,
+            TimeUnit::MICROS(_) => 1_000,
+            TimeUnit::NANOS(_) => 1_000_000,
+        }),
+        (None, ConvertedType::TIMESTAMP_MILLIS) => Ok(1),
+        (None, ConvertedType::TIMESTAMP_MICROS) => Ok(1_000),
+        _ => Err(ParquetError::General(format!(
+            "column {} isn't annotated as timestamp",
+            column.path()
+        ))),
+    }
+}
+
+/// Convert the raw values read from a timestamp column, reusing the allocation.
+///
+/// Fails if the column isn't an `INT64` UTC timestamp column.
+pub fn from_column_values(
+    column: &ColumnDescriptor,
+    mut values: Vec<i64>,
+) -> Result<Vec<UtcTimeStamp>> {
+    let units = units_per_milli(column)?;
+    if units != 1 {
+        for x in &mut values {
+            *x = x.div_euclid(units);
+        }
+    }
+    Ok(from_millis_vec(values))
+}
+
+/// Read up to `max_records` records of a timestamp column, appending the
+/// non-null values to `out`.
+///
+/// Returns the number of records, values and levels read, like
+/// [`ColumnReaderImpl::read_records`]. Definition levels are required to
+/// locate nulls in optional columns.
+pub fn read_records(
+    reader: &mut ColumnReaderImpl<Int64Type>,
+    column: &ColumnDescriptor,
+    max_records: usize,
+    def_levels: Option<&mut Vec<i16>>,
+    out: &mut Vec<UtcTimeStamp>,
+) -> Result<(usize, usize, usize)> {
+    let mut values = Vec::with_capacity(max_records);
+    let read = reader.read_records(max_records, def_levels, None, &mut values)?;
+    out.extend(from_column_values(column, values)?);
+    Ok(read)
+}
+
+/// Write non-null timestamps to a `TIMESTAMP(MILLIS, true)` column.
+///
+/// Returns the number of values written, like
+/// [`ColumnWriterImpl::write_batch`]. Fails for columns of any other type.
+pub fn write_batch(
+    writer: &mut ColumnWriterImpl<'_, Int64Type>,
+    timestamps: &[UtcTimeStamp],
+    def_levels: Option<&[i16]>,
+) -> Result<usize> {
+    let column = writer.get_descriptor();
+    let is_utc_millis = matches!(
+        column.logical_type(),
+        Some(LogicalType::Timestamp {
+            is_adjusted_to_u_t_c: true,
+            unit: TimeUnit::MILLIS(_)
+        })
+    );
+    if column.physical_type() != PhysicalType::INT64 || !is_utc_millis {
+        return Err(ParquetError::General(format!(
+            "column {} isn't an INT64 TIMESTAMP(MILLIS, true) column",
+            column.path()
+        )));
+    }
+
+    writer.write_batch(as_millis_slice(timestamps), def_levels, None)
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::parquet::{
+        column::{reader::get_typed_column_reader, writer::get_typed_column_writer_mut},
+        file::{
+            reader::{FileReader, SerializedFileReader},
+            writer::SerializedFileWriter,
+        },
+        format::{MicroSeconds, NanoSeconds},
+        schema::types::ColumnPath,
+    };
+    use std::sync::Arc;
+
+    fn column(tp: Type) -> ColumnDescriptor {
+        ColumnDescriptor::new(Arc::new(tp), 0, 0, ColumnPath::from("ts"))
+    }
+
+    fn timestamp_column(is_adjusted_to_u_t_c: bool, unit: TimeUnit) -> ColumnDescriptor {
+        let tp = Type::primitive_type_builder("ts", PhysicalType::INT64)
+            .with_logical_type(Some(LogicalType::Timestamp {
+                is_adjusted_to_u_t_c,
+                unit,
+            }))
+            .build();
+        column(tp.unwrap())
+    }
+
+    #[test]
+    fn column_values() {
+        let ms = UtcTimeStamp::from_milliseconds;
+        let values = || vec![-1, 1_999_999];
+
+        let millis = column(field("ts", Repetition::REQUIRED).unwrap());
+        assert_eq!(millis.converted_type(), ConvertedType::TIMESTAMP_MILLIS);
+        assert_eq!(from_column_values(&millis, values()).unwrap(), [
+            ms(-1),
+            ms(1_999_999)
+        ]);
+
+        let micros = timestamp_column(true, TimeUnit::MICROS(MicroSeconds {}));
+        assert_eq!(from_column_values(&micros, values()).unwrap(), [
+            ms(-1),
+            ms(1_999)
+        ]);
+
+        let nanos = timestamp_column(true, TimeUnit::NANOS(NanoSeconds {}));
+        assert_eq!(from_column_values(&nanos, values()).unwrap(), [
+            ms(-1),
+            ms(1)
+        ]);
+
+        let legacy = Type::primitive_type_builder("ts", PhysicalType::INT64)
+            .with_converted_type(ConvertedType::TIMESTAMP_MICROS)
+            .build();
+        assert_eq!(
+            from_column_values(&column(legacy.unwrap()), values()).unwrap(),
+            [ms(-1), ms(1_999)]
+        );
+
+        let local = timestamp_column(false, TimeUnit::MILLIS(MilliSeconds {}));
+        assert!(from_column_values(&local, values()).is_err());
+
+        let plain = Type::primitive_type_builder("ts", PhysicalType::INT64).build();
+        assert!(from_column_values(&column(plain.unwrap()), values()).is_err());
+    }
+
+    #[test]
+    fn round_trip() {
+        let ts = vec![
+            UtcTimeStamp::from_milliseconds(i64::MIN),
+            UtcTimeStamp::zero(),
+        ];
+        let schema = Type::group_type_builder("schema")
+            .with_fields(vec![Arc::new(field("ts", Repetition::OPTIONAL).unwrap())])
+            .build()
+            .unwrap();
+
+        let mut buf = Vec::new();
+        let mut writer =
+            SerializedFileWriter::new(&mut buf, Arc::new(schema), Default::default()).unwrap();
+        let mut row_group = writer.next_row_group().unwrap();
+        let mut column = row_group.next_column().unwrap().unwrap();
+        let written = write_batch(
+            get_typed_column_writer_mut(column.untyped()),
+            &ts,
+            Some(&[1, 0, 1]),
+        )
+        .unwrap();
+        assert_eq!(written, 2);
+        column.close().unwrap();
+        row_group.close().unwrap();
+        writer.close().unwrap();
+
+        let reader = SerializedFileReader::new(bytes::Bytes::from(buf)).unwrap();
+        let row_group = reader.get_row_group(0).unwrap();
+        let descr = row_group.metadata().column(0).column_descr_ptr();
+        let mut column = get_typed_column_reader(row_group.get_column_reader(0).unwrap());
+
+        let mut out = Vec::new();
+        let mut def_levels = Vec::new();
+        let read = read_records(&mut column, &descr, 10, Some(&mut def_levels), &mut out).unwrap();
+        assert_eq!(read, (3, 2, 3));
+        assert_eq!(def_levels, [1, 0, 1]);
+        assert_eq!(out, ts);
+    }
+}
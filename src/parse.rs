@@ -0,0 +1,571 @@
+//! Parsing timestamps from strings.
+
+use crate::{
+    calendar::{days_from_civil, days_in_month, MS_PER_DAY},
+    TimeDelta, UtcTimeStamp,
+};
+use core::{fmt, str::FromStr};
+
+/// Error returned when parsing a timestamp or delta from a string.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ParseError {
+    /// The input doesn't match the expected format.
+    Invalid,
+    /// The input is well-formed but can't be represented.
+    OutOfRange,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ParseError::Invalid => "input doesn't match the expected format",
+            ParseError::OutOfRange => "input out of range",
+        })
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl UtcTimeStamp {
+    /// Parse an RFC 3339 string such as `2020-09-13T12:26:40.5+02:00`.
+    ///
+    /// The offset is applied to get the UTC time. Digits beyond millisecond
+    /// precision are truncated.
+    ///
+    /// Examples:
+    ///
+    /// ```
+    /// use utctimestamp::{ParseError, UtcTimeStamp};
+    ///
+    /// let ts = UtcTimeStamp::parse_rfc3339("2020-09-13T14:26:40.5+02:00").unwrap();
+    /// assert_eq!(ts.as_milliseconds(), 1_600_000_000_500);
+    /// assert_eq!(UtcTimeStamp::parse_rfc3339("2020-09-13"), Err(ParseError::Invalid));
+    /// ```
+    pub fn parse_rfc3339(s: &str) -> Result<Self, ParseError> {
+        chrono::DateTime::parse_from_rfc3339(s)
+            .map(UtcTimeStamp::from)
+            .map_err(|_| ParseError::Invalid)
+    }
+
+    /// Parse an RFC 2822 date such as `Tue, 1 Jul 2003 10:52:37 +0200`.
+    ///
+    /// The offset is applied to get the UTC time.
+    ///
+    /// Examples:
+    ///
+    /// ```
+    /// use utctimestamp::UtcTimeStamp;
+    ///
+    /// let ts = UtcTimeStamp::parse_rfc2822("Tue, 1 Jul 2003 10:52:37 +0200").unwrap();
+    /// assert_eq!(ts, UtcTimeStamp::from_seconds(1_057_049_557));
+    /// ```
+    pub fn parse_rfc2822(s: &str) -> Result<Self, ParseError> {
+        chrono::DateTime::parse_from_rfc2822(s)
+            .map(UtcTimeStamp::from)
+            .map_err(|_| ParseError::Invalid)
+    }
+
+    /// Parse an HTTP date as used in headers like `Last-Modified`.
+    ///
+    /// Accepts the preferred IMF-fixdate format of RFC 7231, e.g.
+    /// `Sun, 06 Nov 1994 08:49:37 GMT`, as well as the obsolete RFC 850
+    /// (`Sunday, 06-Nov-94 08:49:37 GMT`) and asctime
+    /// (`Sun Nov  6 08:49:37 1994`) formats that recipients must still
+    /// understand. Two-digit RFC 850 years below 70 are placed in the 2000s.
+    ///
+    /// Examples:
+    ///
+    /// ```
+    /// use utctimestamp::UtcTimeStamp;
+    ///
+    /// let ts = UtcTimeStamp::from_seconds(784_111_777);
+    /// assert_eq!(UtcTimeStamp::parse_http_date("Sun, 06 Nov 1994 08:49:37 GMT"), Ok(ts));
+    /// assert_eq!(UtcTimeStamp::parse_http_date("Sunday, 06-Nov-94 08:49:37 GMT"), Ok(ts));
+    /// assert_eq!(UtcTimeStamp::parse_http_date("Sun Nov  6 08:49:37 1994"), Ok(ts));
+    /// ```
+    pub fn parse_http_date(s: &str) -> Result<Self, ParseError> {
+        const FORMATS: [&str; 3] = [
+            "%a, %d %b %Y %H:%M:%S GMT",
+            "%A, %d-%b-%y %H:%M:%S GMT",
+            "%a %b %e %H:%M:%S %Y",
+        ];
+
+        FORMATS
+            .iter()
+            .find_map(|fmt| chrono::NaiveDateTime::parse_from_str(s, fmt).ok())
+            .map(|dt| UtcTimeStamp::from_milliseconds(dt.and_utc().timestamp_millis()))
+            .ok_or(ParseError::Invalid)
+    }
+
+    /// Parse a string with a custom strftime-like format.
+    ///
+    /// See [`chrono::format::strftime`] for the supported directives. Inputs
+    /// without an offset are interpreted as UTC, inputs without a time as
+    /// midnight.
+    ///
+    /// Examples:
+    ///
+    /// ```
+    /// use utctimestamp::UtcTimeStamp;
+    ///
+    /// let ts = UtcTimeStamp::parse_from_str("20200913 122640.250", "%Y%m%d %H%M%S%.3f").unwrap();
+    /// assert_eq!(ts.as_milliseconds(), 1_600_000_000_250);
+    ///
+    /// let ts = UtcTimeStamp::parse_from_str("13.09.2020", "%d.%m.%Y").unwrap();
+    /// assert_eq!(ts.as_milliseconds(), 1_599_955_200_000);
+    /// ```
+    pub fn parse_from_str(s: &str, fmt: &str) -> Result<Self, ParseError> {
+        use chrono::{format::ParseErrorKind, DateTime, NaiveDate, NaiveDateTime};
+
+        let not_enough = |e: &chrono::ParseError| e.kind() == ParseErrorKind::NotEnough;
+        let naive = match DateTime::parse_from_str(s, fmt) {
+            Ok(dt) => return Ok(UtcTimeStamp::from(dt)),
+            Err(e) if not_enough(&e) => match NaiveDateTime::parse_from_str(s, fmt) {
+                Err(e) if not_enough(&e) => {
+                    NaiveDate::parse_from_str(s, fmt).map(|x| x.and_time(chrono::NaiveTime::MIN))
+                }
+                x => x,
+            },
+            Err(_) => return Err(ParseError::Invalid),
+        };
+
+        let dt = naive.map_err(|_| ParseError::Invalid)?.and_utc();
+        Ok(UtcTimeStamp::from_milliseconds(dt.timestamp_millis()))
+    }
+}
+
+// ============================================================================================== //
+// [Fixed layout]                                                                                 //
+// ============================================================================================== //
+
+/// `?` for `Option` in const fns.
+macro_rules! try_opt {
+    ($e:expr) => {
+        match $e {
+            Some(x) => x,
+            None => return None,
+        }
+    };
+}
+
+/// Parse `n` ASCII digits at `at`.
+#[inline(always)]
+const fn digits(s: &[u8], at: usize, n: usize) -> Option<u32> {
+    let mut value = 0;
+    let mut i = at;
+    while i < at + n {
+        let digit = s[i].wrapping_sub(b'0');
+        if digit > 9 {
+            return None;
+        }
+        value = value * 10 + digit as u32;
+        i += 1;
+    }
+    Some(value)
+}
+
+/// Fixed layout parser, see [`UtcTimeStamp::parse_fixed`]. Also backs [`ts!`].
+pub const fn parse_fixed_const(s: &[u8]) -> Option<UtcTimeStamp> {
+    let millis = match s.len() {
+        20 => 0,
+        24 if s[19] == b'.' => try_opt!(digits(s, 20, 3)),
+        _ => return None,
+    };
+    if s[4] != b'-'
+        || s[7] != b'-'
+        || s[13] != b':'
+        || s[16] != b':'
+        || !matches!(s[10], b'T' | b' ')
+        || s[s.len() - 1] != b'Z'
+    {
+        return None;
+    }
+
+    let (year, month, day) = (
+        try_opt!(digits(s, 0, 4)),
+        try_opt!(digits(s, 5, 2)),
+        try_opt!(digits(s, 8, 2)),
+    );
+    let (hour, minute, second) = (
+        try_opt!(digits(s, 11, 2)),
+        try_opt!(digits(s, 14, 2)),
+        try_opt!(digits(s, 17, 2)),
+    );
+    if month < 1
+        || month > 12
+        || day == 0
+        || day > days_in_month(year as i64, month)
+        || hour > 23
+        || minute > 59
+        || second > 59
+    {
+        return None;
+    }
+
+    let days = days_from_civil(year as i64, month, day);
+    let ms_of_day = ((hour * 60 + minute) * 60 + second) as i64 * 1000 + millis as i64;
+    Some(UtcTimeStamp::from_milliseconds(
+        days * MS_PER_DAY + ms_of_day,
+    ))
+}
+
+impl UtcTimeStamp {
+    /// Parse the fixed layout `YYYY-MM-DDTHH:MM:SS.mmmZ` without going through chrono.
+    ///
+    /// The millisecond part may be omitted as a whole (`YYYY-MM-DDTHH:MM:SSZ`)
+    /// and a space is accepted instead of the `T`. Anything else, including
+    /// offsets other than `Z`, is rejected. This is several times faster than
+    /// [`parse_rfc3339`](Self::parse_rfc3339) and intended for bulk ingest.
+    ///
+    /// Examples:
+    ///
+    /// ```
+    /// use utctimestamp::UtcTimeStamp;
+    ///
+    /// let ts = UtcTimeStamp::parse_fixed(b"2020-09-13T12:26:40.250Z").unwrap();
+    /// assert_eq!(ts.as_milliseconds(), 1_600_000_000_250);
+    /// assert!(UtcTimeStamp::parse_fixed(b"2020-09-13T12:26:40.25Z").is_err());
+    /// ```
+    pub fn parse_fixed(s: &[u8]) -> Result<Self, ParseError> {
+        parse_fixed_const(s).ok_or(ParseError::Invalid)
+    }
+}
+
+/// Parse a column of fixed layout timestamps, see [`UtcTimeStamp::parse_fixed`].
+///
+/// Stops at the first invalid input and returns its index along with the error.
+///
+/// Examples:
+///
+/// ```
+/// use utctimestamp::{parse_fixed_column, ParseError};
+///
+/// let column = ["1970-01-01T00:00:00Z", "1970-01-01T00:00:01.500Z"];
+/// let ts = parse_fixed_column(&column).unwrap();
+/// assert_eq!(ts[1].as_milliseconds(), 1500);
+///
+/// assert_eq!(parse_fixed_column(&["1970-01-01T00:00:00Z", "n/a"]), Err((1, ParseError::Invalid)));
+/// ```
+pub fn parse_fixed_column<I>(column: I) -> Result<Vec<UtcTimeStamp>, (usize, ParseError)>
+where
+    I: IntoIterator,
+    I::Item: AsRef<[u8]>,
+{
+    let column = column.into_iter();
+    let mut out = Vec::with_capacity(column.size_hint().0);
+    for (idx, s) in column.enumerate() {
+        match parse_fixed_const(s.as_ref()) {
+            Some(ts) => out.push(ts),
+            None => return Err((idx, ParseError::Invalid)),
+        }
+    }
+    Ok(out)
+}
+
+// ============================================================================================== //
+// [Deltas]                                                                                       //
+// ============================================================================================== //
+
+/// Parse compact deltas such as `15m`, `1h30m` or `-250ms`. Backs [`td!`].
+///
+/// Components may be separated by spaces and use the units `w`, `d`, `h`,
+/// `m`, `s` and `ms`.
+pub const fn parse_delta_const(s: &[u8]) -> Option<TimeDelta> {
+    let (negative, mut i) = match s.first() {
+        Some(b'-') => (true, 1),
+        Some(b'+') => (false, 1),
+        _ => (false, 0),
+    };
+
+    let mut total: i64 = 0;
+    let mut components = 0;
+    while i < s.len() {
+        if s[i] == b' ' {
+            i += 1;
+            continue;
+        }
+
+        let mut value: i64 = 0;
+        let start = i;
+        while i < s.len() && s[i].is_ascii_digit() {
+            value = try_opt!(value.checked_mul(10));
+            value = try_opt!(value.checked_add((s[i] - b'0') as i64));
+            i += 1;
+        }
+        if i == start || i == s.len() {
+            return None;
+        }
+
+        let unit = match s[i] {
+            b'm' if i + 1 < s.len() && s[i + 1] == b's' => {
+                i += 1;
+                1
+            }
+            b'w' => 7 * MS_PER_DAY,
+            b'd' => MS_PER_DAY,
+            b'h' => 60 * 60 * 1000,
+            b'm' => 60 * 1000,
+            b's' => 1000,
+            _ => return None,
+        };
+        i += 1;
+
+        total = try_opt!(total.checked_add(try_opt!(value.checked_mul(unit))));
+        components += 1;
+    }
+
+    if components == 0 {
+        return None;
+    }
+    Some(TimeDelta::from_milliseconds(if negative {
+        -total
+    } else {
+        total
+    }))
+}
+
+// ============================================================================================== //
+// [FromStr]                                                                                      //
+// ============================================================================================== //
+
+/// Parse an RFC 3339 or ISO 8601 timestamp with offset.
+///
+/// In addition to strict RFC 3339, a space may separate date and time and
+/// fields may omit leading zeros, e.g. `2020-9-13 12:26:40Z`.
+impl FromStr for UtcTimeStamp {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        UtcTimeStamp::parse_rfc3339(s).or_else(|_| {
+            s.parse::<chrono::DateTime<chrono::FixedOffset>>()
+                .map(UtcTimeStamp::from)
+                .map_err(|_| ParseError::Invalid)
+        })
+    }
+}
+
+fn delta_unit_ms(unit: &str) -> Option<i64> {
+    Some(match unit {
+        "ms" | "msec" | "millis" | "millisecond" | "milliseconds" => 1,
+        "s" | "sec" | "secs" | "second" | "seconds" => 1000,
+        "m" | "min" | "mins" | "minute" | "minutes" => 60 * 1000,
+        "h" | "hr" | "hrs" | "hour" | "hours" => 60 * 60 * 1000,
+        "d" | "day" | "days" => MS_PER_DAY,
+        "w" | "week" | "weeks" => 7 * MS_PER_DAY,
+        _ => return None,
+    })
+}
+
+/// Parse human-friendly deltas such as `90s`, `1h30m`, `2 days` or `-250ms`.
+///
+/// The input is a sequence of integer values, each followed by a unit and
+/// optionally separated by spaces, with an optional leading sign. Units range
+/// from milliseconds (`ms`, `msec`, `millis`) through seconds (`s`, `sec`),
+/// minutes (`m`, `min`), hours (`h`, `hr`), days (`d`) to weeks (`w`); the
+/// spelled out singular and plural forms are accepted as well. Months and
+/// years are rejected since their length varies.
+///
+/// Examples:
+///
+/// ```
+/// use utctimestamp::TimeDelta;
+///
+/// assert_eq!("1h30m".parse(), Ok(TimeDelta::from_minutes(90)));
+/// assert_eq!("2 days 1 hour".parse(), Ok(TimeDelta::from_hours(49)));
+/// assert_eq!("-250ms".parse(), Ok(TimeDelta::from_milliseconds(-250)));
+/// ```
+impl FromStr for TimeDelta {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (negative, mut rest) = match s.as_bytes().first() {
+            Some(b'-') => (true, &s[1..]),
+            Some(b'+') => (false, &s[1..]),
+            _ => (false, s),
+        };
+
+        let mut total: i64 = 0;
+        let mut components = 0;
+        loop {
+            rest = rest.trim_start();
+            if rest.is_empty() {
+                break;
+            }
+
+            let digits = rest
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(rest.len());
+            let (value, tail) = rest.split_at(digits);
+            let tail = tail.trim_start();
+            let unit_len = tail
+                .find(|c: char| !c.is_ascii_alphabetic())
+                .unwrap_or(tail.len());
+            let (unit, tail) = tail.split_at(unit_len);
+            if value.is_empty() {
+                return Err(ParseError::Invalid);
+            }
+
+            let unit = delta_unit_ms(unit).ok_or(ParseError::Invalid)?;
+            let value: i64 = value.parse().map_err(|_| ParseError::OutOfRange)?;
+            total = value
+                .checked_mul(unit)
+                .and_then(|x| total.checked_add(x))
+                .ok_or(ParseError::OutOfRange)?;
+            components += 1;
+            rest = tail;
+        }
+
+        if components == 0 {
+            return Err(ParseError::Invalid);
+        }
+        Ok(TimeDelta::from_milliseconds(if negative {
+            -total
+        } else {
+            total
+        }))
+    }
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use super::parse_delta_const;
+    use crate::*;
+
+    #[test]
+    fn rfc3339() {
+        let ms = |s: &str| UtcTimeStamp::parse_rfc3339(s).map(UtcTimeStamp::as_milliseconds);
+        assert_eq!(ms("1970-01-01T00:00:00Z"), Ok(0));
+        assert_eq!(ms("1969-12-31T23:59:59.9999Z"), Ok(-1));
+        assert_eq!(ms("1970-01-01T01:00:00+01:00"), Ok(0));
+        assert_eq!(ms("1970-01-01 00:00:00Z"), Ok(0));
+        assert_eq!(ms("1970-01-01T00:00:00"), Err(ParseError::Invalid));
+        assert_eq!(ms(""), Err(ParseError::Invalid));
+    }
+
+    #[test]
+    fn rfc2822() {
+        let ms = |s: &str| UtcTimeStamp::parse_rfc2822(s).map(UtcTimeStamp::as_milliseconds);
+        assert_eq!(ms("Thu, 1 Jan 1970 00:00:00 +0000"), Ok(0));
+        assert_eq!(ms("1 Jan 1970 01:00:00 +0100"), Ok(0));
+        assert_eq!(ms("Thu, 01 Jan 1970 00:00:01 GMT"), Ok(1000));
+        assert_eq!(ms("1970-01-01T00:00:00Z"), Err(ParseError::Invalid));
+    }
+
+    #[test]
+    fn http_date() {
+        let ms = |s: &str| UtcTimeStamp::parse_http_date(s).map(UtcTimeStamp::as_milliseconds);
+        assert_eq!(ms("Thu, 01 Jan 1970 00:00:00 GMT"), Ok(0));
+        assert_eq!(ms("Wed, 31 Dec 1969 23:59:59 GMT"), Ok(-1000));
+        assert_eq!(ms("Monday, 01-Jan-01 00:00:00 GMT"), Ok(978_307_200_000));
+        assert_eq!(ms("Thu Jan  1 00:00:00 1970"), Ok(0));
+        assert_eq!(ms("Thu Jan 1 00:00:00 1970"), Ok(0));
+        assert_eq!(
+            ms("Thu, 01 Jan 1970 00:00:00 +0000"),
+            Err(ParseError::Invalid)
+        );
+        assert_eq!(
+            ms("Fri, 01 Jan 1970 00:00:00 GMT"),
+            Err(ParseError::Invalid)
+        );
+        assert_eq!(ms("1970-01-01T00:00:00Z"), Err(ParseError::Invalid));
+    }
+
+    #[test]
+    fn custom_format() {
+        let ms = |s, fmt| UtcTimeStamp::parse_from_str(s, fmt).map(UtcTimeStamp::as_milliseconds);
+        assert_eq!(ms("1970-01-01 01:00 +0100", "%Y-%m-%d %H:%M %z"), Ok(0));
+        assert_eq!(
+            ms("1970/01/02 00:00:01", "%Y/%m/%d %H:%M:%S"),
+            Ok(86_401_000)
+        );
+        assert_eq!(ms("19691231", "%Y%m%d"), Ok(-86_400_000));
+        assert_eq!(ms("1970-01-01", "%Y-%m"), Err(ParseError::Invalid));
+        assert_eq!(ms("12:00", "%H:%M"), Err(ParseError::Invalid));
+    }
+
+    #[test]
+    fn fixed_layout() {
+        let ms =
+            |s: &str| UtcTimeStamp::parse_fixed(s.as_bytes()).map(UtcTimeStamp::as_milliseconds);
+        assert_eq!(ms("1970-01-01T00:00:00.000Z"), Ok(0));
+        assert_eq!(ms("1969-12-31 23:59:59.999Z"), Ok(-1));
+        assert_eq!(ms("2000-02-29T00:00:00Z"), Ok(951_782_400_000));
+        assert_eq!(ms("0000-01-01T00:00:00Z"), Ok(-62_167_219_200_000));
+        assert_eq!(ms("1999-02-29T00:00:00Z"), Err(ParseError::Invalid));
+        assert_eq!(ms("1970-01-01T24:00:00Z"), Err(ParseError::Invalid));
+        assert_eq!(ms("1970-01-01T00:00:00+00:00"), Err(ParseError::Invalid));
+        assert_eq!(ms("1970-01-01T00:00:00.000"), Err(ParseError::Invalid));
+        assert_eq!(ms("1970-01-01T00:00:0a.000Z"), Err(ParseError::Invalid));
+        assert_eq!(ms("1970-13-01T00:00:00Z"), Err(ParseError::Invalid));
+        assert_eq!(ms(""), Err(ParseError::Invalid));
+
+        // Agrees with the general purpose parser.
+        for s in &[
+            "2020-09-13T12:26:40.250Z",
+            "1601-03-01T23:59:59.001Z",
+            "2400-12-31T00:00:00Z",
+        ] {
+            assert_eq!(
+                ms(s),
+                UtcTimeStamp::parse_rfc3339(s).map(UtcTimeStamp::as_milliseconds)
+            );
+        }
+
+        assert_eq!(parse_fixed_column(Vec::<&[u8]>::new()), Ok(vec![]));
+    }
+
+    #[test]
+    fn compact_delta() {
+        let ms = |s: &str| parse_delta_const(s.as_bytes()).map(TimeDelta::as_milliseconds);
+        assert_eq!(ms("15m"), Some(900_000));
+        assert_eq!(ms("1h30m"), Some(5_400_000));
+        assert_eq!(ms("1d 2h"), Some(93_600_000));
+        assert_eq!(ms("-250ms"), Some(-250));
+        assert_eq!(ms("1w"), Some(604_800_000));
+        assert_eq!(ms("1m1ms"), Some(60_001));
+        assert_eq!(ms("0s"), Some(0));
+        assert_eq!(ms(""), None);
+        assert_eq!(ms("-"), None);
+        assert_eq!(ms("15"), None);
+        assert_eq!(ms("h"), None);
+        assert_eq!(ms("1y"), None);
+        assert_eq!(ms("1.5h"), None);
+        assert_eq!(ms("99999999999999999999s"), None);
+    }
+
+    #[test]
+    fn from_str() {
+        let ms = |s: &str| s.parse::<UtcTimeStamp>().map(UtcTimeStamp::as_milliseconds);
+        assert_eq!(ms("2020-09-13T12:26:40Z"), Ok(1_600_000_000_000));
+        assert_eq!(ms("2020-9-13 12:26:40.001 +00:00"), Ok(1_600_000_000_001));
+        assert_eq!(ms("2020-09-13T12:26:40"), Err(ParseError::Invalid));
+        assert_eq!(ms("yesterday"), Err(ParseError::Invalid));
+    }
+
+    #[test]
+    fn delta_from_str() {
+        let ms = |s: &str| s.parse::<TimeDelta>().map(TimeDelta::as_milliseconds);
+        assert_eq!(ms("90s"), Ok(90_000));
+        assert_eq!(ms("1h30m"), Ok(5_400_000));
+        assert_eq!(ms("2d"), Ok(172_800_000));
+        assert_eq!(ms("250ms"), Ok(250));
+        assert_eq!(ms(" 1 hour 5 mins "), Ok(3_900_000));
+        assert_eq!(ms("1w 1sec 1msec"), Ok(604_801_001));
+        assert_eq!(ms("-1m"), Ok(-60_000));
+        assert_eq!(ms("+3 seconds"), Ok(3000));
+        assert_eq!(ms(""), Err(ParseError::Invalid));
+        assert_eq!(ms("-"), Err(ParseError::Invalid));
+        assert_eq!(ms("15"), Err(ParseError::Invalid));
+        assert_eq!(ms("h"), Err(ParseError::Invalid));
+        assert_eq!(ms("1y"), Err(ParseError::Invalid));
+        assert_eq!(ms("1.5h"), Err(ParseError::Invalid));
+        assert_eq!(ms("1h-5m"), Err(ParseError::Invalid));
+        assert_eq!(ms("99999999999999999999s"), Err(ParseError::OutOfRange));
+        assert_eq!(ms("9999999999999999w"), Err(ParseError::OutOfRange));
+    }
+}
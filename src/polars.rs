@@ -0,0 +1,112 @@
+//! Conversions from and to Polars' `DatetimeChunked` columns.
+//!
+//! Columns created here use millisecond precision and the `UTC` time zone, so
+//! their physical `i64` values are exactly the wrapped milliseconds and owned
+//! vectors are moved into Polars without copying. Missing values are
+//! represented by Polars' validity mask rather than a sentinel value and map
+//! to `None`.
+//!
+//! Examples:
+//!
+//! ```
+//! use utctimestamp::{polars, UtcTimeStamp};
+//!
+//! let ts = vec![UtcTimeStamp::from_seconds(1), UtcTimeStamp::from_seconds(2)];
+//! let column = polars::to_datetime("ts", ts.clone());
+//! assert_eq!(polars::as_slice(&column).unwrap(), &ts[..]);
+//! ```
+
+use crate::{as_millis_slice, from_millis_slice, into_millis_vec, UtcTimeStamp};
+use polars_core::prelude::*;
+
+/// Convert a vector into a column without nulls, reusing the allocation.
+pub fn to_datetime(name: &str, timestamps: Vec<UtcTimeStamp>) -> DatetimeChunked {
+    Int64Chunked::from_vec(name.into(), into_millis_vec(timestamps))
+        .into_datetime(TimeUnit::Milliseconds, Some(TimeZone::UTC))
+}
+
+/// Copy a slice into a column without nulls.
+pub fn from_slice(name: &str, timestamps: &[UtcTimeStamp]) -> DatetimeChunked {
+    Int64Chunked::from_slice(name.into(), as_millis_slice(timestamps))
+        .into_datetime(TimeUnit::Milliseconds, Some(TimeZone::UTC))
+}
+
+/// Build a column from optional timestamps, with `None` becoming null.
+pub fn from_options<I>(name: &str, timestamps: I) -> DatetimeChunked
+where
+    I: IntoIterator<Item = Option<UtcTimeStamp>>,
+{
+    let values = timestamps
+        .into_iter()
+        .map(|x| x.map(UtcTimeStamp::as_milliseconds));
+    Int64Chunked::from_iter_options(name.into(), values)
+        .into_datetime(TimeUnit::Milliseconds, Some(TimeZone::UTC))
+}
+
+/// View a column as timestamps, without copying.
+///
+/// Fails unless the column has millisecond precision, consists of a single
+/// chunk and contains no nulls. Use [`to_vec`] for other columns.
+pub fn as_slice(column: &DatetimeChunked) -> PolarsResult<&[UtcTimeStamp]> {
+    polars_ensure!(
+        column.time_unit() == TimeUnit::Milliseconds,
+        ComputeError: "expected a millisecond datetime column, got {}", column.dtype()
+    );
+    column.physical().cont_slice().map(from_millis_slice)
+}
+
+/// Copy a column of any precision into a vector, with nulls becoming `None`.
+///
+/// Micro- and nanosecond values are rounded towards the past. Columns without
+/// time zone are assumed to hold UTC wall clock times.
+pub fn to_vec(column: &DatetimeChunked) -> Vec<Option<UtcTimeStamp>> {
+    let column = match column.time_unit() {
+        TimeUnit::Milliseconds => column.clone(),
+        _ => column.cast_time_unit(TimeUnit::Milliseconds),
+    };
+    column
+        .physical()
+        .iter()
+        .map(|x| x.map(UtcTimeStamp::from_milliseconds))
+        .collect()
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn columns() {
+        let ts: Vec<_> = (-2..3).map(UtcTimeStamp::from_milliseconds).collect();
+
+        let column = to_datetime("ts", ts.clone());
+        assert_eq!(column.name().as_str(), "ts");
+        assert_eq!(
+            column.dtype(),
+            &DataType::Datetime(TimeUnit::Milliseconds, Some(TimeZone::UTC))
+        );
+        assert_eq!(as_slice(&column).unwrap(), &ts[..]);
+        assert_eq!(as_slice(&from_slice("ts", &ts)).unwrap(), &ts[..]);
+
+        let ptr = ts.as_ptr();
+        assert_eq!(as_slice(&to_datetime("ts", ts)).unwrap().as_ptr(), ptr);
+
+        let options = vec![Some(UtcTimeStamp::zero()), None];
+        let column = from_options("ts", options.clone());
+        assert_eq!(column.null_count(), 1);
+        assert_eq!(to_vec(&column), options);
+        assert!(as_slice(&column).is_err());
+
+        let micros = Int64Chunked::from_vec("ts".into(), vec![-1, 1_999])
+            .into_datetime(TimeUnit::Microseconds, None);
+        assert!(as_slice(&micros).is_err());
+        assert_eq!(to_vec(&micros), [
+            Some(UtcTimeStamp::from_milliseconds(-1)),
+            Some(UtcTimeStamp::from_milliseconds(1)),
+        ]);
+    }
+}
@@ -0,0 +1,134 @@
+//! Helpers for Prometheus remote-write samples and HTTP API times.
+//!
+//! Samples carry `i64` millisecond timestamps, which are exactly the internal
+//! representation of [`UtcTimeStamp`]. The HTTP query API instead expects and
+//! returns times as float seconds (or RFC 3339 strings), and range queries are
+//! usually aligned to multiples of their step so that results are cacheable.
+//!
+//! Examples:
+//!
+//! ```
+//! use utctimestamp::{prometheus, TimeDelta, UtcTimeStamp};
+//!
+//! let now = prometheus::parse_time("1600000012.345").unwrap();
+//! let step = TimeDelta::from_seconds(15);
+//! let start = now - TimeDelta::from_minutes(1);
+//!
+//! let times: Vec<_> = prometheus::query_range(start, now, step).map(prometheus::format_time).collect();
+//! assert_eq!(times, ["1599999945", "1599999960", "1599999975", "1599999990", "1600000005"]);
+//! ```
+
+use crate::{resample::bucket_start, ParseError, TimeDelta, TimeRange, UtcTimeStamp};
+use core::fmt::Write;
+
+/// Convert to a remote-write sample timestamp.
+#[inline]
+pub fn to_sample_timestamp(ts: UtcTimeStamp) -> i64 {
+    ts.as_milliseconds()
+}
+
+/// Convert a remote-write sample timestamp.
+#[inline]
+pub fn from_sample_timestamp(millis: i64) -> UtcTimeStamp {
+    UtcTimeStamp::from_milliseconds(millis)
+}
+
+/// Format as float seconds like the HTTP API, e.g. `1600000000.25`.
+///
+/// The digits are derived from the integer milliseconds, so no float rounding
+/// artifacts can occur. Trailing zeros and a trailing dot are omitted.
+pub fn format_time(ts: UtcTimeStamp) -> String {
+    let (secs, ms) = ts.split_seconds();
+    let mut out = String::new();
+    if secs < 0 && ms != 0 {
+        // Negative times are truncated towards zero, e.g. -1.5 for -1500 ms.
+        let ms = ts.as_milliseconds().unsigned_abs();
+        write!(out, "-{}.{:03}", ms / 1000, ms % 1000).unwrap();
+    } else if ms != 0 {
+        write!(out, "{}.{:03}", secs, ms).unwrap();
+    } else {
+        write!(out, "{}", secs).unwrap();
+    }
+
+    if ms != 0 {
+        out.truncate(out.trim_end_matches('0').len());
+    }
+    out
+}
+
+/// Parse a time parameter of the HTTP API, float seconds or RFC 3339.
+///
+/// Float seconds are rounded to the nearest millisecond.
+pub fn parse_time(s: &str) -> Result<UtcTimeStamp, ParseError> {
+    match s.parse::<f64>() {
+        Ok(secs) if secs.is_finite() => {
+            UtcTimeStamp::from_seconds_f64(secs).ok_or(ParseError::OutOfRange)
+        }
+        Ok(_) => Err(ParseError::Invalid),
+        Err(_) => UtcTimeStamp::parse_rfc3339(s),
+    }
+}
+
+/// Align a timestamp down to a multiple of `step`, counted from the epoch.
+///
+/// Unlike [`UtcTimeStamp::align_to`], timestamps before the epoch are also
+/// rounded towards the past.
+pub fn align_to_step(ts: UtcTimeStamp, step: TimeDelta) -> UtcTimeStamp {
+    assert!(step.is_positive(), "step must be positive");
+    bucket_start(ts, UtcTimeStamp::zero(), step)
+}
+
+/// The evaluation times of a range query with both ends aligned to `step`.
+///
+/// Includes the aligned end time, like the range query API does.
+pub fn query_range(start: UtcTimeStamp, end: UtcTimeStamp, step: TimeDelta) -> TimeRange {
+    TimeRange::right_closed(align_to_step(start, step), align_to_step(end, step), step)
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn api_times() {
+        let ms = UtcTimeStamp::from_milliseconds;
+        assert_eq!(format_time(ms(1_600_000_000_250)), "1600000000.25");
+        assert_eq!(format_time(ms(1_600_000_000_001)), "1600000000.001");
+        assert_eq!(format_time(ms(0)), "0");
+        assert_eq!(format_time(ms(-1_500)), "-1.5");
+        assert_eq!(format_time(ms(-500)), "-0.5");
+        assert_eq!(format_time(ms(-2_000)), "-2");
+        assert_eq!(format_time(ms(i64::MIN)), "-9223372036854775.808");
+
+        assert_eq!(parse_time("1600000000.25"), Ok(ms(1_600_000_000_250)));
+        assert_eq!(parse_time("-0.5"), Ok(ms(-500)));
+        assert_eq!(
+            parse_time("2020-09-13T12:26:40.25Z"),
+            Ok(ms(1_600_000_000_250))
+        );
+        assert_eq!(parse_time("1e300"), Err(ParseError::OutOfRange));
+        assert_eq!(parse_time("NaN"), Err(ParseError::Invalid));
+        assert_eq!(parse_time("yesterday"), Err(ParseError::Invalid));
+
+        assert_eq!(to_sample_timestamp(ms(5)), 5);
+        assert_eq!(from_sample_timestamp(5), ms(5));
+    }
+
+    #[test]
+    fn step_alignment() {
+        let ms = UtcTimeStamp::from_milliseconds;
+        let step = TimeDelta::from_milliseconds(10);
+
+        assert_eq!(align_to_step(ms(25), step), ms(20));
+        assert_eq!(align_to_step(ms(20), step), ms(20));
+        assert_eq!(align_to_step(ms(-5), step), ms(-10));
+
+        let times: Vec<_> = query_range(ms(-5), ms(29), step).collect();
+        assert_eq!(times, [ms(-10), ms(0), ms(10), ms(20)]);
+        assert_eq!(query_range(ms(21), ms(25), step).count(), 1);
+    }
+}
@@ -0,0 +1,154 @@
+//! Conversions from and to protobuf's well-known `Timestamp` and `Duration` types.
+
+use crate::{OutOfRangeError, TimeDelta, UtcTimeStamp};
+use core::convert::TryFrom;
+use prost_types::{Duration, Timestamp};
+
+/// Seconds of `0001-01-01T00:00:00Z`, the earliest valid protobuf timestamp.
+const MIN_TIMESTAMP_SECS: i64 = -62_135_596_800;
+/// Seconds of `9999-12-31T23:59:59Z`, the latest valid protobuf timestamp.
+const MAX_TIMESTAMP_SECS: i64 = 253_402_300_799;
+/// Seconds of the longest valid protobuf duration, about 10,000 years.
+const MAX_DURATION_SECS: i64 = 315_576_000_000;
+const NANOS_PER_SEC: i32 = 1_000_000_000;
+const NANOS_PER_MS: i32 = 1_000_000;
+
+/// Convert a protobuf timestamp, rounding towards the past to whole milliseconds.
+///
+/// Fails for timestamps violating the protobuf specification, i.e. outside of
+/// the years 1 to 9999 or with nanoseconds outside of `0..1_000_000_000`.
+impl TryFrom<Timestamp> for UtcTimeStamp {
+    type Error = OutOfRangeError;
+
+    fn try_from(other: Timestamp) -> Result<Self, Self::Error> {
+        if !(MIN_TIMESTAMP_SECS..=MAX_TIMESTAMP_SECS).contains(&other.seconds)
+            || !(0..NANOS_PER_SEC).contains(&other.nanos)
+        {
+            return Err(OutOfRangeError);
+        }
+
+        let ms = other.seconds * 1000 + i64::from(other.nanos / NANOS_PER_MS);
+        Ok(UtcTimeStamp::from_milliseconds(ms))
+    }
+}
+
+/// Convert to a protobuf timestamp.
+///
+/// Fails for timestamps outside of the years 1 to 9999.
+impl TryFrom<UtcTimeStamp> for Timestamp {
+    type Error = OutOfRangeError;
+
+    fn try_from(other: UtcTimeStamp) -> Result<Self, Self::Error> {
+        let (seconds, ms) = other.split_seconds();
+        if !(MIN_TIMESTAMP_SECS..=MAX_TIMESTAMP_SECS).contains(&seconds) {
+            return Err(OutOfRangeError);
+        }
+
+        Ok(Timestamp {
+            seconds,
+            nanos: ms as i32 * NANOS_PER_MS,
+        })
+    }
+}
+
+/// Convert a protobuf duration, truncating sub-millisecond precision.
+///
+/// Fails for durations violating the protobuf specification, i.e. longer than
+/// about 10,000 years or with nanoseconds out of range or of the wrong sign.
+impl TryFrom<Duration> for TimeDelta {
+    type Error = OutOfRangeError;
+
+    fn try_from(other: Duration) -> Result<Self, Self::Error> {
+        let signs_match =
+            other.seconds == 0 || other.nanos == 0 || (other.seconds < 0) == (other.nanos < 0);
+        if !(-MAX_DURATION_SECS..=MAX_DURATION_SECS).contains(&other.seconds)
+            || other.nanos.unsigned_abs() >= NANOS_PER_SEC as u32
+            || !signs_match
+        {
+            return Err(OutOfRangeError);
+        }
+
+        let ms = other.seconds * 1000 + i64::from(other.nanos / NANOS_PER_MS);
+        Ok(TimeDelta::from_milliseconds(ms))
+    }
+}
+
+/// Convert to a protobuf duration.
+///
+/// Fails for deltas longer than about 10,000 years.
+impl TryFrom<TimeDelta> for Duration {
+    type Error = OutOfRangeError;
+
+    fn try_from(other: TimeDelta) -> Result<Self, Self::Error> {
+        let seconds = other.as_seconds();
+        if !(-MAX_DURATION_SECS..=MAX_DURATION_SECS).contains(&seconds) {
+            return Err(OutOfRangeError);
+        }
+
+        Ok(Duration {
+            seconds,
+            nanos: other.subsec_milliseconds() as i32 * NANOS_PER_MS,
+        })
+    }
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use core::convert::TryFrom;
+    use prost_types::{Duration, Timestamp};
+
+    #[test]
+    fn protobuf() {
+        let pb = |seconds, nanos| Timestamp { seconds, nanos };
+        let ts = UtcTimeStamp::from_milliseconds(-1_250);
+
+        assert_eq!(Timestamp::try_from(ts), Ok(pb(-2, 750_000_000)));
+        assert_eq!(UtcTimeStamp::try_from(pb(-2, 750_999_999)), Ok(ts));
+        assert_eq!(
+            UtcTimeStamp::try_from(pb(253_402_300_799, 999_999_999)),
+            Ok(ts!("9999-12-31T23:59:59.999Z"))
+        );
+        assert_eq!(
+            Timestamp::try_from(ts!("0001-01-01T00:00:00Z")),
+            Ok(pb(-62_135_596_800, 0))
+        );
+        assert_eq!(
+            Timestamp::try_from(ts!("0000-12-31T23:59:59.999Z")),
+            Err(OutOfRangeError)
+        );
+        assert_eq!(UtcTimeStamp::try_from(pb(0, -1)), Err(OutOfRangeError));
+        assert_eq!(
+            UtcTimeStamp::try_from(pb(i64::MAX, 0)),
+            Err(OutOfRangeError)
+        );
+
+        let pb = |seconds, nanos| Duration { seconds, nanos };
+        let delta = TimeDelta::from_milliseconds(-1_250);
+
+        assert_eq!(Duration::try_from(delta), Ok(pb(-1, -250_000_000)));
+        assert_eq!(TimeDelta::try_from(pb(-1, -250_999_999)), Ok(delta));
+        assert_eq!(
+            TimeDelta::try_from(pb(0, 1_999_999)),
+            Ok(TimeDelta::from_milliseconds(1))
+        );
+        assert_eq!(TimeDelta::try_from(pb(-1, 5)), Err(OutOfRangeError));
+        assert_eq!(
+            TimeDelta::try_from(pb(0, 1_000_000_000)),
+            Err(OutOfRangeError)
+        );
+        assert_eq!(TimeDelta::try_from(pb(0, i32::MIN)), Err(OutOfRangeError));
+        assert_eq!(
+            TimeDelta::try_from(pb(315_576_000_001, 0)),
+            Err(OutOfRangeError)
+        );
+        assert_eq!(
+            Duration::try_from(TimeDelta::from_milliseconds(i64::MIN)),
+            Err(OutOfRangeError)
+        );
+    }
+}
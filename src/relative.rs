@@ -0,0 +1,217 @@
+//! Relative time expressions in the style of Grafana and Elasticsearch.
+
+use crate::{
+    calendar::{civil_from_days, days_from_civil, days_in_month, split_day, MS_PER_DAY},
+    ParseError, UtcTimeStamp,
+};
+
+#[derive(Copy, Clone)]
+pub(crate) enum Unit {
+    Year,
+    Month,
+    Week,
+    Day,
+    Hour,
+    Minute,
+    Second,
+}
+
+impl Unit {
+    fn from_byte(x: u8) -> Option<Unit> {
+        Some(match x {
+            b'y' => Unit::Year,
+            b'M' => Unit::Month,
+            b'w' => Unit::Week,
+            b'd' => Unit::Day,
+            b'h' | b'H' => Unit::Hour,
+            b'm' => Unit::Minute,
+            b's' => Unit::Second,
+            _ => return None,
+        })
+    }
+
+    /// Length of fixed size units in milliseconds.
+    fn millis(self) -> Option<i64> {
+        match self {
+            Unit::Year | Unit::Month => None,
+            Unit::Week => Some(7 * MS_PER_DAY),
+            Unit::Day => Some(MS_PER_DAY),
+            Unit::Hour => Some(3_600_000),
+            Unit::Minute => Some(60_000),
+            Unit::Second => Some(1000),
+        }
+    }
+}
+
+/// Add calendar months, clamping the day to the length of the target month.
+fn add_months(ts: UtcTimeStamp, months: i64) -> Option<UtcTimeStamp> {
+    let (days, ms) = split_day(ts);
+    let (year, month, day) = civil_from_days(days);
+    let total = (year * 12 + month as i64 - 1).checked_add(months)?;
+    let (year, month) = (total.div_euclid(12), total.rem_euclid(12) as u32 + 1);
+    // Far beyond the range of millisecond timestamps, guards the day arithmetic.
+    if year.abs() > 1_000_000_000 {
+        return None;
+    }
+    let day = day.min(days_in_month(year, month));
+    let days = days_from_civil(year, month, day);
+    Some(UtcTimeStamp::from_milliseconds(
+        days.checked_mul(MS_PER_DAY)?.checked_add(ms)?,
+    ))
+}
+
+pub(crate) fn add(ts: UtcTimeStamp, amount: i64, unit: Unit) -> Option<UtcTimeStamp> {
+    match unit.millis() {
+        Some(ms) => Some(UtcTimeStamp::from_milliseconds(
+            ts.as_milliseconds().checked_add(amount.checked_mul(ms)?)?,
+        )),
+        None => add_months(ts, match unit {
+            Unit::Year => amount.checked_mul(12)?,
+            _ => amount,
+        }),
+    }
+}
+
+/// Round down to the start of the unit, `None` if out of range. Weeks start on monday.
+fn round_down(ts: UtcTimeStamp, unit: Unit) -> Option<UtcTimeStamp> {
+    let (days, _) = split_day(ts);
+    let (year, month, _) = civil_from_days(days);
+    let days = match unit {
+        Unit::Year => days_from_civil(year, 1, 1),
+        Unit::Month => days_from_civil(year, month, 1),
+        // 1970-01-01 was a thursday.
+        Unit::Week => days - (days + 3).rem_euclid(7),
+        _ => {
+            let ms = unit.millis().expect("fixed size unit");
+            let floor = ts.as_milliseconds().div_euclid(ms).checked_mul(ms)?;
+            return Some(UtcTimeStamp::from_milliseconds(floor));
+        }
+    };
+    days.checked_mul(MS_PER_DAY)
+        .map(UtcTimeStamp::from_milliseconds)
+}
+
+impl UtcTimeStamp {
+    /// Evaluate a relative time expression such as `now-1h` or `now-7d/d`.
+    ///
+    /// An expression starts with `now`, which is replaced by the given
+    /// timestamp, or with an RFC 3339 timestamp followed by `||`. It is followed
+    /// by any number of operations, applied from left to right:
+    ///
+    /// - `+<n><unit>` and `-<n><unit>` add or subtract, `n` defaults to 1
+    /// - `/<unit>` rounds down to the start of the unit
+    ///
+    /// Units are `y` (years), `M` (months), `w` (weeks starting on monday),
+    /// `d`, `h` (or `H`), `m` and `s`. Adding months or years clamps the day to
+    /// the length of the resulting month.
+    ///
+    /// Examples:
+    ///
+    /// ```
+    /// use utctimestamp::{ts, UtcTimeStamp};
+    ///
+    /// let now = ts!("2024-03-31T15:20:00Z");
+    /// let eval = |expr| UtcTimeStamp::parse_relative(expr, now).unwrap();
+    ///
+    /// assert_eq!(eval("now-1h"), ts!("2024-03-31T14:20:00Z"));
+    /// assert_eq!(eval("now/d"), ts!("2024-03-31T00:00:00Z"));
+    /// assert_eq!(eval("now-7d/d"), ts!("2024-03-24T00:00:00Z"));
+    /// assert_eq!(eval("now-1M"), ts!("2024-02-29T15:20:00Z"));
+    /// assert_eq!(eval("2024-01-01T00:00:00Z||+1w"), ts!("2024-01-08T00:00:00Z"));
+    /// ```
+    pub fn parse_relative(expr: &str, now: UtcTimeStamp) -> Result<Self, ParseError> {
+        let (mut ts, ops) = if let Some(ops) = expr.strip_prefix("now") {
+            (now, ops)
+        } else if let Some(idx) = expr.find("||") {
+            (expr[..idx].parse()?, &expr[idx + 2..])
+        } else {
+            return Err(ParseError::Invalid);
+        };
+
+        let ops = ops.as_bytes();
+        let mut i = 0;
+        while i < ops.len() {
+            let op = ops[i];
+            i += 1;
+
+            let start = i;
+            while i < ops.len() && ops[i].is_ascii_digit() {
+                i += 1;
+            }
+            let amount = match (op, i - start) {
+                (b'/', 0) => None,
+                (b'+', 0) | (b'-', 0) => Some(1),
+                (b'+', _) | (b'-', _) => {
+                    let digits = core::str::from_utf8(&ops[start..i]).expect("digits are ASCII");
+                    Some(digits.parse::<i64>().map_err(|_| ParseError::OutOfRange)?)
+                }
+                _ => return Err(ParseError::Invalid),
+            };
+
+            let unit = ops
+                .get(i)
+                .and_then(|&x| Unit::from_byte(x))
+                .ok_or(ParseError::Invalid)?;
+            i += 1;
+
+            ts = match amount {
+                Some(amount) => {
+                    let amount = if op == b'-' { -amount } else { amount };
+                    add(ts, amount, unit).ok_or(ParseError::OutOfRange)?
+                }
+                None => round_down(ts, unit).ok_or(ParseError::OutOfRange)?,
+            };
+        }
+
+        Ok(ts)
+    }
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn relative() {
+        let now = ts!("2021-01-31T10:11:12.345Z");
+        let eval = |expr| UtcTimeStamp::parse_relative(expr, now);
+
+        assert_eq!(eval("now"), Ok(now));
+        assert_eq!(eval("now+1M"), Ok(ts!("2021-02-28T10:11:12.345Z")));
+        assert_eq!(eval("now-1y/y"), Ok(ts!("2020-01-01T00:00:00Z")));
+        assert_eq!(eval("now/M+1M-1d"), Ok(ts!("2021-01-31T00:00:00Z")));
+        assert_eq!(eval("now/w"), Ok(ts!("2021-01-25T00:00:00Z")));
+        assert_eq!(eval("now/s"), Ok(ts!("2021-01-31T10:11:12Z")));
+        assert_eq!(eval("now-h"), Ok(ts!("2021-01-31T09:11:12.345Z")));
+        assert_eq!(eval("now+90m/H"), Ok(ts!("2021-01-31T11:00:00Z")));
+        assert_eq!(
+            eval("1969-12-31T23:59:59Z||/d"),
+            Ok(ts!("1969-12-31T00:00:00Z"))
+        );
+
+        assert_eq!(eval("today"), Err(ParseError::Invalid));
+        assert_eq!(eval("now-1"), Err(ParseError::Invalid));
+        assert_eq!(eval("now-1x"), Err(ParseError::Invalid));
+        assert_eq!(eval("now/1d"), Err(ParseError::Invalid));
+        assert_eq!(eval("now*2d"), Err(ParseError::Invalid));
+        assert_eq!(eval("garbage||+1d"), Err(ParseError::Invalid));
+        assert_eq!(
+            eval("now+99999999999999999999d"),
+            Err(ParseError::OutOfRange)
+        );
+        assert_eq!(eval("now+9999999999999y"), Err(ParseError::OutOfRange));
+        assert_eq!(eval("now+700000000000000000M"), Err(ParseError::OutOfRange));
+
+        let min = UtcTimeStamp::from_milliseconds(i64::MIN);
+        for expr in &["now/y", "now/M", "now/w", "now/d", "now/s"] {
+            assert_eq!(
+                UtcTimeStamp::parse_relative(expr, min),
+                Err(ParseError::OutOfRange)
+            );
+        }
+    }
+}
@@ -0,0 +1,530 @@
+//! Grouping of timestamped values into aligned buckets.
+
+use crate::{TimeDelta, UtcTimeStamp};
+use core::iter::Peekable;
+
+/// Align a timestamp to the start of its bucket, rounding towards negative infinity.
+pub(crate) const fn bucket_start(
+    ts: UtcTimeStamp,
+    anchor: UtcTimeStamp,
+    freq: TimeDelta,
+) -> UtcTimeStamp {
+    let (ts, anchor, freq) = (
+        ts.as_milliseconds(),
+        anchor.as_milliseconds(),
+        freq.as_milliseconds(),
+    );
+    UtcTimeStamp::from_milliseconds((ts - anchor).div_euclid(freq) * freq + anchor)
+}
+
+// ============================================================================================== //
+// [Resampler]                                                                                    //
+// ============================================================================================== //
+
+/// Groups a stream of `(UtcTimeStamp, T)` pairs into buckets aligned to a frequency.
+///
+/// The input is expected to be sorted by timestamp: each run of consecutive
+/// values falling into the same bucket is emitted as one group. Buckets without
+/// values are skipped. Timestamps before the anchor are assigned to the bucket
+/// they fall into, not the one closer to the anchor.
+///
+/// Examples:
+///
+/// ```
+/// use utctimestamp::{Resampler, TimeDelta, UtcTimeStamp};
+///
+/// let ticks = vec![
+///     (UtcTimeStamp::from_seconds(0), 1),
+///     (UtcTimeStamp::from_seconds(59), 2),
+///     (UtcTimeStamp::from_seconds(61), 3),
+/// ];
+///
+/// let minutes: Vec<_> = Resampler::new(ticks, TimeDelta::from_minutes(1)).collect();
+/// assert_eq!(minutes, vec![
+///     (UtcTimeStamp::from_seconds(0), vec![1, 2]),
+///     (UtcTimeStamp::from_seconds(60), vec![3]),
+/// ]);
+/// ```
+pub struct Resampler<I: Iterator> {
+    iter: Peekable<I>,
+    anchor: UtcTimeStamp,
+    freq: TimeDelta,
+}
+
+impl<I, T> Resampler<I>
+where
+    I: Iterator<Item = (UtcTimeStamp, T)>,
+{
+    /// Create a resampler with buckets aligned to `1970-01-01 00:00:00 UTC`.
+    pub fn new(iter: impl IntoIterator<IntoIter = I>, freq: TimeDelta) -> Self {
+        Resampler::anchored(iter, UtcTimeStamp::zero(), freq)
+    }
+
+    /// Create a resampler with buckets aligned to a custom anchor.
+    pub fn anchored(
+        iter: impl IntoIterator<IntoIter = I>,
+        anchor: UtcTimeStamp,
+        freq: TimeDelta,
+    ) -> Self {
+        assert!(freq.is_positive(), "resampling frequency must be positive");
+        Resampler {
+            iter: iter.into_iter().peekable(),
+            anchor,
+            freq,
+        }
+    }
+
+    /// Fold the values of each bucket instead of collecting them.
+    ///
+    /// Every bucket starts out with a clone of `init`.
+    pub fn aggregate<B, F>(self, init: B, f: F) -> Aggregate<I, B, F>
+    where
+        B: Clone,
+        F: FnMut(B, T) -> B,
+    {
+        Aggregate {
+            inner: self,
+            init,
+            f,
+        }
+    }
+
+    /// Reduce the values of each bucket using an [`Aggregator`].
+    ///
+    /// Every bucket starts out with a clone of `agg`.
+    ///
+    /// ```
+    /// use utctimestamp::{agg, Resampler, TimeDelta, UtcTimeStamp};
+    ///
+    /// let data = vec![
+    ///     (UtcTimeStamp::from_seconds(0), 1.0),
+    ///     (UtcTimeStamp::from_seconds(1), 2.0),
+    ///     (UtcTimeStamp::from_seconds(7), 4.0),
+    /// ];
+    /// let means: Vec<_> = Resampler::new(data, TimeDelta::from_seconds(5))
+    ///     .aggregate_with(agg::Mean::default())
+    ///     .collect();
+    ///
+    /// assert_eq!(means, vec![
+    ///     (UtcTimeStamp::from_seconds(0), 1.5),
+    ///     (UtcTimeStamp::from_seconds(5), 4.0),
+    /// ]);
+    /// ```
+    pub fn aggregate_with<A>(self, agg: A) -> AggregateWith<I, A>
+    where
+        A: Aggregator<T>,
+    {
+        AggregateWith { inner: self, agg }
+    }
+
+    /// Compute open, high, low and close values for each bucket.
+    pub fn ohlc(self) -> AggregateWith<I, agg::Ohlc<T>>
+    where
+        T: Clone + PartialOrd,
+    {
+        self.aggregate_with(agg::Ohlc::default())
+    }
+
+    /// Feed all values of the next bucket into `f`, returning the bucket's start.
+    fn next_bucket(&mut self, mut f: impl FnMut(T)) -> Option<UtcTimeStamp> {
+        let (ts, value) = self.iter.next()?;
+        let bucket = bucket_start(ts, self.anchor, self.freq);
+        f(value);
+
+        while let Some((ts, _)) = self.iter.peek() {
+            if bucket_start(*ts, self.anchor, self.freq) != bucket {
+                break;
+            }
+            f(self.iter.next()?.1);
+        }
+
+        Some(bucket)
+    }
+}
+
+impl<I, T> Iterator for Resampler<I>
+where
+    I: Iterator<Item = (UtcTimeStamp, T)>,
+{
+    type Item = (UtcTimeStamp, Vec<T>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut values = Vec::new();
+        let bucket = self.next_bucket(|x| values.push(x))?;
+        Some((bucket, values))
+    }
+}
+
+/// Iterator folding the values of each bucket, see [`Resampler::aggregate`].
+pub struct Aggregate<I: Iterator, B, F> {
+    inner: Resampler<I>,
+    init: B,
+    f: F,
+}
+
+impl<I, T, B, F> Iterator for Aggregate<I, B, F>
+where
+    I: Iterator<Item = (UtcTimeStamp, T)>,
+    B: Clone,
+    F: FnMut(B, T) -> B,
+{
+    type Item = (UtcTimeStamp, B);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let f = &mut self.f;
+        let mut acc = Some(self.init.clone());
+        let bucket = self
+            .inner
+            .next_bucket(|x| acc = acc.take().map(|acc| f(acc, x)))?;
+        Some((bucket, acc?))
+    }
+}
+
+/// Iterator reducing the values of each bucket, see [`Resampler::aggregate_with`].
+pub struct AggregateWith<I: Iterator, A> {
+    inner: Resampler<I>,
+    agg: A,
+}
+
+impl<I, T, A> Iterator for AggregateWith<I, A>
+where
+    I: Iterator<Item = (UtcTimeStamp, T)>,
+    A: Aggregator<T>,
+{
+    type Item = (UtcTimeStamp, A::Output);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut agg = self.agg.clone();
+        let bucket = self.inner.next_bucket(|x| agg.update(x))?;
+        Some((bucket, agg.finish()))
+    }
+}
+
+// ============================================================================================== //
+// [Aggregators]                                                                                  //
+// ============================================================================================== //
+
+/// Reduces the values of a bucket into a single output.
+///
+/// `update` is guaranteed to be called at least once before `finish`.
+pub trait Aggregator<T>: Clone {
+    type Output;
+
+    fn update(&mut self, value: T);
+
+    fn finish(self) -> Self::Output;
+}
+
+/// Open, high, low and close values of a bucket.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde-support",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct OhlcBar<T> {
+    pub open: T,
+    pub high: T,
+    pub low: T,
+    pub close: T,
+}
+
+/// Built-in aggregators for use with [`Resampler::aggregate_with`].
+pub mod agg {
+    use super::{Aggregator, OhlcBar};
+    use core::ops;
+
+    const EMPTY: &str = "aggregator finished without values";
+
+    /// The first value of each bucket.
+    #[derive(Copy, Clone, Debug)]
+    pub struct First<T>(Option<T>);
+
+    /// The last value of each bucket.
+    #[derive(Copy, Clone, Debug)]
+    pub struct Last<T>(Option<T>);
+
+    /// The smallest value of each bucket, the first one on ties.
+    #[derive(Copy, Clone, Debug)]
+    pub struct Min<T>(Option<T>);
+
+    /// The largest value of each bucket, the first one on ties.
+    #[derive(Copy, Clone, Debug)]
+    pub struct Max<T>(Option<T>);
+
+    /// The sum of all values in each bucket.
+    #[derive(Copy, Clone, Debug)]
+    pub struct Sum<T>(Option<T>);
+
+    /// The number of values in each bucket.
+    #[derive(Copy, Clone, Debug, Default)]
+    pub struct Count(u64);
+
+    /// The arithmetic mean of each bucket.
+    #[derive(Copy, Clone, Debug, Default)]
+    pub struct Mean {
+        sum: f64,
+        count: u64,
+    }
+
+    /// Open, high, low and close values of each bucket.
+    #[derive(Copy, Clone, Debug)]
+    pub struct Ohlc<T>(Option<OhlcBar<T>>);
+
+    macro_rules! impl_default {
+        ($($name:ident),*) => {$(
+            impl<T> Default for $name<T> {
+                fn default() -> Self {
+                    $name(None)
+                }
+            }
+        )*};
+    }
+
+    impl_default!(First, Last, Min, Max, Sum, Ohlc);
+
+    impl<T: Clone> Aggregator<T> for First<T> {
+        type Output = T;
+
+        fn update(&mut self, value: T) {
+            if self.0.is_none() {
+                self.0 = Some(value);
+            }
+        }
+
+        fn finish(self) -> T {
+            self.0.expect(EMPTY)
+        }
+    }
+
+    impl<T: Clone> Aggregator<T> for Last<T> {
+        type Output = T;
+
+        fn update(&mut self, value: T) {
+            self.0 = Some(value);
+        }
+
+        fn finish(self) -> T {
+            self.0.expect(EMPTY)
+        }
+    }
+
+    impl<T: Clone + PartialOrd> Aggregator<T> for Min<T> {
+        type Output = T;
+
+        fn update(&mut self, value: T) {
+            match &self.0 {
+                Some(cur) if *cur <= value => {}
+                _ => self.0 = Some(value),
+            }
+        }
+
+        fn finish(self) -> T {
+            self.0.expect(EMPTY)
+        }
+    }
+
+    impl<T: Clone + PartialOrd> Aggregator<T> for Max<T> {
+        type Output = T;
+
+        fn update(&mut self, value: T) {
+            match &self.0 {
+                Some(cur) if *cur >= value => {}
+                _ => self.0 = Some(value),
+            }
+        }
+
+        fn finish(self) -> T {
+            self.0.expect(EMPTY)
+        }
+    }
+
+    impl<T: Clone + ops::Add<Output = T>> Aggregator<T> for Sum<T> {
+        type Output = T;
+
+        fn update(&mut self, value: T) {
+            self.0 = Some(match self.0.take() {
+                Some(cur) => cur + value,
+                None => value,
+            });
+        }
+
+        fn finish(self) -> T {
+            self.0.expect(EMPTY)
+        }
+    }
+
+    impl<T> Aggregator<T> for Count {
+        type Output = u64;
+
+        fn update(&mut self, _: T) {
+            self.0 += 1;
+        }
+
+        fn finish(self) -> u64 {
+            self.0
+        }
+    }
+
+    impl<T: Into<f64>> Aggregator<T> for Mean {
+        type Output = f64;
+
+        fn update(&mut self, value: T) {
+            self.sum += value.into();
+            self.count += 1;
+        }
+
+        fn finish(self) -> f64 {
+            self.sum / self.count as f64
+        }
+    }
+
+    impl<T: Clone + PartialOrd> Aggregator<T> for Ohlc<T> {
+        type Output = OhlcBar<T>;
+
+        fn update(&mut self, value: T) {
+            match &mut self.0 {
+                None => {
+                    self.0 = Some(OhlcBar {
+                        open: value.clone(),
+                        high: value.clone(),
+                        low: value.clone(),
+                        close: value,
+                    })
+                }
+                Some(bar) => {
+                    if value > bar.high {
+                        bar.high = value.clone();
+                    }
+                    if value < bar.low {
+                        bar.low = value.clone();
+                    }
+                    bar.close = value;
+                }
+            }
+        }
+
+        fn finish(self) -> OhlcBar<T> {
+            self.0.expect(EMPTY)
+        }
+    }
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn ts(secs: i64) -> UtcTimeStamp {
+        UtcTimeStamp::from_seconds(secs)
+    }
+
+    #[test]
+    fn resample_groups() {
+        let data = vec![
+            (ts(-1), 'a'),
+            (ts(0), 'b'),
+            (ts(5), 'c'),
+            (ts(25), 'd'),
+            (ts(29), 'e'),
+        ];
+        let buckets: Vec<_> = Resampler::new(data.clone(), TimeDelta::from_seconds(10)).collect();
+        assert_eq!(buckets, vec![
+            (ts(-10), vec!['a']),
+            (ts(0), vec!['b', 'c']),
+            (ts(20), vec!['d', 'e']),
+        ]);
+
+        let buckets: Vec<_> =
+            Resampler::anchored(data, ts(3), TimeDelta::from_seconds(10)).collect();
+        assert_eq!(buckets, vec![
+            (ts(-7), vec!['a', 'b']),
+            (ts(3), vec!['c']),
+            (ts(23), vec!['d', 'e']),
+        ]);
+
+        let empty: Vec<(UtcTimeStamp, ())> = vec![];
+        assert_eq!(Resampler::new(empty, TimeDelta::from_seconds(1)).count(), 0);
+    }
+
+    #[test]
+    fn resample_aggregate() {
+        let data = (0..10).map(|x| (ts(x * 30), x));
+        let sums: Vec<_> = Resampler::new(data, TimeDelta::from_minutes(1))
+            .aggregate(0, |acc, x| acc + x)
+            .collect();
+        assert_eq!(sums, vec![
+            (ts(0), 1),
+            (ts(60), 5),
+            (ts(120), 9),
+            (ts(180), 13),
+            (ts(240), 17)
+        ]);
+    }
+
+    #[test]
+    fn builtin_aggregators() {
+        let data = vec![
+            (ts(0), 3),
+            (ts(1), 1),
+            (ts(2), 4),
+            (ts(3), 1),
+            (ts(4), 5),
+            (ts(5), 9),
+        ];
+        let freq = TimeDelta::from_seconds(5);
+        fn run<A: Aggregator<i32, Output = i32>>(data: &[(UtcTimeStamp, i32)], agg: A) -> Vec<i32> {
+            let freq = TimeDelta::from_seconds(5);
+            Resampler::new(data.to_vec(), freq)
+                .aggregate_with(agg)
+                .map(|x| x.1)
+                .collect()
+        }
+
+        assert_eq!(run(&data, agg::First::default()), vec![3, 9]);
+        assert_eq!(run(&data, agg::Last::default()), vec![5, 9]);
+        assert_eq!(run(&data, agg::Min::default()), vec![1, 9]);
+        assert_eq!(run(&data, agg::Max::default()), vec![5, 9]);
+        assert_eq!(run(&data, agg::Sum::default()), vec![14, 9]);
+
+        let counts: Vec<_> = Resampler::new(data.clone(), freq)
+            .aggregate_with(agg::Count::default())
+            .collect();
+        assert_eq!(counts, vec![(ts(0), 5), (ts(5), 1)]);
+
+        let means: Vec<_> = Resampler::new(data.clone(), freq)
+            .aggregate_with(agg::Mean::default())
+            .collect();
+        assert_eq!(means, vec![(ts(0), 2.8), (ts(5), 9.0)]);
+    }
+
+    #[test]
+    fn ohlc() {
+        let ticks = vec![
+            (ts(0), 10.0),
+            (ts(20), 12.5),
+            (ts(30), 9.5),
+            (ts(59), 11.0),
+            (ts(61), 11.5),
+        ];
+        let bars: Vec<_> = Resampler::new(ticks, TimeDelta::from_minutes(1))
+            .ohlc()
+            .collect();
+        assert_eq!(bars, vec![
+            (ts(0), OhlcBar {
+                open: 10.0,
+                high: 12.5,
+                low: 9.5,
+                close: 11.0
+            }),
+            (ts(60), OhlcBar {
+                open: 11.5,
+                high: 11.5,
+                low: 11.5,
+                close: 11.5
+            }),
+        ]);
+    }
+}
@@ -0,0 +1,566 @@
+//! Calendar based recurrence rules.
+//!
+//! Implements the subset of RFC 5545 `RRULE`s consisting of `FREQ`, `INTERVAL`,
+//! `BYDAY`, `COUNT` and `UNTIL`. All calendar math is performed in UTC.
+
+use crate::UtcTimeStamp;
+use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime, Weekday};
+use core::{convert::TryFrom, fmt, str::FromStr};
+
+// ============================================================================================== //
+// [Rule definition]                                                                              //
+// ============================================================================================== //
+
+/// Base frequency of a recurrence rule.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Frequency {
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+/// A `BYDAY` entry, e.g. `FR` (every friday) or `3FR` (third friday).
+///
+/// The ordinal counts from the end of the month (or year) when negative.
+/// It is only meaningful for monthly and yearly rules.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ByDay {
+    pub ordinal: Option<i8>,
+    pub weekday: Weekday,
+}
+
+impl ByDay {
+    /// Every occurrence of the given weekday.
+    pub const fn every(weekday: Weekday) -> Self {
+        ByDay {
+            ordinal: None,
+            weekday,
+        }
+    }
+
+    /// The n-th occurrence of the given weekday, counting from the end if negative.
+    pub const fn nth(ordinal: i8, weekday: Weekday) -> Self {
+        ByDay {
+            ordinal: Some(ordinal),
+            weekday,
+        }
+    }
+}
+
+/// A recurrence rule, such as "the third friday of every month".
+///
+/// The time of day of all occurrences is taken from the start timestamp passed
+/// to [`RecurrenceRule::iter`].
+///
+/// Examples:
+///
+/// ```
+/// use utctimestamp::RecurrenceRule;
+/// use chrono::{offset::TimeZone, Utc};
+///
+/// let rule: RecurrenceRule = "FREQ=MONTHLY;BYDAY=3FR;COUNT=3".parse().unwrap();
+/// let start = Utc.with_ymd_and_hms(2024, 1, 1, 16, 0, 0).unwrap();
+/// let expiries: Vec<_> = rule.iter(start).collect();
+///
+/// assert_eq!(expiries, vec![
+///     Utc.with_ymd_and_hms(2024, 1, 19, 16, 0, 0).unwrap(),
+///     Utc.with_ymd_and_hms(2024, 2, 16, 16, 0, 0).unwrap(),
+///     Utc.with_ymd_and_hms(2024, 3, 15, 16, 0, 0).unwrap(),
+/// ]);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RecurrenceRule {
+    pub freq: Frequency,
+    /// Number of periods between two recurrences. `0` is treated like `1`.
+    pub interval: u32,
+    pub by_day: Vec<ByDay>,
+    /// Maximum number of occurrences yielded.
+    pub count: Option<u32>,
+    /// Last permitted occurrence, inclusive.
+    pub until: Option<UtcTimeStamp>,
+}
+
+impl RecurrenceRule {
+    /// Create a rule recurring every period of the given frequency.
+    pub fn new(freq: Frequency) -> Self {
+        RecurrenceRule {
+            freq,
+            interval: 1,
+            by_day: Vec::new(),
+            count: None,
+            until: None,
+        }
+    }
+
+    /// Iterate over all occurrences at or after `start`.
+    ///
+    /// Unlike RFC 5545 `DTSTART`, `start` itself is only yielded when it
+    /// matches the rule.
+    pub fn iter(&self, start: impl Into<UtcTimeStamp>) -> Recurrences {
+        let start = chrono::DateTime::<chrono::Utc>::from(start.into()).naive_utc();
+        Recurrences {
+            rule: self.clone(),
+            start,
+            period: 0,
+            pending: Vec::new(),
+            emitted: 0,
+            empty_periods: 0,
+            done: false,
+        }
+    }
+
+    /// Calculate the first day of the `n`-th period, `None` if out of range.
+    fn period_start(&self, start: NaiveDate, n: u32) -> Option<NaiveDate> {
+        let steps = i64::from(n) * i64::from(self.interval.max(1));
+        match self.freq {
+            Frequency::Daily => start.checked_add_signed(chrono::Duration::try_days(steps)?),
+            Frequency::Weekly => {
+                let monday = start.week(Weekday::Mon).first_day();
+                monday.checked_add_signed(chrono::Duration::try_weeks(steps)?)
+            }
+            Frequency::Monthly => {
+                let months = i64::from(start.year()) * 12 + i64::from(start.month0()) + steps;
+                let year = i32::try_from(months.div_euclid(12)).ok()?;
+                NaiveDate::from_ymd_opt(year, months.rem_euclid(12) as u32 + 1, 1)
+            }
+            Frequency::Yearly => {
+                let year = i32::try_from(i64::from(start.year()) + steps).ok()?;
+                NaiveDate::from_ymd_opt(year, 1, 1)
+            }
+        }
+    }
+
+    /// Expand a period into the sorted list of matching days.
+    fn period_days(&self, start: NaiveDate, first: NaiveDate) -> Vec<NaiveDate> {
+        let mut days = match self.freq {
+            Frequency::Daily => {
+                if self.by_day.is_empty()
+                    || self.by_day.iter().any(|x| x.weekday == first.weekday())
+                {
+                    vec![first]
+                } else {
+                    vec![]
+                }
+            }
+            Frequency::Weekly => {
+                let weekdays = if self.by_day.is_empty() {
+                    vec![start.weekday()]
+                } else {
+                    self.by_day.iter().map(|x| x.weekday).collect()
+                };
+
+                weekdays
+                    .into_iter()
+                    .filter_map(|wd| {
+                        first.checked_add_days(chrono::Days::new(wd.num_days_from_monday().into()))
+                    })
+                    .collect()
+            }
+            Frequency::Monthly => {
+                let last = first
+                    .checked_add_months(chrono::Months::new(1))
+                    .and_then(|x| x.pred_opt());
+                if self.by_day.is_empty() {
+                    NaiveDate::from_ymd_opt(first.year(), first.month(), start.day())
+                        .into_iter()
+                        .collect()
+                } else if let Some(last) = last {
+                    expand_by_day(&self.by_day, first, last)
+                } else {
+                    vec![]
+                }
+            }
+            Frequency::Yearly => {
+                let last = NaiveDate::from_ymd_opt(first.year(), 12, 31);
+                if self.by_day.is_empty() {
+                    NaiveDate::from_ymd_opt(first.year(), start.month(), start.day())
+                        .into_iter()
+                        .collect()
+                } else if let Some(last) = last {
+                    expand_by_day(&self.by_day, first, last)
+                } else {
+                    vec![]
+                }
+            }
+        };
+
+        days.sort_unstable();
+        days.dedup();
+        days
+    }
+}
+
+/// Collect all days in `first..=last` matching any of the `BYDAY` entries.
+fn expand_by_day(by_day: &[ByDay], first: NaiveDate, last: NaiveDate) -> Vec<NaiveDate> {
+    let mut days = Vec::new();
+
+    for entry in by_day {
+        let offset =
+            (7 + entry.weekday.num_days_from_monday() - first.weekday().num_days_from_monday()) % 7;
+        let candidates: Vec<_> = first
+            .checked_add_days(chrono::Days::new(offset.into()))
+            .into_iter()
+            .flat_map(|x| x.iter_weeks())
+            .take_while(|x| *x <= last)
+            .collect();
+
+        match entry.ordinal {
+            None => days.extend(candidates),
+            Some(n) if n > 0 => days.extend(candidates.get(n as usize - 1)),
+            Some(n) if n < 0 => {
+                let idx = candidates.len().checked_sub(n.unsigned_abs() as usize);
+                days.extend(idx.map(|idx| candidates[idx]));
+            }
+            Some(_) => {}
+        }
+    }
+
+    days
+}
+
+// ============================================================================================== //
+// [Parsing]                                                                                      //
+// ============================================================================================== //
+
+/// Error returned when parsing a recurrence rule fails.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RecurrenceRuleError {
+    /// The mandatory `FREQ` part is missing.
+    MissingFrequency,
+    /// `COUNT` and `UNTIL` were both specified.
+    CountAndUntil,
+    /// A part is malformed or uses a value outside the supported subset.
+    InvalidPart(String),
+}
+
+impl fmt::Display for RecurrenceRuleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RecurrenceRuleError::MissingFrequency => f.write_str("missing FREQ part"),
+            RecurrenceRuleError::CountAndUntil => {
+                f.write_str("COUNT and UNTIL are mutually exclusive")
+            }
+            RecurrenceRuleError::InvalidPart(part) => {
+                write!(f, "invalid or unsupported part `{}`", part)
+            }
+        }
+    }
+}
+
+impl std::error::Error for RecurrenceRuleError {}
+
+fn parse_weekday(s: &str) -> Option<Weekday> {
+    Some(match s {
+        "MO" => Weekday::Mon,
+        "TU" => Weekday::Tue,
+        "WE" => Weekday::Wed,
+        "TH" => Weekday::Thu,
+        "FR" => Weekday::Fri,
+        "SA" => Weekday::Sat,
+        "SU" => Weekday::Sun,
+        _ => return None,
+    })
+}
+
+fn parse_by_day(s: &str) -> Option<ByDay> {
+    let (ordinal, weekday) = s.split_at(s.len().checked_sub(2)?);
+    let ordinal = match ordinal {
+        "" => None,
+        x => match x.strip_prefix('+').unwrap_or(x).parse::<i8>().ok()? {
+            n @ -53..=-1 | n @ 1..=53 => Some(n),
+            _ => return None,
+        },
+    };
+
+    Some(ByDay {
+        ordinal,
+        weekday: parse_weekday(weekday)?,
+    })
+}
+
+fn parse_until(s: &str) -> Option<UtcTimeStamp> {
+    let dt = if let Some(s) = s.strip_suffix('Z') {
+        NaiveDateTime::parse_from_str(s, "%Y%m%dT%H%M%S").ok()?
+    } else {
+        // A plain date includes the whole day.
+        let date = NaiveDate::parse_from_str(s, "%Y%m%d").ok()?;
+        date.and_time(NaiveTime::from_hms_milli_opt(23, 59, 59, 999)?)
+    };
+
+    Some(dt.and_utc().into())
+}
+
+/// Parse a rule such as `FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,WE;UNTIL=20240630T000000Z`.
+///
+/// An optional `RRULE:` prefix is accepted. Time zone local `UNTIL` values are
+/// not supported.
+impl FromStr for RecurrenceRule {
+    type Err = RecurrenceRuleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let s = s.strip_prefix("RRULE:").unwrap_or(s);
+
+        let mut freq = None;
+        let mut rule = RecurrenceRule::new(Frequency::Daily);
+
+        for part in s.split(';').filter(|x| !x.is_empty()) {
+            let invalid = || RecurrenceRuleError::InvalidPart(part.to_owned());
+            let (key, value) = part.split_once('=').ok_or_else(invalid)?;
+
+            match key {
+                "FREQ" => {
+                    freq = Some(match value {
+                        "DAILY" => Frequency::Daily,
+                        "WEEKLY" => Frequency::Weekly,
+                        "MONTHLY" => Frequency::Monthly,
+                        "YEARLY" => Frequency::Yearly,
+                        _ => return Err(invalid()),
+                    })
+                }
+                "INTERVAL" => {
+                    rule.interval = value.parse().ok().filter(|x| *x > 0).ok_or_else(invalid)?
+                }
+                "COUNT" => rule.count = Some(value.parse().map_err(|_| invalid())?),
+                "UNTIL" => rule.until = Some(parse_until(value).ok_or_else(invalid)?),
+                "BYDAY" => {
+                    rule.by_day = value
+                        .split(',')
+                        .map(parse_by_day)
+                        .collect::<Option<_>>()
+                        .ok_or_else(invalid)?
+                }
+                _ => return Err(invalid()),
+            }
+        }
+
+        rule.freq = freq.ok_or(RecurrenceRuleError::MissingFrequency)?;
+
+        if rule.count.is_some() && rule.until.is_some() {
+            return Err(RecurrenceRuleError::CountAndUntil);
+        }
+
+        let ordinals_allowed = matches!(rule.freq, Frequency::Monthly | Frequency::Yearly);
+        if !ordinals_allowed && rule.by_day.iter().any(|x| x.ordinal.is_some()) {
+            return Err(RecurrenceRuleError::InvalidPart("BYDAY".to_owned()));
+        }
+
+        Ok(rule)
+    }
+}
+
+// ============================================================================================== //
+// [Iterator]                                                                                     //
+// ============================================================================================== //
+
+/// Iterator over the occurrences of a [`RecurrenceRule`].
+///
+/// Rules without `COUNT` or `UNTIL` yield occurrences until the end of the
+/// representable date range. Rules that can never match again, such as
+/// `FREQ=DAILY;INTERVAL=7;BYDAY=TU` started on a monday, end early.
+#[derive(Debug)]
+pub struct Recurrences {
+    rule: RecurrenceRule,
+    start: NaiveDateTime,
+    period: u32,
+    /// Occurrences of the current period, in reverse order.
+    pending: Vec<UtcTimeStamp>,
+    emitted: u32,
+    /// Consecutive periods expanded without yielding any occurrence.
+    empty_periods: u32,
+    done: bool,
+}
+
+impl Recurrences {
+    /// Number of consecutive empty periods after which no later period can match.
+    ///
+    /// Weekdays repeat every 7 days and the Gregorian calendar every 400 years
+    /// (a whole number of weeks), so the matching days of the periods repeat
+    /// with at most that many periods. One more accounts for the first period,
+    /// which may be empty only because it starts before `start`.
+    fn max_empty_periods(&self) -> u32 {
+        match self.rule.freq {
+            Frequency::Daily => 7 + 1,
+            Frequency::Weekly => 1 + 1,
+            Frequency::Monthly => 400 * 12 + 1,
+            Frequency::Yearly => 400 + 1,
+        }
+    }
+
+    /// Expand the next period into `pending`. Returns `false` once exhausted.
+    fn fill(&mut self) -> bool {
+        let start = self.start.date();
+        let first = match self.rule.period_start(start, self.period) {
+            Some(x) => x,
+            None => return false,
+        };
+        self.period = match self.period.checked_add(1) {
+            Some(x) => x,
+            None => return false,
+        };
+
+        if let Some(until) = self.rule.until {
+            if UtcTimeStamp::from(first.and_hms_opt(0, 0, 0).unwrap().and_utc()) > until {
+                return false;
+            }
+        }
+
+        self.pending = self
+            .rule
+            .period_days(start, first)
+            .into_iter()
+            .map(|day| day.and_time(self.start.time()))
+            .filter(|x| *x >= self.start)
+            .map(|x| UtcTimeStamp::from(x.and_utc()))
+            .rev()
+            .collect();
+
+        true
+    }
+}
+
+impl Iterator for Recurrences {
+    type Item = UtcTimeStamp;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        if matches!(self.rule.count, Some(count) if self.emitted >= count) {
+            self.done = true;
+            return None;
+        }
+
+        while self.pending.is_empty() {
+            if self.empty_periods >= self.max_empty_periods() || !self.fill() {
+                self.done = true;
+                return None;
+            }
+            self.empty_periods = if self.pending.is_empty() {
+                self.empty_periods + 1
+            } else {
+                0
+            };
+        }
+
+        let next = self.pending.pop()?;
+        if matches!(self.rule.until, Some(until) if next > until) {
+            self.done = true;
+            return None;
+        }
+
+        self.emitted += 1;
+        Some(next)
+    }
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use chrono::{offset::TimeZone, Utc, Weekday};
+
+    fn ts(y: i32, m: u32, d: u32, h: u32, mi: u32) -> UtcTimeStamp {
+        Utc.with_ymd_and_hms(y, m, d, h, mi, 0).unwrap().into()
+    }
+
+    #[test]
+    fn parse_rule() {
+        let rule: RecurrenceRule = "RRULE:FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,FR".parse().unwrap();
+        assert_eq!(rule.freq, Frequency::Weekly);
+
+        let rule: RecurrenceRule = "FREQ=MONTHLY;BYDAY=-1FR,+2MO;UNTIL=20240301"
+            .parse()
+            .unwrap();
+        assert_eq!(rule.by_day, vec![
+            ByDay::nth(-1, Weekday::Fri),
+            ByDay::nth(2, Weekday::Mon)
+        ]);
+        assert_eq!(
+            rule.until,
+            Some(ts(2024, 3, 1, 23, 59) + TimeDelta::from_milliseconds(59_999))
+        );
+
+        assert_eq!(
+            "INTERVAL=2".parse::<RecurrenceRule>(),
+            Err(RecurrenceRuleError::MissingFrequency)
+        );
+        assert_eq!(
+            "FREQ=DAILY;COUNT=1;UNTIL=20240101T000000Z".parse::<RecurrenceRule>(),
+            Err(RecurrenceRuleError::CountAndUntil),
+        );
+        assert!("FREQ=HOURLY".parse::<RecurrenceRule>().is_err());
+        assert!("FREQ=WEEKLY;BYDAY=1MO".parse::<RecurrenceRule>().is_err());
+    }
+
+    #[test]
+    fn daily_and_weekly() {
+        let rule: RecurrenceRule = "FREQ=DAILY;BYDAY=MO,TU,WE,TH,FR;COUNT=4".parse().unwrap();
+        let days: Vec<_> = rule.iter(ts(2024, 1, 5, 9, 30)).collect();
+        assert_eq!(days, vec![
+            ts(2024, 1, 5, 9, 30),
+            ts(2024, 1, 8, 9, 30),
+            ts(2024, 1, 9, 9, 30),
+            ts(2024, 1, 10, 9, 30),
+        ]);
+
+        let rule: RecurrenceRule = "FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,TH;UNTIL=20240126T000000Z"
+            .parse()
+            .unwrap();
+        let days: Vec<_> = rule.iter(ts(2024, 1, 4, 12, 0)).collect();
+        assert_eq!(days, vec![
+            ts(2024, 1, 4, 12, 0),
+            ts(2024, 1, 15, 12, 0),
+            ts(2024, 1, 18, 12, 0)
+        ]);
+    }
+
+    #[test]
+    fn monthly_and_yearly() {
+        let rule: RecurrenceRule = "FREQ=MONTHLY;COUNT=4".parse().unwrap();
+        let days: Vec<_> = rule.iter(ts(2024, 1, 31, 0, 0)).collect();
+        assert_eq!(days, vec![
+            ts(2024, 1, 31, 0, 0),
+            ts(2024, 3, 31, 0, 0),
+            ts(2024, 5, 31, 0, 0),
+            ts(2024, 7, 31, 0, 0),
+        ]);
+
+        let rule: RecurrenceRule = "FREQ=MONTHLY;INTERVAL=3;BYDAY=-1MO;COUNT=2"
+            .parse()
+            .unwrap();
+        let days: Vec<_> = rule.iter(ts(2024, 2, 27, 8, 0)).collect();
+        assert_eq!(days, vec![ts(2024, 5, 27, 8, 0), ts(2024, 8, 26, 8, 0)]);
+
+        let rule: RecurrenceRule = "FREQ=YEARLY;BYDAY=1MO;COUNT=2".parse().unwrap();
+        let days: Vec<_> = rule.iter(ts(2024, 1, 1, 0, 0)).collect();
+        assert_eq!(days, vec![ts(2024, 1, 1, 0, 0), ts(2025, 1, 6, 0, 0)]);
+
+        let mut rule = RecurrenceRule::new(Frequency::Yearly);
+        rule.count = Some(2);
+        let days: Vec<_> = rule.iter(ts(2024, 2, 29, 0, 0)).collect();
+        assert_eq!(days, vec![ts(2024, 2, 29, 0, 0), ts(2028, 2, 29, 0, 0)]);
+    }
+
+    #[test]
+    fn never_matching() {
+        // Every 7th day from a monday is a monday.
+        let rule: RecurrenceRule = "FREQ=DAILY;INTERVAL=7;BYDAY=TU;COUNT=1".parse().unwrap();
+        assert_eq!(rule.iter(ts(2024, 1, 1, 0, 0)).next(), None);
+
+        let rule: RecurrenceRule = "FREQ=MONTHLY;INTERVAL=12;BYDAY=6FR".parse().unwrap();
+        assert_eq!(rule.iter(ts(2024, 1, 1, 0, 0)).next(), None);
+
+        // Rare matches, a february with five fridays, are still found.
+        let rule: RecurrenceRule = "FREQ=MONTHLY;INTERVAL=12;BYDAY=5FR;COUNT=2"
+            .parse()
+            .unwrap();
+        let days: Vec<_> = rule.iter(ts(2023, 2, 1, 0, 0)).collect();
+        assert_eq!(days, vec![ts(2036, 2, 29, 0, 0), ts(2064, 2, 29, 0, 0)]);
+    }
+}
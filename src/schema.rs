@@ -0,0 +1,72 @@
+//! OpenAPI schemas for use with utoipa.
+
+use crate::{TimeDelta, UtcTimeStamp};
+use utoipa::{
+    openapi::{
+        schema::{KnownFormat, ObjectBuilder, Schema, SchemaFormat, Type},
+        RefOr,
+    },
+    PartialSchema, ToSchema,
+};
+
+/// Matches the default serde representation of the type.
+impl PartialSchema for UtcTimeStamp {
+    #[cfg(not(feature = "serde-human-readable"))]
+    fn schema() -> RefOr<Schema> {
+        ObjectBuilder::new()
+            .schema_type(Type::Integer)
+            .format(Some(SchemaFormat::KnownFormat(KnownFormat::Int64)))
+            .description(Some("Milliseconds since 1970-01-01T00:00:00Z"))
+            .into()
+    }
+
+    #[cfg(feature = "serde-human-readable")]
+    fn schema() -> RefOr<Schema> {
+        ObjectBuilder::new()
+            .schema_type(Type::String)
+            .format(Some(SchemaFormat::KnownFormat(KnownFormat::DateTime)))
+            .description(Some("RFC 3339 timestamp with millisecond precision"))
+            .into()
+    }
+}
+
+impl ToSchema for UtcTimeStamp {}
+
+impl PartialSchema for TimeDelta {
+    fn schema() -> RefOr<Schema> {
+        ObjectBuilder::new()
+            .schema_type(Type::Integer)
+            .format(Some(SchemaFormat::KnownFormat(KnownFormat::Int64)))
+            .description(Some("Duration in milliseconds"))
+            .into()
+    }
+}
+
+impl ToSchema for TimeDelta {}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use utoipa::{PartialSchema, ToSchema};
+
+    #[test]
+    fn schemas() {
+        assert_eq!(UtcTimeStamp::name(), "UtcTimeStamp");
+        assert_eq!(TimeDelta::name(), "TimeDelta");
+
+        let schema = serde_json::to_value(TimeDelta::schema()).unwrap();
+        assert_eq!(schema["type"], "integer");
+        assert_eq!(schema["format"], "int64");
+
+        let schema = serde_json::to_value(UtcTimeStamp::schema()).unwrap();
+        if cfg!(feature = "serde-human-readable") {
+            assert_eq!(schema["format"], "date-time");
+        } else {
+            assert_eq!(schema["format"], "int64");
+        }
+    }
+}
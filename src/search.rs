@@ -0,0 +1,201 @@
+//! Binary search helpers for sorted timestamp slices.
+//!
+//! All functions expect the input slice to be sorted in ascending order and
+//! return indices into it, so they can be used to address parallel value arrays.
+
+use crate::{TimeDelta, UtcTimeStamp};
+use core::ops::{self, Bound, RangeBounds};
+
+// ============================================================================================== //
+// [As-of lookup]                                                                                 //
+// ============================================================================================== //
+
+/// Index of the latest timestamp at or before `ts`.
+///
+/// With duplicate timestamps, the last of them is returned.
+///
+/// Examples:
+///
+/// ```
+/// use utctimestamp::{search, UtcTimeStamp};
+///
+/// let quotes: Vec<_> = [10, 20, 30].iter().map(|&s| UtcTimeStamp::from_seconds(s)).collect();
+///
+/// assert_eq!(search::asof(&quotes, UtcTimeStamp::from_seconds(25)), Some(1));
+/// assert_eq!(search::asof(&quotes, UtcTimeStamp::from_seconds(30)), Some(2));
+/// assert_eq!(search::asof(&quotes, UtcTimeStamp::from_seconds(5)), None);
+/// ```
+pub fn asof(sorted: &[UtcTimeStamp], ts: UtcTimeStamp) -> Option<usize> {
+    sorted.partition_point(|x| *x <= ts).checked_sub(1)
+}
+
+/// Like [`asof`], but only matches timestamps at most `tolerance` before `ts`.
+pub fn asof_within(
+    sorted: &[UtcTimeStamp],
+    ts: UtcTimeStamp,
+    tolerance: TimeDelta,
+) -> Option<usize> {
+    asof(sorted, ts).filter(|&idx| ts - sorted[idx] <= tolerance)
+}
+
+/// Index of the earliest timestamp at or after `ts`.
+///
+/// With duplicate timestamps, the first of them is returned.
+pub fn after(sorted: &[UtcTimeStamp], ts: UtcTimeStamp) -> Option<usize> {
+    let idx = sorted.partition_point(|x| *x < ts);
+    if idx < sorted.len() {
+        Some(idx)
+    } else {
+        None
+    }
+}
+
+/// Like [`after`], but only matches timestamps at most `tolerance` after `ts`.
+pub fn after_within(
+    sorted: &[UtcTimeStamp],
+    ts: UtcTimeStamp,
+    tolerance: TimeDelta,
+) -> Option<usize> {
+    after(sorted, ts).filter(|&idx| sorted[idx] - ts <= tolerance)
+}
+
+/// Index of the timestamp closest to `ts`, preferring the earlier one on ties.
+pub fn nearest(sorted: &[UtcTimeStamp], ts: UtcTimeStamp) -> Option<usize> {
+    match (asof(sorted, ts), after(sorted, ts)) {
+        (Some(before), Some(after)) if sorted[after] - ts < ts - sorted[before] => Some(after),
+        (Some(before), _) => Some(before),
+        (None, after) => after,
+    }
+}
+
+/// Like [`nearest`], but only matches timestamps at most `tolerance` away from `ts`.
+pub fn nearest_within(
+    sorted: &[UtcTimeStamp],
+    ts: UtcTimeStamp,
+    tolerance: TimeDelta,
+) -> Option<usize> {
+    nearest(sorted, ts)
+        .filter(|&idx| (sorted[idx] - ts).as_milliseconds().abs() <= tolerance.as_milliseconds())
+}
+
+// ============================================================================================== //
+// [Range slicing]                                                                                //
+// ============================================================================================== //
+
+/// Index range of all timestamps within `range`.
+///
+/// Accepts any range expression over timestamps as well as [`TimeInterval`],
+/// and can be used to slice parallel value arrays.
+///
+/// [`TimeInterval`]: crate::TimeInterval
+///
+/// Examples:
+///
+/// ```
+/// use utctimestamp::{search, UtcTimeStamp};
+///
+/// let ts = UtcTimeStamp::from_seconds;
+/// let times = [ts(1), ts(2), ts(3), ts(4)];
+/// let prices = [10.0, 10.5, 10.25, 11.0];
+///
+/// let idx = search::range_indices(&times, ts(2)..ts(4));
+/// assert_eq!(&prices[idx], &[10.5, 10.25]);
+/// assert_eq!(search::slice_range(&times, ts(3)..), &[ts(3), ts(4)]);
+/// ```
+pub fn range_indices(
+    sorted: &[UtcTimeStamp],
+    range: impl RangeBounds<UtcTimeStamp>,
+) -> ops::Range<usize> {
+    let start = match range.start_bound() {
+        Bound::Included(start) => sorted.partition_point(|x| x < start),
+        Bound::Excluded(start) => sorted.partition_point(|x| x <= start),
+        Bound::Unbounded => 0,
+    };
+
+    let rest = &sorted[start..];
+    let len = match range.end_bound() {
+        Bound::Included(end) => rest.partition_point(|x| x <= end),
+        Bound::Excluded(end) => rest.partition_point(|x| x < end),
+        Bound::Unbounded => rest.len(),
+    };
+
+    start..start + len
+}
+
+/// Sub-slice of all timestamps within `range`, see [`range_indices`].
+pub fn slice_range(
+    sorted: &[UtcTimeStamp],
+    range: impl RangeBounds<UtcTimeStamp>,
+) -> &[UtcTimeStamp] {
+    &sorted[range_indices(sorted, range)]
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TimeInterval;
+
+    fn ts(s: i64) -> UtcTimeStamp {
+        UtcTimeStamp::from_seconds(s)
+    }
+
+    #[test]
+    fn asof_after() {
+        let data = [ts(10), ts(20), ts(20), ts(30)];
+        assert_eq!(asof(&data, ts(9)), None);
+        assert_eq!(asof(&data, ts(10)), Some(0));
+        assert_eq!(asof(&data, ts(20)), Some(2));
+        assert_eq!(asof(&data, ts(99)), Some(3));
+        assert_eq!(asof(&[], ts(0)), None);
+
+        assert_eq!(after(&data, ts(9)), Some(0));
+        assert_eq!(after(&data, ts(11)), Some(1));
+        assert_eq!(after(&data, ts(30)), Some(3));
+        assert_eq!(after(&data, ts(31)), None);
+
+        let tol = TimeDelta::from_seconds(5);
+        assert_eq!(asof_within(&data, ts(35), tol), Some(3));
+        assert_eq!(asof_within(&data, ts(36), tol), None);
+        assert_eq!(after_within(&data, ts(15), tol), Some(1));
+        assert_eq!(after_within(&data, ts(14), tol), None);
+    }
+
+    #[test]
+    fn nearest_lookup() {
+        let data = [ts(10), ts(20), ts(30)];
+        assert_eq!(nearest(&data, ts(0)), Some(0));
+        assert_eq!(nearest(&data, ts(14)), Some(0));
+        assert_eq!(nearest(&data, ts(15)), Some(0));
+        assert_eq!(nearest(&data, ts(16)), Some(1));
+        assert_eq!(nearest(&data, ts(100)), Some(2));
+        assert_eq!(nearest(&[], ts(0)), None);
+
+        let tol = TimeDelta::from_seconds(3);
+        assert_eq!(nearest_within(&data, ts(17), tol), Some(1));
+        assert_eq!(nearest_within(&data, ts(23), tol), Some(1));
+        assert_eq!(nearest_within(&data, ts(25), tol), None);
+    }
+
+    #[test]
+    fn range_slicing() {
+        let data = [ts(1), ts(2), ts(2), ts(3), ts(5)];
+        assert_eq!(range_indices(&data, ts(2)..ts(3)), 1..3);
+        assert_eq!(range_indices(&data, ts(2)..=ts(3)), 1..4);
+        assert_eq!(range_indices(&data, ..ts(2)), 0..1);
+        assert_eq!(
+            range_indices(&data, (Bound::Excluded(ts(2)), Bound::Unbounded)),
+            3..5
+        );
+        assert_eq!(range_indices(&data, ts(4)..ts(5)), 4..4);
+        assert_eq!(range_indices(&data, ts(6)..ts(1)), 5..5);
+        assert_eq!(slice_range(&data, ..), &data);
+        assert_eq!(slice_range(&data, TimeInterval::new(ts(3), ts(9))), &[
+            ts(3),
+            ts(5)
+        ]);
+    }
+}
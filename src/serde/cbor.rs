@@ -0,0 +1,147 @@
+//! (De)serialize a [`UtcTimeStamp`] as a CBOR date/time with `serde_cbor`.
+//!
+//! Timestamps are serialized as tag 1 (epoch-based date/time, RFC 8949
+//! section 3.4.2): integer seconds for whole seconds, fractional seconds as a
+//! float otherwise. On deserialization, tag 1 numbers and tag 0 RFC 3339
+//! strings are accepted, as well as untagged values of either kind.
+//!
+//! With formats other than CBOR, the tag is dropped and the plain number is
+//! used.
+//!
+//! Examples:
+//!
+//! ```
+//! use serde::{Deserialize, Serialize};
+//! use utctimestamp::UtcTimeStamp;
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Reading(#[serde(with = "utctimestamp::serde::cbor")] UtcTimeStamp);
+//!
+//! let reading = Reading(UtcTimeStamp::from_seconds(1_600_000_000));
+//! let bytes = serde_cbor::to_vec(&reading).unwrap();
+//! assert_eq!(bytes, [0xc1, 0x1a, 0x5f, 0x5e, 0x10, 0x00]);
+//!
+//! let tag_0 = b"\xc0\x742020-09-13T12:26:40Z";
+//! let reading: Reading = serde_cbor::from_slice(tag_0).unwrap();
+//! assert_eq!(reading.0, UtcTimeStamp::from_seconds(1_600_000_000));
+//! ```
+
+use super::rfc3339;
+use crate::UtcTimeStamp;
+use core::{convert::TryFrom, fmt};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use serde_cbor::tags::Tagged;
+
+const TAG_DATE_TIME_STRING: u64 = 0;
+const TAG_EPOCH_DATE_TIME: u64 = 1;
+
+pub fn serialize<S: Serializer>(ts: &UtcTimeStamp, serializer: S) -> Result<S::Ok, S::Error> {
+    let tag = Some(TAG_EPOCH_DATE_TIME);
+    match ts.split_seconds() {
+        (secs, 0) => Tagged::new(tag, secs).serialize(serializer),
+        _ => Tagged::new(tag, ts.as_seconds_f64()).serialize(serializer),
+    }
+}
+
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<UtcTimeStamp, D::Error> {
+    let tagged = Tagged::<Raw>::deserialize(deserializer)?;
+    match (tagged.tag, tagged.value) {
+        (Some(TAG_EPOCH_DATE_TIME), Raw::Str(_)) | (Some(TAG_DATE_TIME_STRING), Raw::Num(_)) => {
+            Err(de::Error::custom("CBOR date/time tag doesn't match value"))
+        }
+        (Some(TAG_EPOCH_DATE_TIME), Raw::Num(ts))
+        | (Some(TAG_DATE_TIME_STRING), Raw::Str(ts))
+        | (None, Raw::Num(ts))
+        | (None, Raw::Str(ts)) => Ok(ts),
+        (Some(tag), _) => Err(de::Error::custom(format_args!(
+            "unexpected CBOR tag {}",
+            tag
+        ))),
+    }
+}
+
+/// A timestamp along with the kind of value it was parsed from.
+enum Raw {
+    Num(UtcTimeStamp),
+    Str(UtcTimeStamp),
+}
+
+impl<'de> Deserialize<'de> for Raw {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(Visitor)
+    }
+}
+
+struct Visitor;
+
+impl<'de> de::Visitor<'de> for Visitor {
+    type Value = Raw;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("epoch seconds or an RFC 3339 timestamp string")
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<Self::Value, E> {
+        UtcTimeStamp::from_seconds_f64(v)
+            .map(Raw::Num)
+            .ok_or_else(|| E::custom("timestamp out of range"))
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+        v.checked_mul(1000)
+            .map(|x| Raw::Num(UtcTimeStamp::from_milliseconds(x)))
+            .ok_or_else(|| E::custom("timestamp out of range"))
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+        let v = i64::try_from(v).map_err(|_| E::custom("timestamp out of range"))?;
+        self.visit_i64(v)
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        rfc3339::Visitor.visit_str(v).map(Raw::Str)
+    }
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::UtcTimeStamp;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Wrapper(#[serde(with = "super")] UtcTimeStamp);
+
+    #[test]
+    fn round_trip() {
+        let from_cbor = |b: &[u8]| serde_cbor::from_slice::<Wrapper>(b).map(|x| x.0);
+        let to_cbor = |ms| serde_cbor::to_vec(&Wrapper(UtcTimeStamp::from_milliseconds(ms)));
+
+        for &ms in &[0, -1, 1_600_000_000_250, -1_000, i64::MAX / 1000 * 1000] {
+            let ts = UtcTimeStamp::from_milliseconds(ms);
+            assert_eq!(from_cbor(&to_cbor(ms).unwrap()).unwrap(), ts);
+        }
+
+        // Tag 1 with a negative integer and with a float, packed into a half.
+        assert_eq!(to_cbor(-2000).unwrap(), [0xc1, 0x21]);
+        assert_eq!(to_cbor(-1500).unwrap(), [0xc1, 0xf9, 0xbe, 0x00]);
+
+        // Untagged values and mismatched or unknown tags.
+        assert_eq!(
+            from_cbor(&[0x1a, 0x5f, 0x5e, 0x10, 0x00]).unwrap(),
+            UtcTimeStamp::from_seconds(1_600_000_000)
+        );
+        assert!(from_cbor(&[0xc0, 0x01]).is_err());
+        assert!(from_cbor(b"\xc1\x742020-09-13T12:26:40Z").is_err());
+        assert!(from_cbor(&[0xc2, 0x01]).is_err());
+        assert!(from_cbor(&[0xc1, 0x1b, 0xff, 0, 0, 0, 0, 0, 0, 0]).is_err());
+
+        assert_eq!(
+            serde_json::to_string(&Wrapper(UtcTimeStamp::zero())).unwrap(),
+            "0"
+        );
+    }
+}
@@ -0,0 +1,5 @@
+//! (De)serialize an `Option<UtcTimeStamp>` as a CBOR date/time or null.
+//!
+//! See [`cbor`](super::cbor).
+
+option_module!(cbor);
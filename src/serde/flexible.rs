@@ -0,0 +1,180 @@
+//! Deserialize a [`UtcTimeStamp`] from epoch integers or RFC 3339 strings.
+//!
+//! Accepts integers, integer strings and RFC 3339 strings for the same field.
+//! Integers are interpreted as seconds if their magnitude is below 10¹¹ (the
+//! year 5138 in seconds, early 1973 in milliseconds) and as milliseconds
+//! otherwise. Use the [`seconds`] or [`millis`] submodules to interpret them
+//! explicitly instead.
+//!
+//! Timestamps are serialized as integer milliseconds, like the derived impl.
+//!
+//! Examples:
+//!
+//! ```
+//! use serde::Deserialize;
+//! use utctimestamp::UtcTimeStamp;
+//!
+//! #[derive(Deserialize)]
+//! struct Event {
+//!     #[serde(with = "utctimestamp::serde::flexible")]
+//!     at: UtcTimeStamp,
+//! }
+//!
+//! let expected = UtcTimeStamp::from_seconds(1_600_000_000);
+//! for json in &[
+//!     r#"{"at":1600000000}"#,
+//!     r#"{"at":1600000000000}"#,
+//!     r#"{"at":"1600000000"}"#,
+//!     r#"{"at":"2020-09-13T12:26:40Z"}"#,
+//! ] {
+//!     assert_eq!(serde_json::from_str::<Event>(json).unwrap().at, expected);
+//! }
+//! ```
+
+use super::rfc3339;
+use crate::UtcTimeStamp;
+use core::{convert::TryFrom, fmt};
+use serde::{de, Deserializer, Serializer};
+
+const SECONDS_THRESHOLD: i64 = 100_000_000_000;
+
+#[derive(Copy, Clone)]
+pub(super) enum Unit {
+    Auto,
+    Seconds,
+    Millis,
+}
+
+pub(super) struct Visitor(pub(super) Unit);
+
+impl Visitor {
+    fn convert_int<E: de::Error>(&self, v: i64) -> Result<UtcTimeStamp, E> {
+        let is_seconds = match self.0 {
+            Unit::Auto => matches!(v.checked_abs(), Some(x) if x < SECONDS_THRESHOLD),
+            Unit::Seconds => true,
+            Unit::Millis => false,
+        };
+        if is_seconds {
+            v.checked_mul(1000)
+                .map(UtcTimeStamp::from_milliseconds)
+                .ok_or_else(|| E::custom("timestamp out of range"))
+        } else {
+            Ok(UtcTimeStamp::from_milliseconds(v))
+        }
+    }
+}
+
+impl<'de> de::Visitor<'de> for Visitor {
+    type Value = UtcTimeStamp;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("an epoch integer or an RFC 3339 timestamp string")
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+        self.convert_int(v)
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+        match i64::try_from(v) {
+            Ok(v) => self.convert_int(v),
+            Err(_) => Err(E::invalid_value(de::Unexpected::Unsigned(v), &self)),
+        }
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        if let Ok(int) = v.parse::<i64>() {
+            return self.convert_int(int);
+        }
+        rfc3339::parse(v).ok_or_else(|| E::invalid_value(de::Unexpected::Str(v), &self))
+    }
+}
+
+pub fn serialize<S: Serializer>(ts: &UtcTimeStamp, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_i64(ts.as_milliseconds())
+}
+
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<UtcTimeStamp, D::Error> {
+    deserializer.deserialize_any(Visitor(Unit::Auto))
+}
+
+/// Like [`flexible`](super), but integers are always seconds.
+///
+/// Timestamps are serialized as integer seconds, truncated towards the past.
+pub mod seconds {
+    use super::{Unit, Visitor};
+    use crate::UtcTimeStamp;
+    use serde::{Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(ts: &UtcTimeStamp, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::serde::ts_seconds::serialize(ts, serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<UtcTimeStamp, D::Error> {
+        deserializer.deserialize_any(Visitor(Unit::Seconds))
+    }
+}
+
+/// Like [`flexible`](super), but integers are always milliseconds.
+pub mod millis {
+    use super::{Unit, Visitor};
+    use crate::UtcTimeStamp;
+    use serde::{Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(ts: &UtcTimeStamp, serializer: S) -> Result<S::Ok, S::Error> {
+        super::serialize(ts, serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<UtcTimeStamp, D::Error> {
+        deserializer.deserialize_any(Visitor(Unit::Millis))
+    }
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::UtcTimeStamp;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Auto(#[serde(with = "super")] UtcTimeStamp);
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Seconds(#[serde(with = "super::seconds")] UtcTimeStamp);
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Millis(#[serde(with = "super::millis")] UtcTimeStamp);
+
+    #[test]
+    fn flexible() {
+        let auto = |s: &str| serde_json::from_str::<Auto>(s).map(|x| x.0.as_milliseconds());
+        assert_eq!(auto("99999999999").unwrap(), 99_999_999_999_000);
+        assert_eq!(auto("100000000000").unwrap(), 100_000_000_000);
+        assert_eq!(auto("-5").unwrap(), -5000);
+        assert_eq!(auto(r#""-5""#).unwrap(), -5000);
+        assert_eq!(auto(r#""1970-01-01T00:00:00.042Z""#).unwrap(), 42);
+        assert!(auto(r#""yesterday""#).is_err());
+        assert!(auto("1.5").is_err());
+        assert!(auto(&u64::MAX.to_string()).is_err());
+
+        let secs = |s: &str| serde_json::from_str::<Seconds>(s).map(|x| x.0.as_milliseconds());
+        assert_eq!(secs("100000000000").unwrap(), 100_000_000_000_000);
+        assert!(secs(&i64::MAX.to_string()).is_err());
+
+        let millis = |s: &str| serde_json::from_str::<Millis>(s).map(|x| x.0.as_milliseconds());
+        assert_eq!(millis("5").unwrap(), 5);
+        assert_eq!(millis(r#""1970-01-01T00:00:01Z""#).unwrap(), 1000);
+
+        let ts = UtcTimeStamp::from_milliseconds(1500);
+        assert_eq!(serde_json::to_string(&Auto(ts)).unwrap(), "1500");
+        assert_eq!(serde_json::to_string(&Seconds(ts)).unwrap(), "1");
+        assert_eq!(serde_json::to_string(&Millis(ts)).unwrap(), "1500");
+    }
+}
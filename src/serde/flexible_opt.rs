@@ -0,0 +1,33 @@
+//! Deserialize an `Option<UtcTimeStamp>` from epoch integers, RFC 3339 strings or null.
+//!
+//! See [`flexible`](super::flexible).
+
+option_module!(flexible);
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::UtcTimeStamp;
+    use serde::Deserialize;
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Event {
+        #[serde(with = "super", default)]
+        at: Option<UtcTimeStamp>,
+    }
+
+    #[test]
+    fn deserialize() {
+        let at = |s: &str| serde_json::from_str::<Event>(s).unwrap().at;
+        assert_eq!(at(r#"{"at":null}"#), None);
+        assert_eq!(at(r#"{}"#), None);
+        assert_eq!(at(r#"{"at":5}"#), Some(UtcTimeStamp::from_seconds(5)));
+        assert_eq!(
+            at(r#"{"at":"1970-01-01T00:00:05Z"}"#),
+            Some(UtcTimeStamp::from_seconds(5))
+        );
+    }
+}
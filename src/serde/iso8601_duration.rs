@@ -0,0 +1,75 @@
+//! (De)serialize a [`TimeDelta`] as an ISO 8601 duration string.
+//!
+//! Deltas are serialized using hours, minutes and seconds only, e.g.
+//! `PT25H30M` or `-PT0.250S`. On deserialization, week and day components
+//! are accepted as well and treated as exactly 7 days and 24 hours. Year and
+//! month components are rejected, as their length depends on the calendar.
+//!
+//! Examples:
+//!
+//! ```
+//! use serde::{Deserialize, Serialize};
+//! use utctimestamp::TimeDelta;
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Config {
+//!     #[serde(with = "utctimestamp::serde::iso8601_duration")]
+//!     interval: TimeDelta,
+//! }
+//!
+//! let config: Config = serde_json::from_str(r#"{"interval":"PT1H30M"}"#).unwrap();
+//! assert_eq!(config.interval, TimeDelta::from_minutes(90));
+//! assert_eq!(serde_json::to_string(&config).unwrap(), r#"{"interval":"PT1H30M"}"#);
+//! ```
+
+use crate::TimeDelta;
+use core::fmt;
+use serde::{de, Deserializer, Serializer};
+
+pub fn serialize<S: Serializer>(delta: &TimeDelta, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&delta.to_iso8601())
+}
+
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<TimeDelta, D::Error> {
+    deserializer.deserialize_str(Visitor)
+}
+
+struct Visitor;
+
+impl<'de> de::Visitor<'de> for Visitor {
+    type Value = TimeDelta;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("an ISO 8601 duration string")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        TimeDelta::parse_iso8601(v).map_err(|_| E::invalid_value(de::Unexpected::Str(v), &self))
+    }
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::TimeDelta;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Wrapper(#[serde(with = "super")] TimeDelta);
+
+    #[test]
+    fn round_trip() {
+        let delta = Wrapper(TimeDelta::from_milliseconds(-86_400_250));
+        let json = serde_json::to_string(&delta).unwrap();
+        assert_eq!(json, r#""-PT24H0.250S""#);
+        assert_eq!(serde_json::from_str::<Wrapper>(&json).unwrap(), delta);
+        assert_eq!(
+            serde_json::from_str::<Wrapper>(r#""P1D""#).unwrap(),
+            Wrapper(TimeDelta::from_hours(24))
+        );
+        assert!(serde_json::from_str::<Wrapper>(r#""P1M""#).is_err());
+    }
+}
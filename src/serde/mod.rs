@@ -0,0 +1,126 @@
+//! Serde helpers for alternative timestamp representations.
+//!
+//! The derived impls (de)serialize timestamps as integer milliseconds. The
+//! modules in here can be used with `#[serde(with = "...")]` to pick a different
+//! representation for individual fields.
+//!
+//! - [`ts_seconds`], [`ts_milliseconds`], [`ts_nanoseconds`]: integers since the epoch,
+//!   wire compatible with the equally named modules in `chrono::serde`
+//! - [`ts_seconds_f64`]: fractional seconds since the epoch, e.g. `1694791200.125`
+//! - [`rfc3339`]: RFC 3339 strings such as `2020-09-13T12:26:40.000Z`
+//! - [`flexible`]: accepts epoch seconds, milliseconds and RFC 3339 strings
+//! - `cbor`: CBOR tag 1 epoch date/times, requires the `cbor-support` feature
+//! - [`msgpack`]: the MessagePack timestamp extension, for use with `rmp-serde`
+//!
+//! Each module has an `_opt` variant for `Option<UtcTimeStamp>` fields, mapping
+//! `None` to null. Combine it with `#[serde(default)]` to also accept missing
+//! fields.
+//!
+//! For [`TimeDelta`](crate::TimeDelta) fields, [`iso8601_duration`] provides
+//! ISO 8601 duration strings such as `PT1H30M`.
+//!
+//! With the `serde-human-readable` feature, the default impls of
+//! [`UtcTimeStamp`] use RFC 3339 strings for human-readable formats such as
+//! JSON and keep the integer milliseconds for binary formats such as bincode.
+//! Integer milliseconds are still accepted when deserializing from
+//! human-readable formats.
+
+/// Implements an `Option<UtcTimeStamp>` variant of a `with` module.
+macro_rules! option_module {
+    ($inner:ident) => {
+        use crate::UtcTimeStamp;
+        use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+        struct Wrapper(UtcTimeStamp);
+
+        impl Serialize for Wrapper {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                super::$inner::serialize(&self.0, serializer)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for Wrapper {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                super::$inner::deserialize(deserializer).map(Wrapper)
+            }
+        }
+
+        pub fn serialize<S: Serializer>(
+            ts: &Option<UtcTimeStamp>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            ts.map(Wrapper).serialize(serializer)
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Option<UtcTimeStamp>, D::Error> {
+            Ok(Option::<Wrapper>::deserialize(deserializer)?.map(|x| x.0))
+        }
+    };
+}
+
+#[cfg(feature = "cbor-support")]
+pub mod cbor;
+#[cfg(feature = "cbor-support")]
+pub mod cbor_opt;
+pub mod flexible;
+pub mod flexible_opt;
+pub mod iso8601_duration;
+pub mod msgpack;
+pub mod msgpack_opt;
+pub mod rfc3339;
+pub mod rfc3339_opt;
+pub mod ts_milliseconds;
+pub mod ts_milliseconds_opt;
+pub mod ts_nanoseconds;
+pub mod ts_nanoseconds_opt;
+pub mod ts_seconds;
+pub mod ts_seconds_f64;
+pub mod ts_seconds_f64_opt;
+pub mod ts_seconds_opt;
+
+#[cfg(feature = "serde-human-readable")]
+use crate::UtcTimeStamp;
+#[cfg(feature = "serde-human-readable")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+#[cfg(feature = "serde-human-readable")]
+impl Serialize for UtcTimeStamp {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            rfc3339::serialize(self, serializer)
+        } else {
+            serializer.serialize_i64(self.as_milliseconds())
+        }
+    }
+}
+
+#[cfg(feature = "serde-human-readable")]
+impl<'de> Deserialize<'de> for UtcTimeStamp {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(flexible::Visitor(flexible::Unit::Millis))
+        } else {
+            i64::deserialize(deserializer).map(UtcTimeStamp::from_milliseconds)
+        }
+    }
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(all(test, feature = "serde-human-readable"))]
+mod tests {
+    use crate::UtcTimeStamp;
+    use serde_test::{assert_de_tokens, assert_tokens, Configure, Token};
+
+    #[test]
+    fn human_readable() {
+        let ts = UtcTimeStamp::from_milliseconds(1500);
+        assert_tokens(&ts.readable(), &[Token::Str("1970-01-01T00:00:01.500Z")]);
+        assert_tokens(&ts.compact(), &[Token::I64(1500)]);
+        assert_de_tokens(&ts.readable(), &[Token::I64(1500)]);
+    }
+}
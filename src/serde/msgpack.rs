@@ -0,0 +1,215 @@
+//! (De)serialize a [`UtcTimeStamp`] as a MessagePack timestamp extension.
+//!
+//! Timestamps are serialized as extension type -1 in the smallest of the
+//! 32, 64 and 96 bit forms that can hold them, as used by Fluentd, Redis and
+//! most MessagePack libraries. All three forms are accepted on
+//! deserialization, rounding nanoseconds towards the past.
+//!
+//! Extensions are passed through serde using the `_ExtStruct` newtype
+//! convention of `rmp-serde`. Other formats see a plain `(-1, bytes)` tuple.
+//!
+//! Examples:
+//!
+//! ```
+//! use serde::{Deserialize, Serialize};
+//! use utctimestamp::UtcTimeStamp;
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Record(#[serde(with = "utctimestamp::serde::msgpack")] UtcTimeStamp);
+//!
+//! let record = Record(UtcTimeStamp::from_seconds(1_600_000_000));
+//! let bytes = rmp_serde::to_vec(&record).unwrap();
+//! assert_eq!(bytes, [0xd6, 0xff, 0x5f, 0x5e, 0x10, 0x00]);
+//!
+//! let record: Record = rmp_serde::from_slice(&bytes).unwrap();
+//! assert_eq!(record.0, UtcTimeStamp::from_seconds(1_600_000_000));
+//! ```
+
+use crate::UtcTimeStamp;
+use core::{convert::TryFrom, fmt};
+use serde::{
+    de::{self, Deserialize},
+    ser::{Serialize, SerializeTuple},
+    Deserializer, Serializer,
+};
+
+const EXT_STRUCT_NAME: &str = "_ExtStruct";
+const EXT_TYPE_TIMESTAMP: i8 = -1;
+
+/// Encode the extension payload, returning the buffer and the used length.
+fn encode(ts: UtcTimeStamp) -> ([u8; 12], usize) {
+    let (secs, ms) = ts.split_seconds();
+    let nanos = ms * 1_000_000;
+    let mut buf = [0; 12];
+
+    if nanos == 0 && u32::try_from(secs).is_ok() {
+        buf[..4].copy_from_slice(&(secs as u32).to_be_bytes());
+        (buf, 4)
+    } else if (0..1 << 34).contains(&secs) {
+        let packed = u64::from(nanos) << 34 | secs as u64;
+        buf[..8].copy_from_slice(&packed.to_be_bytes());
+        (buf, 8)
+    } else {
+        buf[..4].copy_from_slice(&nanos.to_be_bytes());
+        buf[4..].copy_from_slice(&secs.to_be_bytes());
+        (buf, 12)
+    }
+}
+
+/// Decode the extension payload in any of the three forms.
+fn decode<E: de::Error>(payload: &[u8]) -> Result<UtcTimeStamp, E> {
+    let be_u32 = |x: &[u8]| u32::from_be_bytes([x[0], x[1], x[2], x[3]]);
+    let (secs, nanos) = match payload.len() {
+        4 => (i64::from(be_u32(payload)), 0),
+        8 => {
+            let packed = (u64::from(be_u32(payload)) << 32) | u64::from(be_u32(&payload[4..]));
+            ((packed & ((1 << 34) - 1)) as i64, (packed >> 34) as u32)
+        }
+        12 => {
+            let mut secs = [0; 8];
+            secs.copy_from_slice(&payload[4..]);
+            (i64::from_be_bytes(secs), be_u32(payload))
+        }
+        len => return Err(E::invalid_length(len, &"a 4, 8 or 12 byte timestamp")),
+    };
+
+    if nanos >= 1_000_000_000 {
+        return Err(E::custom("timestamp nanoseconds out of range"));
+    }
+
+    let ms = i128::from(secs) * 1000 + i128::from(nanos / 1_000_000);
+    i64::try_from(ms)
+        .map(UtcTimeStamp::from_milliseconds)
+        .map_err(|_| E::custom("timestamp out of range"))
+}
+
+struct Bytes<'a>(&'a [u8]);
+
+impl Serialize for Bytes<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(self.0)
+    }
+}
+
+struct Ext<'a>(&'a [u8]);
+
+impl Serialize for Ext<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut tuple = serializer.serialize_tuple(2)?;
+        tuple.serialize_element(&EXT_TYPE_TIMESTAMP)?;
+        tuple.serialize_element(&Bytes(self.0))?;
+        tuple.end()
+    }
+}
+
+pub fn serialize<S: Serializer>(ts: &UtcTimeStamp, serializer: S) -> Result<S::Ok, S::Error> {
+    let (buf, len) = encode(*ts);
+    serializer.serialize_newtype_struct(EXT_STRUCT_NAME, &Ext(&buf[..len]))
+}
+
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<UtcTimeStamp, D::Error> {
+    deserializer.deserialize_newtype_struct(EXT_STRUCT_NAME, Visitor)
+}
+
+struct Visitor;
+
+impl<'de> de::Visitor<'de> for Visitor {
+    type Value = UtcTimeStamp;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a MessagePack timestamp extension")
+    }
+
+    fn visit_newtype_struct<D: Deserializer<'de>>(self, d: D) -> Result<Self::Value, D::Error> {
+        d.deserialize_tuple(2, self)
+    }
+
+    fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let ext_type: i8 = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        if ext_type != EXT_TYPE_TIMESTAMP {
+            return Err(de::Error::custom(format_args!(
+                "unexpected extension type {}",
+                ext_type
+            )));
+        }
+
+        seq.next_element::<Payload>()?
+            .map(|x| x.0)
+            .ok_or_else(|| de::Error::invalid_length(1, &self))
+    }
+}
+
+/// The decoded extension payload.
+struct Payload(UtcTimeStamp);
+
+impl<'de> Deserialize<'de> for Payload {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_bytes(PayloadVisitor)
+    }
+}
+
+struct PayloadVisitor;
+
+impl<'de> de::Visitor<'de> for PayloadVisitor {
+    type Value = Payload;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("timestamp extension bytes")
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        decode(v).map(Payload)
+    }
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::UtcTimeStamp;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Wrapper(#[serde(with = "super")] UtcTimeStamp);
+
+    #[test]
+    fn round_trip() {
+        let from_msgpack = |b: &[u8]| rmp_serde::from_slice::<Wrapper>(b).map(|x| x.0);
+        let to_msgpack = |ms| rmp_serde::to_vec(&Wrapper(UtcTimeStamp::from_milliseconds(ms)));
+
+        // fixext 4, fixext 8 and ext 8 with 12 bytes.
+        assert_eq!(to_msgpack(1_000).unwrap(), [0xd6, 0xff, 0, 0, 0, 1]);
+        assert_eq!(to_msgpack(1_500).unwrap(), [
+            0xd7, 0xff, 0x77, 0x35, 0x94, 0x00, 0, 0, 0, 1
+        ]);
+        assert_eq!(to_msgpack(-1).unwrap(), [
+            0xc7, 12, 0xff, 0x3b, 0x8b, 0x87, 0xc0, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff
+        ]);
+
+        for &ms in &[
+            0,
+            -1,
+            1_600_000_000_250,
+            (1 << 34) * 1000 - 1,
+            (1 << 34) * 1000,
+            i64::MIN,
+            i64::MAX,
+        ] {
+            let ts = UtcTimeStamp::from_milliseconds(ms);
+            assert_eq!(from_msgpack(&to_msgpack(ms).unwrap()).unwrap(), ts);
+        }
+
+        // Wrong extension type, bad length and nanoseconds out of range.
+        assert!(from_msgpack(&[0xd6, 0x01, 0, 0, 0, 1]).is_err());
+        assert!(from_msgpack(&[0xd5, 0xff, 0, 1]).is_err());
+        assert!(from_msgpack(&[0xd7, 0xff, 0xff, 0xff, 0xff, 0xff, 0, 0, 0, 0]).is_err());
+        assert!(from_msgpack(&[
+            0xc7, 12, 0xff, 0, 0, 0, 0, 0x7f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff
+        ])
+        .is_err());
+    }
+}
@@ -0,0 +1,5 @@
+//! (De)serialize an `Option<UtcTimeStamp>` as a MessagePack timestamp or nil.
+//!
+//! See [`msgpack`](super::msgpack).
+
+option_module!(msgpack);
@@ -0,0 +1,90 @@
+//! (De)serialize a [`UtcTimeStamp`] as an RFC 3339 string.
+//!
+//! Timestamps are serialized in UTC with millisecond precision, e.g.
+//! `2020-09-13T12:26:40.000Z`. Any valid RFC 3339 string is accepted on
+//! deserialization; offsets are converted to UTC and sub-millisecond digits
+//! are truncated.
+//!
+//! Examples:
+//!
+//! ```
+//! use serde::{Deserialize, Serialize};
+//! use utctimestamp::UtcTimeStamp;
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Event {
+//!     #[serde(with = "utctimestamp::serde::rfc3339")]
+//!     at: UtcTimeStamp,
+//! }
+//!
+//! let event = Event { at: UtcTimeStamp::from_seconds(1_600_000_000) };
+//! let json = serde_json::to_string(&event).unwrap();
+//! assert_eq!(json, r#"{"at":"2020-09-13T12:26:40.000Z"}"#);
+//!
+//! let event: Event = serde_json::from_str(r#"{"at":"2020-09-13T14:26:40+02:00"}"#).unwrap();
+//! assert_eq!(event.at, UtcTimeStamp::from_seconds(1_600_000_000));
+//! ```
+
+use crate::UtcTimeStamp;
+use core::fmt;
+use serde::{de, Deserializer, Serializer};
+
+pub(crate) fn format(ts: UtcTimeStamp) -> Option<String> {
+    let dt = chrono::DateTime::from_timestamp_millis(ts.as_milliseconds())?;
+    Some(dt.to_rfc3339_opts(chrono::SecondsFormat::Millis, true))
+}
+
+pub(crate) fn parse(s: &str) -> Option<UtcTimeStamp> {
+    UtcTimeStamp::parse_rfc3339(s).ok()
+}
+
+pub fn serialize<S: Serializer>(ts: &UtcTimeStamp, serializer: S) -> Result<S::Ok, S::Error> {
+    let s = format(*ts).ok_or_else(|| serde::ser::Error::custom("timestamp out of range"))?;
+    serializer.serialize_str(&s)
+}
+
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<UtcTimeStamp, D::Error> {
+    deserializer.deserialize_str(Visitor)
+}
+
+pub(crate) struct Visitor;
+
+impl<'de> de::Visitor<'de> for Visitor {
+    type Value = UtcTimeStamp;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("an RFC 3339 timestamp string")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        parse(v).ok_or_else(|| E::invalid_value(de::Unexpected::Str(v), &self))
+    }
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::UtcTimeStamp;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Wrapper(#[serde(with = "super")] UtcTimeStamp);
+
+    #[test]
+    fn round_trip() {
+        let ts = Wrapper(UtcTimeStamp::from_milliseconds(-1));
+        let json = serde_json::to_string(&ts).unwrap();
+        assert_eq!(json, r#""1969-12-31T23:59:59.999Z""#);
+        assert_eq!(serde_json::from_str::<Wrapper>(&json).unwrap(), ts);
+
+        assert_eq!(
+            serde_json::from_str::<Wrapper>(r#""1970-01-01T00:00:01.2345Z""#).unwrap(),
+            Wrapper(UtcTimeStamp::from_milliseconds(1234))
+        );
+        assert!(serde_json::from_str::<Wrapper>(r#""1970-01-01""#).is_err());
+        assert!(serde_json::from_str::<Wrapper>("0").is_err());
+    }
+}
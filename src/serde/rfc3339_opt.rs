@@ -0,0 +1,60 @@
+//! (De)serialize an `Option<UtcTimeStamp>` as an RFC 3339 string or null.
+//!
+//! See [`rfc3339`](super::rfc3339) for the string format.
+//!
+//! Examples:
+//!
+//! ```
+//! use serde::{Deserialize, Serialize};
+//! use utctimestamp::UtcTimeStamp;
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Order {
+//!     #[serde(with = "utctimestamp::serde::rfc3339_opt", default)]
+//!     closed_at: Option<UtcTimeStamp>,
+//! }
+//!
+//! let order: Order = serde_json::from_str(r#"{"closed_at":null}"#).unwrap();
+//! assert_eq!(order.closed_at, None);
+//!
+//! let order: Order = serde_json::from_str(r#"{}"#).unwrap();
+//! assert_eq!(order.closed_at, None);
+//! ```
+
+option_module!(rfc3339);
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::UtcTimeStamp;
+    use serde::{Deserialize, Serialize};
+    use serde_test::{assert_tokens, Token};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Wrapper(#[serde(with = "super")] Option<UtcTimeStamp>);
+
+    #[test]
+    fn round_trip() {
+        for ts in [None, Some(UtcTimeStamp::from_seconds(86400))].iter() {
+            let json = serde_json::to_string(&Wrapper(*ts)).unwrap();
+            assert_eq!(
+                serde_json::from_str::<Wrapper>(&json).unwrap(),
+                Wrapper(*ts)
+            );
+        }
+        assert_eq!(
+            serde_json::to_string(&Wrapper(Some(UtcTimeStamp::zero()))).unwrap(),
+            r#""1970-01-01T00:00:00.000Z""#
+        );
+        assert_eq!(serde_json::to_string(&Wrapper(None)).unwrap(), "null");
+
+        assert_tokens(&Wrapper(Some(UtcTimeStamp::zero())), &[
+            Token::NewtypeStruct { name: "Wrapper" },
+            Token::Some,
+            Token::Str("1970-01-01T00:00:00.000Z"),
+        ]);
+    }
+}
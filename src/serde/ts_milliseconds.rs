@@ -0,0 +1,55 @@
+//! (De)serialize a [`UtcTimeStamp`] as integer milliseconds since the epoch.
+//!
+//! Unlike the derived impl, which serializes a newtype struct, this always
+//! emits a plain `i64` and is wire compatible with
+//! `chrono::serde::ts_milliseconds`.
+
+use crate::UtcTimeStamp;
+use serde::{Deserialize, Deserializer, Serializer};
+
+pub fn serialize<S: Serializer>(ts: &UtcTimeStamp, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_i64(ts.as_milliseconds())
+}
+
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<UtcTimeStamp, D::Error> {
+    i64::deserialize(deserializer).map(UtcTimeStamp::from_milliseconds)
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::UtcTimeStamp;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Ours(#[serde(with = "super")] UtcTimeStamp);
+
+    #[derive(Serialize, Deserialize)]
+    struct Chrono(#[serde(with = "chrono::serde::ts_milliseconds")] chrono::DateTime<chrono::Utc>);
+
+    #[test]
+    fn chrono_compat() {
+        for &ms in &[0, -1, 1_600_000_000_123, -62_135_596_800_000] {
+            let ours = serde_json::to_string(&Ours(UtcTimeStamp::from_milliseconds(ms))).unwrap();
+            let theirs = Chrono(chrono::DateTime::from_timestamp_millis(ms).unwrap());
+            assert_eq!(ours, serde_json::to_string(&theirs).unwrap());
+            assert_eq!(
+                serde_json::from_str::<Chrono>(&ours)
+                    .unwrap()
+                    .0
+                    .timestamp_millis(),
+                ms
+            );
+            assert_eq!(
+                serde_json::from_str::<Ours>(&ours)
+                    .unwrap()
+                    .0
+                    .as_milliseconds(),
+                ms
+            );
+        }
+    }
+}
@@ -0,0 +1,5 @@
+//! (De)serialize an `Option<UtcTimeStamp>` as integer milliseconds or null.
+//!
+//! Wire compatible with `chrono::serde::ts_milliseconds_option`.
+
+option_module!(ts_milliseconds);
@@ -0,0 +1,66 @@
+//! (De)serialize a [`UtcTimeStamp`] as integer nanoseconds since the epoch.
+//!
+//! Wire compatible with `chrono::serde::ts_nanoseconds`. Deserialized values
+//! are truncated towards the past to millisecond precision. Serialization
+//! fails for timestamps outside the `i64` nanosecond range, roughly the years
+//! 1677 to 2262.
+
+use crate::UtcTimeStamp;
+use serde::{ser, Deserialize, Deserializer, Serializer};
+
+pub fn serialize<S: Serializer>(ts: &UtcTimeStamp, serializer: S) -> Result<S::Ok, S::Error> {
+    let nanos = ts.as_milliseconds().checked_mul(1_000_000).ok_or_else(|| {
+        ser::Error::custom("value out of range for a timestamp with nanosecond precision")
+    })?;
+    serializer.serialize_i64(nanos)
+}
+
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<UtcTimeStamp, D::Error> {
+    let nanos = i64::deserialize(deserializer)?;
+    Ok(UtcTimeStamp::from_milliseconds(nanos.div_euclid(1_000_000)))
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::UtcTimeStamp;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Ours(#[serde(with = "super")] UtcTimeStamp);
+
+    #[derive(Serialize, Deserialize)]
+    struct Chrono(#[serde(with = "chrono::serde::ts_nanoseconds")] chrono::DateTime<chrono::Utc>);
+
+    #[test]
+    fn chrono_compat() {
+        for &ms in &[0, -1, 1_600_000_000_123, -9_000_000_000_000] {
+            let ours = serde_json::to_string(&Ours(UtcTimeStamp::from_milliseconds(ms))).unwrap();
+            let theirs = Chrono(chrono::DateTime::from_timestamp_millis(ms).unwrap());
+            assert_eq!(ours, serde_json::to_string(&theirs).unwrap());
+            assert_eq!(
+                serde_json::from_str::<Ours>(&ours)
+                    .unwrap()
+                    .0
+                    .as_milliseconds(),
+                ms
+            );
+        }
+
+        let theirs = serde_json::to_string(&Chrono(
+            chrono::DateTime::from_timestamp(-1, 999_999_999).unwrap(),
+        ))
+        .unwrap();
+        assert_eq!(
+            serde_json::from_str::<Ours>(&theirs)
+                .unwrap()
+                .0
+                .as_milliseconds(),
+            -1
+        );
+        assert!(serde_json::to_string(&Ours(UtcTimeStamp::from_seconds(10_000_000_000))).is_err());
+    }
+}
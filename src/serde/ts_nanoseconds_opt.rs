@@ -0,0 +1,5 @@
+//! (De)serialize an `Option<UtcTimeStamp>` as integer nanoseconds or null.
+//!
+//! Wire compatible with `chrono::serde::ts_nanoseconds_option`.
+
+option_module!(ts_nanoseconds);
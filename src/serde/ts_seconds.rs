@@ -0,0 +1,70 @@
+//! (De)serialize a [`UtcTimeStamp`] as integer seconds since the epoch.
+//!
+//! Sub-second precision is truncated towards the past on serialization. Wire
+//! compatible with `chrono::serde::ts_seconds`.
+//!
+//! Examples:
+//!
+//! ```
+//! use serde::{Deserialize, Serialize};
+//! use utctimestamp::UtcTimeStamp;
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Event {
+//!     #[serde(with = "utctimestamp::serde::ts_seconds")]
+//!     at: UtcTimeStamp,
+//! }
+//!
+//! let event: Event = serde_json::from_str(r#"{"at":1600000000}"#).unwrap();
+//! assert_eq!(event.at, UtcTimeStamp::from_seconds(1_600_000_000));
+//! ```
+
+use crate::UtcTimeStamp;
+use serde::{de, Deserialize, Deserializer, Serializer};
+
+pub fn serialize<S: Serializer>(ts: &UtcTimeStamp, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_i64(ts.as_seconds())
+}
+
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<UtcTimeStamp, D::Error> {
+    let secs = i64::deserialize(deserializer)?;
+    secs.checked_mul(1000)
+        .map(UtcTimeStamp::from_milliseconds)
+        .ok_or_else(|| de::Error::custom("timestamp out of range"))
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::UtcTimeStamp;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Wrapper(#[serde(with = "super")] UtcTimeStamp);
+
+    #[test]
+    fn round_trip() {
+        let ts = Wrapper(UtcTimeStamp::from_milliseconds(-1500));
+        assert_eq!(serde_json::to_string(&ts).unwrap(), "-2");
+        assert_eq!(
+            serde_json::from_str::<Wrapper>("-2").unwrap(),
+            Wrapper(UtcTimeStamp::from_seconds(-2))
+        );
+        assert!(serde_json::from_str::<Wrapper>(&i64::MAX.to_string()).is_err());
+    }
+
+    #[derive(Serialize)]
+    struct Chrono(#[serde(with = "chrono::serde::ts_seconds")] chrono::DateTime<chrono::Utc>);
+
+    #[test]
+    fn chrono_compat() {
+        for &ms in &[0, -1, -1000, 1_600_000_000_999] {
+            let ours = serde_json::to_string(&Wrapper(UtcTimeStamp::from_milliseconds(ms)));
+            let theirs = Chrono(chrono::DateTime::from_timestamp_millis(ms).unwrap());
+            assert_eq!(ours.unwrap(), serde_json::to_string(&theirs).unwrap());
+        }
+    }
+}
@@ -0,0 +1,83 @@
+//! (De)serialize a [`UtcTimeStamp`] as fractional seconds since the epoch.
+//!
+//! Both floats and integers are accepted on deserialization and rounded to the
+//! nearest millisecond, see [`UtcTimeStamp::from_seconds_f64`].
+//!
+//! Examples:
+//!
+//! ```
+//! use serde::{Deserialize, Serialize};
+//! use utctimestamp::UtcTimeStamp;
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Sample {
+//!     #[serde(with = "utctimestamp::serde::ts_seconds_f64")]
+//!     at: UtcTimeStamp,
+//! }
+//!
+//! let sample: Sample = serde_json::from_str(r#"{"at":1694791200.125}"#).unwrap();
+//! assert_eq!(sample.at.as_milliseconds(), 1_694_791_200_125);
+//! assert_eq!(serde_json::to_string(&sample).unwrap(), r#"{"at":1694791200.125}"#);
+//! ```
+
+use crate::UtcTimeStamp;
+use core::fmt;
+use serde::{de, Deserializer, Serializer};
+
+pub fn serialize<S: Serializer>(ts: &UtcTimeStamp, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_f64(ts.as_seconds_f64())
+}
+
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<UtcTimeStamp, D::Error> {
+    deserializer.deserialize_f64(Visitor)
+}
+
+struct Visitor;
+
+impl<'de> de::Visitor<'de> for Visitor {
+    type Value = UtcTimeStamp;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("epoch seconds")
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<Self::Value, E> {
+        UtcTimeStamp::from_seconds_f64(v).ok_or_else(|| E::custom("timestamp out of range"))
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+        v.checked_mul(1000)
+            .map(UtcTimeStamp::from_milliseconds)
+            .ok_or_else(|| E::custom("timestamp out of range"))
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+        self.visit_f64(v as f64)
+    }
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::UtcTimeStamp;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Wrapper(#[serde(with = "super")] UtcTimeStamp);
+
+    #[test]
+    fn round_trip() {
+        let ms = |s: &str| serde_json::from_str::<Wrapper>(s).map(|x| x.0.as_milliseconds());
+        assert_eq!(ms("1.0005").unwrap(), 1001);
+        assert_eq!(ms("-2").unwrap(), -2000);
+        assert_eq!(ms("1e3").unwrap(), 1_000_000);
+        assert!(ms("1e300").is_err());
+        assert!(ms(r#""1""#).is_err());
+
+        let ts = Wrapper(UtcTimeStamp::from_milliseconds(-1500));
+        assert_eq!(serde_json::to_string(&ts).unwrap(), "-1.5");
+    }
+}
@@ -0,0 +1,5 @@
+//! (De)serialize an `Option<UtcTimeStamp>` as fractional seconds or null.
+//!
+//! See [`ts_seconds_f64`](super::ts_seconds_f64).
+
+option_module!(ts_seconds_f64);
@@ -0,0 +1,32 @@
+//! (De)serialize an `Option<UtcTimeStamp>` as integer seconds or null.
+//!
+//! See [`ts_seconds`](super::ts_seconds).
+
+option_module!(ts_seconds);
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::UtcTimeStamp;
+    use serde::{Deserialize, Serialize};
+    use serde_test::{assert_tokens, Token};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Wrapper(#[serde(with = "super")] Option<UtcTimeStamp>);
+
+    #[test]
+    fn round_trip() {
+        assert_tokens(&Wrapper(Some(UtcTimeStamp::from_seconds(5))), &[
+            Token::NewtypeStruct { name: "Wrapper" },
+            Token::Some,
+            Token::I64(5),
+        ]);
+        assert_tokens(&Wrapper(None), &[
+            Token::NewtypeStruct { name: "Wrapper" },
+            Token::None,
+        ]);
+    }
+}
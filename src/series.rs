@@ -0,0 +1,219 @@
+//! Time series of values keyed by sorted timestamps.
+
+use crate::{merge_sorted, search, NotSortedError, TimeInterval, UtcTimeStamp};
+use core::iter::{self, FromIterator};
+
+/// A series of values, each associated with a timestamp, kept sorted by time.
+///
+/// Timestamps and values are stored in separate vectors, so the timestamp
+/// column can be searched and sliced without touching the values. Duplicate
+/// timestamps are permitted and keep their insertion order.
+///
+/// Examples:
+///
+/// ```
+/// use utctimestamp::{TimeInterval, TimeSeries, UtcTimeStamp};
+///
+/// let ts = UtcTimeStamp::from_seconds;
+/// let prices: TimeSeries<f64> = vec![(ts(3), 10.5), (ts(1), 10.0), (ts(7), 11.0)]
+///     .into_iter()
+///     .collect();
+///
+/// assert_eq!(prices.asof(ts(5)), Some((ts(3), &10.5)));
+/// assert_eq!(prices.range(TimeInterval::new(ts(0), ts(5))).1, &[10.0, 10.5]);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TimeSeries<T> {
+    timestamps: Vec<UtcTimeStamp>,
+    values: Vec<T>,
+}
+
+impl<T> TimeSeries<T> {
+    /// Create an empty series.
+    pub const fn new() -> Self {
+        TimeSeries {
+            timestamps: Vec::new(),
+            values: Vec::new(),
+        }
+    }
+
+    /// Create a series from parallel vectors, verifying that the timestamps are sorted.
+    ///
+    /// Panics if the vectors differ in length.
+    pub fn from_sorted(
+        timestamps: Vec<UtcTimeStamp>,
+        values: Vec<T>,
+    ) -> Result<Self, NotSortedError> {
+        assert_eq!(
+            timestamps.len(),
+            values.len(),
+            "timestamp and value counts differ"
+        );
+        match timestamps.windows(2).position(|x| x[0] > x[1]) {
+            Some(index) => Err(NotSortedError { index: index + 1 }),
+            None => Ok(TimeSeries { timestamps, values }),
+        }
+    }
+
+    /// Create a series from `(timestamp, value)` pairs in arbitrary order.
+    ///
+    /// The sort is stable, so values with equal timestamps keep their order.
+    pub fn from_unsorted(mut pairs: Vec<(UtcTimeStamp, T)>) -> Self {
+        if pairs.windows(2).any(|x| x[0].0 > x[1].0) {
+            pairs.sort_by_key(|x| x.0);
+        }
+        let (timestamps, values) = pairs.into_iter().unzip();
+        TimeSeries { timestamps, values }
+    }
+
+    /// Insert a value at its sorted position, after any values with equal timestamps.
+    pub fn insert(&mut self, ts: UtcTimeStamp, value: T) {
+        let idx = self.timestamps.partition_point(|x| *x <= ts);
+        self.timestamps.insert(idx, ts);
+        self.values.insert(idx, value);
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.timestamps.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.timestamps.is_empty()
+    }
+
+    /// The sorted timestamp column.
+    #[inline]
+    pub fn timestamps(&self) -> &[UtcTimeStamp] {
+        &self.timestamps
+    }
+
+    /// The value column, in timestamp order.
+    #[inline]
+    pub fn values(&self) -> &[T] {
+        &self.values
+    }
+
+    /// Mutable access to the value column.
+    #[inline]
+    pub fn values_mut(&mut self) -> &mut [T] {
+        &mut self.values
+    }
+
+    /// Split the series into its timestamp and value columns.
+    pub fn into_parts(self) -> (Vec<UtcTimeStamp>, Vec<T>) {
+        (self.timestamps, self.values)
+    }
+
+    /// The entry at `idx`.
+    pub fn get(&self, idx: usize) -> Option<(UtcTimeStamp, &T)> {
+        Some((*self.timestamps.get(idx)?, &self.values[idx]))
+    }
+
+    /// The earliest entry.
+    pub fn first(&self) -> Option<(UtcTimeStamp, &T)> {
+        self.get(0)
+    }
+
+    /// The latest entry.
+    pub fn last(&self) -> Option<(UtcTimeStamp, &T)> {
+        self.get(self.len().checked_sub(1)?)
+    }
+
+    /// Timestamps and values of all entries within the interval.
+    pub fn range(&self, interval: TimeInterval) -> (&[UtcTimeStamp], &[T]) {
+        let idx = search::range_indices(&self.timestamps, interval);
+        (&self.timestamps[idx.clone()], &self.values[idx])
+    }
+
+    /// The latest entry at or before `ts`, see [`search::asof`].
+    pub fn asof(&self, ts: UtcTimeStamp) -> Option<(UtcTimeStamp, &T)> {
+        self.get(search::asof(&self.timestamps, ts)?)
+    }
+
+    /// Iterate over all entries in timestamp order.
+    pub fn iter(&self) -> impl Iterator<Item = (UtcTimeStamp, &T)> + '_ {
+        self.timestamps.iter().copied().zip(&self.values)
+    }
+
+    /// Merge two series into one.
+    ///
+    /// Entries with equal timestamps from `self` precede those from `other`.
+    pub fn merge(self, other: Self) -> Self {
+        let mut merged = TimeSeries {
+            timestamps: Vec::with_capacity(self.len() + other.len()),
+            values: Vec::with_capacity(self.len() + other.len()),
+        };
+        for (ts, value) in merge_sorted(vec![self.into_iter(), other.into_iter()]) {
+            merged.timestamps.push(ts);
+            merged.values.push(value);
+        }
+        merged
+    }
+}
+
+impl<T> Default for TimeSeries<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> FromIterator<(UtcTimeStamp, T)> for TimeSeries<T> {
+    fn from_iter<I: IntoIterator<Item = (UtcTimeStamp, T)>>(iter: I) -> Self {
+        TimeSeries::from_unsorted(iter.into_iter().collect())
+    }
+}
+
+impl<T> IntoIterator for TimeSeries<T> {
+    type Item = (UtcTimeStamp, T);
+    type IntoIter = iter::Zip<std::vec::IntoIter<UtcTimeStamp>, std::vec::IntoIter<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.timestamps.into_iter().zip(self.values)
+    }
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn ts(s: i64) -> UtcTimeStamp {
+        UtcTimeStamp::from_seconds(s)
+    }
+
+    #[test]
+    fn time_series() {
+        assert_eq!(
+            TimeSeries::from_sorted(vec![ts(2), ts(1)], vec!['a', 'b']),
+            Err(NotSortedError { index: 1 })
+        );
+
+        let mut series = TimeSeries::from_unsorted(vec![(ts(5), 'c'), (ts(1), 'a'), (ts(5), 'd')]);
+        series.insert(ts(3), 'b');
+        series.insert(ts(5), 'e');
+        assert_eq!(series.timestamps(), &[ts(1), ts(3), ts(5), ts(5), ts(5)]);
+        assert_eq!(series.values(), &['a', 'b', 'c', 'd', 'e']);
+        assert_eq!(series.first(), Some((ts(1), &'a')));
+        assert_eq!(series.last(), Some((ts(5), &'e')));
+
+        assert_eq!(series.asof(ts(0)), None);
+        assert_eq!(series.asof(ts(4)), Some((ts(3), &'b')));
+        assert_eq!(
+            series.range(TimeInterval::new(ts(2), ts(5))),
+            (&[ts(3)][..], &['b'][..])
+        );
+        assert_eq!(series.iter().map(|x| *x.1).collect::<String>(), "abcde");
+
+        let other =
+            TimeSeries::from_sorted(vec![ts(0), ts(3), ts(9)], vec!['x', 'y', 'z']).unwrap();
+        let merged = series.merge(other);
+        assert_eq!(merged.values(), &['x', 'a', 'b', 'y', 'c', 'd', 'e', 'z']);
+        assert_eq!(merged.len(), 8);
+        assert!(TimeSeries::<()>::default().last().is_none());
+    }
+}
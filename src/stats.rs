@@ -0,0 +1,312 @@
+//! Statistics over timestamp series.
+
+use crate::{resample::bucket_start, TimeDelta, UtcTimeStamp};
+
+// ============================================================================================== //
+// [Frequency inference]                                                                          //
+// ============================================================================================== //
+
+/// Infer the regular spacing of a sorted timestamp series.
+///
+/// Returns the spacing shared by the majority of consecutive timestamps, or
+/// `None` if there is no such spacing. Duplicate timestamps are ignored. See
+/// [`infer_freq_within`] for series with jitter.
+///
+/// Examples:
+///
+/// ```
+/// use utctimestamp::{infer_freq, TimeDelta, UtcTimeStamp};
+///
+/// // One missing sample doesn't prevent detecting the native cadence.
+/// let ts: Vec<_> = [0, 60, 120, 240, 300].iter().map(|&s| UtcTimeStamp::from_seconds(s)).collect();
+/// assert_eq!(infer_freq(&ts), Some(TimeDelta::from_minutes(1)));
+/// ```
+pub fn infer_freq(sorted: &[UtcTimeStamp]) -> Option<TimeDelta> {
+    infer_freq_within(sorted, TimeDelta::zero())
+}
+
+/// Infer the regular spacing of a sorted timestamp series with jitter.
+///
+/// Returns the median spacing if the majority of spacings lie within
+/// `tolerance` of it, `None` otherwise. Duplicate timestamps are ignored.
+pub fn infer_freq_within(sorted: &[UtcTimeStamp], tolerance: TimeDelta) -> Option<TimeDelta> {
+    let mut deltas: Vec<_> = sorted
+        .windows(2)
+        .map(|x| (x[1] - x[0]).as_milliseconds())
+        .filter(|x| *x > 0)
+        .collect();
+
+    deltas.sort_unstable();
+    freq_from_sorted_deltas(&deltas, tolerance)
+}
+
+/// Shared tail of the frequency inference, operating on sorted positive deltas.
+pub(crate) fn freq_from_sorted_deltas(deltas: &[i64], tolerance: TimeDelta) -> Option<TimeDelta> {
+    if deltas.is_empty() {
+        return None;
+    }
+
+    let median = deltas[(deltas.len() - 1) / 2];
+    let tolerance = tolerance.as_milliseconds();
+    let matching = deltas
+        .iter()
+        .filter(|x| (**x - median).abs() <= tolerance)
+        .count();
+
+    if matching * 2 > deltas.len() {
+        Some(TimeDelta::from_milliseconds(median))
+    } else {
+        None
+    }
+}
+
+// ============================================================================================== //
+// [Summary]                                                                                      //
+// ============================================================================================== //
+
+/// Summary statistics of a timestamp or delta slice.
+///
+/// Means are accumulated in 128 bit, so they can't overflow, and rounded
+/// towards negative infinity. The median of an even number of elements is the
+/// mean of the two middle ones.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Summary<T> {
+    pub min: T,
+    pub max: T,
+    pub mean: T,
+    pub median: T,
+}
+
+impl<T> Summary<T> {
+    fn map<U>(self, f: impl Fn(T) -> U) -> Summary<U> {
+        Summary {
+            min: f(self.min),
+            max: f(self.max),
+            mean: f(self.mean),
+            median: f(self.median),
+        }
+    }
+}
+
+fn mean_ms(a: i64, b: i64) -> i64 {
+    (a as i128 + b as i128).div_euclid(2) as i64
+}
+
+fn summarize_ms(mut millis: Vec<i64>) -> Option<Summary<i64>> {
+    let len = millis.len();
+    let (min, max) = millis.iter().fold(None, |acc, &x| match acc {
+        None => Some((x, x)),
+        Some((min, max)) => Some((i64::min(min, x), i64::max(max, x))),
+    })?;
+    let sum: i128 = millis.iter().map(|&x| x as i128).sum();
+    let mean = sum.div_euclid(len as i128) as i64;
+
+    let (lower, &mut upper, _) = millis.select_nth_unstable(len / 2);
+    let median = if len % 2 == 1 {
+        upper
+    } else {
+        mean_ms(*lower.iter().max().unwrap(), upper)
+    };
+
+    Some(Summary {
+        min,
+        max,
+        mean,
+        median,
+    })
+}
+
+/// Summary statistics of a timestamp slice in arbitrary order.
+///
+/// Returns `None` for an empty slice.
+///
+/// Examples:
+///
+/// ```
+/// use utctimestamp::{delta_summary, timestamp_summary, UtcTimeStamp};
+///
+/// let ts: Vec<_> = [30, 10, 20, 60].iter().map(|&s| UtcTimeStamp::from_seconds(s)).collect();
+/// let summary = timestamp_summary(&ts).unwrap();
+/// assert_eq!(summary.min, UtcTimeStamp::from_seconds(10));
+/// assert_eq!(summary.max, UtcTimeStamp::from_seconds(60));
+/// assert_eq!(summary.mean, UtcTimeStamp::from_seconds(30));
+/// assert_eq!(summary.median, UtcTimeStamp::from_seconds(25));
+///
+/// let spacing: Vec<_> = ts.windows(2).map(|x| x[1] - x[0]).collect();
+/// assert_eq!(delta_summary(&spacing).unwrap().max.as_milliseconds(), 40_000);
+/// ```
+pub fn timestamp_summary(timestamps: &[UtcTimeStamp]) -> Option<Summary<UtcTimeStamp>> {
+    let millis = timestamps.iter().map(|x| x.as_milliseconds()).collect();
+    Some(summarize_ms(millis)?.map(UtcTimeStamp::from_milliseconds))
+}
+
+/// Summary statistics of a delta slice in arbitrary order, see [`timestamp_summary`].
+pub fn delta_summary(deltas: &[TimeDelta]) -> Option<Summary<TimeDelta>> {
+    let millis = deltas.iter().map(|x| x.as_milliseconds()).collect();
+    Some(summarize_ms(millis)?.map(TimeDelta::from_milliseconds))
+}
+
+// ============================================================================================== //
+// [Histogram]                                                                                    //
+// ============================================================================================== //
+
+/// Count the timestamps falling into each `freq` sized bucket aligned to `anchor`.
+///
+/// Returns `(bucket start, count)` pairs in ascending order, omitting empty
+/// buckets. Buckets are computed like in [`Resampler::anchored`]. Sorted input
+/// is counted in a single pass; unsorted input is accepted but requires an
+/// additional sort of the buckets.
+///
+/// [`Resampler::anchored`]: crate::Resampler::anchored
+///
+/// Examples:
+///
+/// ```
+/// use utctimestamp::{bucket_counts, TimeDelta, UtcTimeStamp};
+///
+/// let ts: Vec<_> = [1, 5, 61, 62, 63, 200].iter().map(|&s| UtcTimeStamp::from_seconds(s)).collect();
+/// let counts = bucket_counts(&ts, UtcTimeStamp::zero(), TimeDelta::from_minutes(1));
+///
+/// assert_eq!(counts, vec![
+///     (UtcTimeStamp::from_seconds(0), 2),
+///     (UtcTimeStamp::from_seconds(60), 3),
+///     (UtcTimeStamp::from_seconds(180), 1),
+/// ]);
+/// ```
+pub fn bucket_counts(
+    timestamps: &[UtcTimeStamp],
+    anchor: UtcTimeStamp,
+    freq: TimeDelta,
+) -> Vec<(UtcTimeStamp, u64)> {
+    assert!(freq.is_positive(), "bucket frequency must be positive");
+
+    let mut counts: Vec<(UtcTimeStamp, u64)> = Vec::new();
+    let mut sorted = true;
+    for &ts in timestamps {
+        let bucket = bucket_start(ts, anchor, freq);
+        match counts.last_mut() {
+            Some(last) if last.0 == bucket => last.1 += 1,
+            Some(last) => {
+                sorted &= last.0 < bucket;
+                counts.push((bucket, 1));
+            }
+            None => counts.push((bucket, 1)),
+        }
+    }
+
+    if !sorted {
+        counts.sort_unstable_by_key(|x| x.0);
+        counts.dedup_by(|next, prev| {
+            if next.0 == prev.0 {
+                prev.1 += next.1;
+                true
+            } else {
+                false
+            }
+        });
+    }
+
+    counts
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn ts(ms: &[i64]) -> Vec<UtcTimeStamp> {
+        ms.iter()
+            .map(|&x| UtcTimeStamp::from_milliseconds(x))
+            .collect()
+    }
+
+    #[test]
+    fn infer() {
+        assert_eq!(
+            infer_freq(&ts(&[0, 10, 20, 30])),
+            Some(TimeDelta::from_milliseconds(10))
+        );
+        assert_eq!(
+            infer_freq(&ts(&[0, 10, 10, 20, 50])),
+            Some(TimeDelta::from_milliseconds(10))
+        );
+        assert_eq!(infer_freq(&ts(&[0, 10, 30, 60])), None);
+        assert_eq!(infer_freq(&ts(&[0, 10, 30])), None);
+        assert_eq!(infer_freq(&ts(&[5])), None);
+        assert_eq!(infer_freq(&ts(&[5, 5])), None);
+
+        let jitter = ts(&[0, 998, 2001, 3000, 4003, 9000]);
+        assert_eq!(infer_freq(&jitter), None);
+        assert_eq!(
+            infer_freq_within(&jitter, TimeDelta::from_milliseconds(5)),
+            Some(TimeDelta::from_milliseconds(1003))
+        );
+    }
+
+    #[test]
+    fn histogram() {
+        let freq = TimeDelta::from_milliseconds(10);
+        let anchor = UtcTimeStamp::from_milliseconds(5);
+        let bucket = UtcTimeStamp::from_milliseconds;
+
+        assert_eq!(bucket_counts(&[], anchor, freq), vec![]);
+        assert_eq!(
+            bucket_counts(&ts(&[-6, 4, 5, 14, 15, 44]), anchor, freq),
+            vec![
+                (bucket(-15), 1),
+                (bucket(-5), 1),
+                (bucket(5), 2),
+                (bucket(15), 1),
+                (bucket(35), 1)
+            ]
+        );
+        assert_eq!(bucket_counts(&ts(&[30, 5, 31, 6, 29]), anchor, freq), vec![
+            (bucket(5), 2),
+            (bucket(25), 3)
+        ]);
+    }
+
+    #[test]
+    fn summary() {
+        assert_eq!(timestamp_summary(&[]), None);
+        assert_eq!(
+            timestamp_summary(&ts(&[7]))
+                .unwrap()
+                .map(|x| x.as_milliseconds()),
+            Summary {
+                min: 7,
+                max: 7,
+                mean: 7,
+                median: 7
+            }
+        );
+        assert_eq!(
+            timestamp_summary(&ts(&[5, -4, 1, 3]))
+                .unwrap()
+                .map(|x| x.as_milliseconds()),
+            Summary {
+                min: -4,
+                max: 5,
+                mean: 1,
+                median: 2
+            }
+        );
+
+        // Sums far beyond the i64 range are fine.
+        let huge = ts(&[i64::MAX, i64::MAX - 2, i64::MAX - 4]);
+        let summary = timestamp_summary(&huge).unwrap();
+        assert_eq!(summary.mean.as_milliseconds(), i64::MAX - 2);
+        assert_eq!(summary.median.as_milliseconds(), i64::MAX - 2);
+
+        let deltas: Vec<_> = [-3, -2]
+            .iter()
+            .map(|&x| TimeDelta::from_milliseconds(x))
+            .collect();
+        let summary = delta_summary(&deltas).unwrap();
+        assert_eq!(summary.mean, TimeDelta::from_milliseconds(-3));
+        assert_eq!(summary.median, TimeDelta::from_milliseconds(-3));
+    }
+}
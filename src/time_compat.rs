@@ -0,0 +1,80 @@
+//! Conversions from and to the `time` crate.
+
+use crate::{OutOfRangeError, TimeDelta, UtcTimeStamp};
+use core::convert::TryFrom;
+
+/// Convert a `time` date time, rounding towards the past to whole milliseconds.
+///
+/// The offset is applied to get the UTC time.
+impl From<time::OffsetDateTime> for UtcTimeStamp {
+    fn from(other: time::OffsetDateTime) -> Self {
+        let ms = other.unix_timestamp_nanos().div_euclid(1_000_000);
+        UtcTimeStamp::from_milliseconds(ms as i64)
+    }
+}
+
+/// Convert to a `time` date time in UTC.
+///
+/// Fails for years beyond the range supported by `time`, by default ±9999.
+impl TryFrom<UtcTimeStamp> for time::OffsetDateTime {
+    type Error = OutOfRangeError;
+
+    fn try_from(other: UtcTimeStamp) -> Result<Self, Self::Error> {
+        let nanos = i128::from(other.as_milliseconds()) * 1_000_000;
+        time::OffsetDateTime::from_unix_timestamp_nanos(nanos).map_err(|_| OutOfRangeError)
+    }
+}
+
+/// Convert a `time` duration, truncating sub-millisecond precision.
+///
+/// Fails for durations longer than `i64::MAX` milliseconds.
+impl TryFrom<time::Duration> for TimeDelta {
+    type Error = OutOfRangeError;
+
+    fn try_from(other: time::Duration) -> Result<Self, Self::Error> {
+        i64::try_from(other.whole_milliseconds())
+            .map(TimeDelta::from_milliseconds)
+            .map_err(|_| OutOfRangeError)
+    }
+}
+
+/// Convert to a `time` duration.
+impl From<TimeDelta> for time::Duration {
+    fn from(other: TimeDelta) -> Self {
+        time::Duration::milliseconds(other.as_milliseconds())
+    }
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use core::convert::TryFrom;
+    use time::{Duration, OffsetDateTime, UtcOffset};
+
+    #[test]
+    fn time_crate() {
+        let dt = OffsetDateTime::from_unix_timestamp_nanos(-1_500_000_001).unwrap();
+        let ts = UtcTimeStamp::from(dt);
+        assert_eq!(ts.as_milliseconds(), -1501);
+        assert_eq!(
+            OffsetDateTime::try_from(ts),
+            Ok(OffsetDateTime::from_unix_timestamp_nanos(-1_501_000_000).unwrap())
+        );
+
+        let local = dt.to_offset(UtcOffset::from_hms(2, 0, 0).unwrap());
+        assert_eq!(UtcTimeStamp::from(local), ts);
+        assert_eq!(
+            OffsetDateTime::try_from(UtcTimeStamp::from_milliseconds(i64::MAX)),
+            Err(OutOfRangeError)
+        );
+
+        let delta = TimeDelta::try_from(Duration::microseconds(-1999)).unwrap();
+        assert_eq!(delta, TimeDelta::from_milliseconds(-1));
+        assert_eq!(Duration::from(delta), Duration::milliseconds(-1));
+        assert_eq!(TimeDelta::try_from(Duration::MAX), Err(OutOfRangeError));
+    }
+}
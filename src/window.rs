@@ -0,0 +1,191 @@
+//! Trailing time windows over sorted timestamped values.
+
+use crate::{TimeDelta, UtcTimeStamp};
+
+// ============================================================================================== //
+// [Slice windows]                                                                                //
+// ============================================================================================== //
+
+/// Iterate over the trailing windows of a slice sorted by timestamp.
+///
+/// For each element at time `t`, the window contains all elements up to and
+/// including it whose timestamp lies within `(t - width, t]`.
+///
+/// Panics if `width` isn't positive.
+///
+/// Examples:
+///
+/// ```
+/// use utctimestamp::{rolling_windows, TimeDelta, UtcTimeStamp};
+///
+/// let data: Vec<_> = [0, 2, 3, 7].iter().map(|&s| (UtcTimeStamp::from_seconds(s), s)).collect();
+/// let sums: Vec<i64> = rolling_windows(&data, TimeDelta::from_seconds(5))
+///     .map(|w| w.iter().map(|x| x.1).sum())
+///     .collect();
+///
+/// assert_eq!(sums, vec![0, 2, 5, 10]);
+/// ```
+pub fn rolling_windows<T>(data: &[(UtcTimeStamp, T)], width: TimeDelta) -> RollingWindows<'_, T> {
+    assert!(width.is_positive(), "width must be positive");
+    RollingWindows {
+        data,
+        width,
+        start: 0,
+        end: 0,
+    }
+}
+
+/// Iterator over trailing windows of a slice, see [`rolling_windows`].
+#[derive(Debug, Clone)]
+pub struct RollingWindows<'a, T> {
+    data: &'a [(UtcTimeStamp, T)],
+    width: TimeDelta,
+    start: usize,
+    end: usize,
+}
+
+impl<'a, T> Iterator for RollingWindows<'a, T> {
+    type Item = &'a [(UtcTimeStamp, T)];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (cur, _) = self.data.get(self.end)?;
+        let cutoff = *cur - self.width;
+        while self.data[self.start].0 <= cutoff {
+            self.start += 1;
+        }
+
+        self.end += 1;
+        Some(&self.data[self.start..self.end])
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.data.len() - self.end;
+        (len, Some(len))
+    }
+}
+
+impl<T> ExactSizeIterator for RollingWindows<'_, T> {}
+
+// ============================================================================================== //
+// [Streaming windows]                                                                            //
+// ============================================================================================== //
+
+/// Trailing windows over an iterator sorted by timestamp.
+///
+/// Since each window borrows from the adapter, this is a streaming interface
+/// rather than an `Iterator`: call [`RollingWindow::next_window`] in a loop.
+/// Window semantics match [`rolling_windows`].
+///
+/// ```
+/// use utctimestamp::{RollingWindow, TimeDelta, UtcTimeStamp};
+///
+/// let data = [0, 2, 3, 7].iter().map(|&s| (UtcTimeStamp::from_seconds(s), s));
+/// let mut windows = RollingWindow::new(data, TimeDelta::from_seconds(5));
+///
+/// let mut lens = vec![];
+/// while let Some(window) = windows.next_window() {
+///     lens.push(window.len());
+/// }
+/// assert_eq!(lens, vec![1, 2, 3, 2]);
+/// ```
+#[derive(Debug)]
+pub struct RollingWindow<I, T> {
+    iter: I,
+    width: TimeDelta,
+    buf: Vec<(UtcTimeStamp, T)>,
+    start: usize,
+}
+
+impl<I, T> RollingWindow<I, T>
+where
+    I: Iterator<Item = (UtcTimeStamp, T)>,
+{
+    /// Create trailing windows of the given width over `iter`.
+    ///
+    /// Panics if `width` isn't positive.
+    pub fn new(iter: impl IntoIterator<IntoIter = I>, width: TimeDelta) -> Self {
+        assert!(width.is_positive(), "width must be positive");
+        RollingWindow {
+            iter: iter.into_iter(),
+            width,
+            buf: Vec::new(),
+            start: 0,
+        }
+    }
+
+    /// Advance to the next element, returning its trailing window.
+    ///
+    /// The last entry of the window is the new element.
+    pub fn next_window(&mut self) -> Option<&[(UtcTimeStamp, T)]> {
+        let (ts, value) = self.iter.next()?;
+
+        // Drop expired elements once they make up half of the buffer.
+        if self.start > 0 && self.start * 2 >= self.buf.len() {
+            self.buf.drain(..self.start);
+            self.start = 0;
+        }
+
+        self.buf.push((ts, value));
+        let cutoff = ts - self.width;
+        while self.buf[self.start].0 <= cutoff {
+            self.start += 1;
+        }
+
+        Some(&self.buf[self.start..])
+    }
+}
+
+// ============================================================================================== //
+// [Tests]                                                                                        //
+// ============================================================================================== //
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn rolling() {
+        let secs = [0, 1, 1, 4, 5, 6, 20, 21];
+        let data: Vec<_> = secs
+            .iter()
+            .map(|&s| (UtcTimeStamp::from_seconds(s), s))
+            .collect();
+        let width = TimeDelta::from_seconds(5);
+        let expected: Vec<Vec<i64>> = vec![
+            vec![0],
+            vec![0, 1],
+            vec![0, 1, 1],
+            vec![0, 1, 1, 4],
+            vec![1, 1, 4, 5],
+            vec![4, 5, 6],
+            vec![20],
+            vec![20, 21],
+        ];
+
+        let windows: Vec<Vec<i64>> = rolling_windows(&data, width)
+            .map(|w| w.iter().map(|x| x.1).collect())
+            .collect();
+        assert_eq!(windows, expected);
+
+        let mut stream = RollingWindow::new(data, width);
+        let mut windows = vec![];
+        while let Some(w) = stream.next_window() {
+            windows.push(w.iter().map(|x| x.1).collect::<Vec<_>>());
+        }
+        assert_eq!(windows, expected);
+    }
+
+    #[test]
+    #[should_panic(expected = "width must be positive")]
+    fn rolling_zero_width() {
+        let data = [(UtcTimeStamp::zero(), ())];
+        rolling_windows(&data, TimeDelta::zero());
+    }
+
+    #[test]
+    #[should_panic(expected = "width must be positive")]
+    fn streaming_negative_width() {
+        let data = vec![(UtcTimeStamp::zero(), ())];
+        RollingWindow::new(data, TimeDelta::from_seconds(-1));
+    }
+}